@@ -0,0 +1,166 @@
+//! Build script: generate lookup tables for the bundled word lists
+//!
+//! Each list in `LISTS` becomes `$OUT_DIR/<name>.rs` (a `PhfSet` static)
+//! plus `$OUT_DIR/<name>.bin` (the words in slot order). See `src/data/phf.rs`.
+//!
+//! The Vietnamese frequency list becomes `$OUT_DIR/vietnamese_freq.rs`
+//! (sorted syllable and pair tables). See `src/data/vietnamese_freq.rs`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/data/phf.rs"]
+mod phf;
+
+/// Shared with the crate so build-time search keys match runtime ones
+#[allow(dead_code)]
+#[path = "src/data"]
+mod data {
+    pub mod chars;
+    pub mod keys;
+}
+
+const FREQ_SOURCE: &str = "src/data/vietnamese_freq.txt";
+
+/// (source word list, generated name)
+const LISTS: &[(&str, &str)] = &[
+    ("src/data/english_dict_merged.txt", "english_dict"),
+    ("src/data/telex_doubles.txt", "telex_doubles"),
+    ("src/data/french_words.txt", "french_words"),
+    ("src/data/german_words.txt", "german_words"),
+    ("src/data/spanish_words.txt", "spanish_words"),
+    ("src/data/latin_words.txt", "latin_words"),
+];
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/data/phf.rs");
+    for (source, name) in LISTS {
+        println!("cargo:rerun-if-changed={}", source);
+        let text = fs::read_to_string(source).unwrap_or_else(|e| panic!("{}: {}", source, e));
+        let mut words: Vec<String> = text
+            .lines()
+            .map(|l| l.trim().to_lowercase())
+            .filter(|l| !l.is_empty())
+            .collect();
+        words.sort();
+        words.dedup();
+        generate(Path::new(&out_dir), name, &words);
+    }
+    println!("cargo:rerun-if-changed={}", FREQ_SOURCE);
+    println!("cargo:rerun-if-changed=src/data/chars.rs");
+    println!("cargo:rerun-if-changed=src/data/keys.rs");
+    generate_freq(Path::new(&out_dir));
+}
+
+/// Write the syllable table (sorted by mark-stripped key, most frequent
+/// first) and the pair table (sorted by word, followers most frequent first)
+fn generate_freq(out_dir: &Path) {
+    let text = fs::read_to_string(FREQ_SOURCE).unwrap_or_else(|e| panic!("{}: {}", FREQ_SOURCE, e));
+    let mut unigrams: Vec<(String, &str, u32)> = Vec::new();
+    let mut bigrams: BTreeMap<&str, Vec<(&str, u32)>> = BTreeMap::new();
+    for line in text.lines() {
+        let Some((count, text)) = line.split_once('\t') else {
+            continue;
+        };
+        let Ok(count) = count.parse::<u32>() else {
+            continue;
+        };
+        match text.split_once(' ') {
+            Some((word, next)) => bigrams.entry(word).or_default().push((next, count)),
+            None => unigrams.push((data::chars::strip_marks(text), text, count)),
+        }
+    }
+    unigrams.sort_by(|a, b| a.0.cmp(&b.0).then(b.2.cmp(&a.2)));
+    for followers in bigrams.values_mut() {
+        followers.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    }
+
+    let mut code = format!(
+        "/// Generated by build.rs from {} ({} syllables, {} words with pairs)\n\
+         static UNIGRAMS: &[(&str, &str, u32)] = &[\n",
+        FREQ_SOURCE,
+        unigrams.len(),
+        bigrams.len()
+    );
+    for (key, word, count) in &unigrams {
+        code.push_str(&format!("    ({:?}, {:?}, {}),\n", key, word, count));
+    }
+    code.push_str("];\n\nstatic BIGRAMS: &[(&str, &[(&str, u32)])] = &[\n");
+    for (word, followers) in &bigrams {
+        let list: Vec<String> = followers
+            .iter()
+            .map(|(w, c)| format!("({:?}, {})", w, c))
+            .collect();
+        code.push_str(&format!("    ({:?}, &[{}]),\n", word, list.join(", ")));
+    }
+    code.push_str("];\n");
+    fs::write(out_dir.join("vietnamese_freq.rs"), code).expect("write frequency tables");
+}
+
+/// Build a minimal perfect hash (hash-and-displace) and write it out
+fn generate(out_dir: &Path, name: &str, words: &[String]) {
+    let len = words.len();
+    let buckets = len.div_ceil(phf::BUCKET_SIZE).max(1);
+
+    let mut by_bucket: Vec<Vec<usize>> = vec![Vec::new(); buckets];
+    for (i, w) in words.iter().enumerate() {
+        by_bucket[phf::bucket(w.as_bytes(), buckets)].push(i);
+    }
+    // Place the largest buckets first while the table is still empty
+    let mut order: Vec<usize> = (0..buckets).collect();
+    order.sort_by_key(|&b| std::cmp::Reverse(by_bucket[b].len()));
+
+    let mut slots: Vec<Option<usize>> = vec![None; len];
+    let mut displacements = vec![0u16; buckets];
+    for b in order {
+        let members = &by_bucket[b];
+        if members.is_empty() {
+            continue;
+        }
+        let (d, positions) = (1..=u16::MAX)
+            .find_map(|d| {
+                let positions: Vec<usize> = members
+                    .iter()
+                    .map(|&i| phf::slot(words[i].as_bytes(), d, len))
+                    .collect();
+                let mut distinct = positions.clone();
+                distinct.sort_unstable();
+                distinct.dedup();
+                let fits = distinct.len() == positions.len()
+                    && positions.iter().all(|&p| slots[p].is_none());
+                fits.then_some((d, positions))
+            })
+            .unwrap_or_else(|| panic!("{}: no displacement for bucket {}", name, b));
+        displacements[b] = d;
+        for (&i, &p) in members.iter().zip(&positions) {
+            slots[p] = Some(i);
+        }
+    }
+
+    let mut blob = Vec::new();
+    let mut offsets = vec![0u32];
+    for slot in &slots {
+        let i = slot.expect("perfect hash leaves no empty slot");
+        blob.extend_from_slice(words[i].as_bytes());
+        offsets.push(blob.len() as u32);
+    }
+
+    let join = |v: Vec<String>| v.join(",");
+    let code = format!(
+        "/// Generated by build.rs from the bundled word list ({len} words)\n\
+         pub static WORDS: PhfSet = PhfSet {{\n\
+         \x20   words: include_bytes!(concat!(env!(\"OUT_DIR\"), \"/{name}.bin\")),\n\
+         \x20   offsets: &[{offsets}],\n\
+         \x20   displacements: &[{displacements}],\n\
+         }};\n",
+        offsets = join(offsets.iter().map(u32::to_string).collect()),
+        displacements = join(displacements.iter().map(u16::to_string).collect()),
+    );
+    fs::write(out_dir.join(format!("{}.bin", name)), blob).expect("write word blob");
+    fs::write(out_dir.join(format!("{}.rs", name)), code).expect("write table");
+}
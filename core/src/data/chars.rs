@@ -404,6 +404,18 @@ pub fn parse_char(c: char) -> Option<ParsedChar> {
     }
 }
 
+/// Remove tone marks, keeping vowel modifiers and đ ("chuyển" → "chuyên")
+pub fn strip_marks(s: &str) -> String {
+    s.chars()
+        .map(|c| match parse_char(c) {
+            Some(p) if p.mark != mark::NONE => {
+                to_char(p.key, p.caps, p.tone, mark::NONE).unwrap_or(c)
+            }
+            _ => c,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
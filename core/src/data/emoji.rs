@@ -0,0 +1,169 @@
+//! Emoji shortcodes for `:name:` triggers
+//!
+//! Common Slack/GitHub names plus Vietnamese aliases typed without marks
+//! (":tim:" → ❤️, ":cuoi:" → 😄, ":cam_on:" → 🙏). Names are lowercase
+//! ASCII letters, digits, `_`, `+` and `-`.
+
+/// (shortcode, emoji), sorted by shortcode (byte order) for binary search
+pub static SHORTCODES: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("angry", "😠"),
+    ("anh_dao", "🌸"),
+    ("ba_la", "🍌"),
+    ("bag", "👜"),
+    ("balloon", "🎈"),
+    ("banh_mi", "🥖"),
+    ("banh_sinh_nhat", "🎂"),
+    ("beer", "🍺"),
+    ("bia", "🍺"),
+    ("birthday", "🎂"),
+    ("blush", "😊"),
+    ("bo", "🐄"),
+    ("boom", "💥"),
+    ("broken_heart", "💔"),
+    ("buon", "😞"),
+    ("cafe", "☕"),
+    ("cake", "🎂"),
+    ("cam_on", "🙏"),
+    ("camera", "📷"),
+    ("cat", "🐱"),
+    ("chay", "🔥"),
+    ("check", "✅"),
+    ("cho", "🐶"),
+    ("clap", "👏"),
+    ("co", "🇻🇳"),
+    ("coffee", "☕"),
+    ("confused", "😕"),
+    ("cool", "😎"),
+    ("cry", "😢"),
+    ("cuoi", "😄"),
+    ("cuoi_ra_nuoc_mat", "😂"),
+    ("dog", "🐶"),
+    ("dong_y", "👌"),
+    ("dragon", "🐉"),
+    ("du", "☂️"),
+    ("eyes", "👀"),
+    ("facepalm", "🤦"),
+    ("fire", "🔥"),
+    ("flag_vn", "🇻🇳"),
+    ("flower", "🌸"),
+    ("gian", "😠"),
+    ("gift", "🎁"),
+    ("grin", "😁"),
+    ("haha", "😆"),
+    ("hand_wave", "👋"),
+    ("heart", "❤️"),
+    ("heart_eyes", "😍"),
+    ("hi", "👋"),
+    ("hihi", "😊"),
+    ("hoa", "🌸"),
+    ("hoa_mai", "🌼"),
+    ("hon", "😘"),
+    ("hug", "🤗"),
+    ("idea", "💡"),
+    ("joy", "😂"),
+    ("khoc", "😢"),
+    ("kiss", "😘"),
+    ("kissing_heart", "😘"),
+    ("laughing", "😆"),
+    ("like", "👍"),
+    ("lo_lang", "😟"),
+    ("lua", "🔥"),
+    ("mat_troi", "☀️"),
+    ("meo", "🐱"),
+    ("met", "😫"),
+    ("money", "💰"),
+    ("moon", "🌙"),
+    ("muscle", "💪"),
+    ("nghi", "🤔"),
+    ("ngon", "😋"),
+    ("ngu", "😴"),
+    ("nhay_mat", "😉"),
+    ("ok", "👌"),
+    ("ok_hand", "👌"),
+    ("party", "🎉"),
+    ("phao_hoa", "🎆"),
+    ("pho", "🍜"),
+    ("pray", "🙏"),
+    ("qua", "🎁"),
+    ("rain", "🌧️"),
+    ("ramen", "🍜"),
+    ("rocket", "🚀"),
+    ("rofl", "🤣"),
+    ("sad", "😞"),
+    ("scream", "😱"),
+    ("see_no_evil", "🙈"),
+    ("shrug", "🤷"),
+    ("sleeping", "😴"),
+    ("smile", "😄"),
+    ("smiley", "😃"),
+    ("smirk", "😏"),
+    ("so", "😱"),
+    ("sob", "😭"),
+    ("star", "⭐"),
+    ("sun", "☀️"),
+    ("sunglasses", "😎"),
+    ("sweat_smile", "😅"),
+    ("tada", "🎉"),
+    ("tam_biet", "👋"),
+    ("tet", "🧧"),
+    ("thinking", "🤔"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("tien", "💰"),
+    ("tim", "❤️"),
+    ("tim_vo", "💔"),
+    ("tired", "😫"),
+    ("trai_tim", "❤️"),
+    ("trang", "🌙"),
+    ("unamused", "😒"),
+    ("vn", "🇻🇳"),
+    ("vo_tay", "👏"),
+    ("vui", "😄"),
+    ("warning", "⚠️"),
+    ("wave", "👋"),
+    ("wink", "😉"),
+    ("x", "❌"),
+    ("yeu", "😍"),
+    ("yum", "😋"),
+    ("zap", "⚡"),
+];
+
+/// Emoji for a shortcode name (without colons, case-insensitive)
+pub fn lookup(name: &str) -> Option<&'static str> {
+    let name = name.to_ascii_lowercase();
+    SHORTCODES
+        .binary_search_by(|(code, _)| code.cmp(&name.as_str()))
+        .ok()
+        .map(|i| SHORTCODES[i].1)
+}
+
+/// Check if a character may appear in a shortcode name
+pub fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_and_valid() {
+        assert!(SHORTCODES.windows(2).all(|w| w[0].0 < w[1].0));
+        for (code, _) in SHORTCODES {
+            assert!(code.chars().all(is_name_char), "{}", code);
+            assert_eq!(*code, code.to_ascii_lowercase());
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup("smile"), Some("😄"));
+        assert_eq!(lookup("+1"), Some("👍"));
+        assert_eq!(lookup("Tim"), Some("❤️"));
+        assert_eq!(lookup("cam_on"), Some("🙏"));
+        assert_eq!(lookup("nope"), None);
+    }
+}
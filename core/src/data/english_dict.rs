@@ -2,25 +2,24 @@
 //!
 //! Uses merged dictionary: 10k common words + words with double telex chars.
 //! Only restores to English when raw_input is a known English word.
+//!
+//! `english_dict_merged.txt` is compiled into a perfect hash table by
+//! `build.rs`, so lookups neither parse nor allocate at runtime.
 
-use std::collections::HashSet;
-use std::sync::LazyLock;
-
-/// Embedded English word list (10k + double telex patterns)
-const ENGLISH_WORDS: &str = include_str!("english_dict_merged.txt");
+use super::phf::PhfSet;
 
-/// HashSet for O(1) lookup
-static DICT: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
-    ENGLISH_WORDS
-        .lines()
-        .filter(|line| !line.is_empty())
-        .collect()
-});
+include!(concat!(env!("OUT_DIR"), "/english_dict.rs"));
 
 /// Check if a word is in the English dictionary (case-insensitive)
+///
+/// Allocation-free for ASCII input (everything typed through the engine).
 pub fn is_english_word(word: &str) -> bool {
-    let lower = word.to_lowercase();
-    DICT.contains(lower.as_str())
+    if word.is_ascii() || !word.chars().any(char::is_uppercase) {
+        WORDS.contains(word)
+    } else {
+        // Rare: non-ASCII uppercase ("DÜSSELDORF") needs full lowercasing
+        WORDS.contains(&word.to_lowercase())
+    }
 }
 
 #[cfg(test)]
@@ -50,8 +49,16 @@ mod tests {
         assert!(!is_english_word("đc"));
     }
 
+    #[test]
+    fn test_non_ascii_entries() {
+        assert!(is_english_word("düsseldorf"));
+        assert!(is_english_word("Düsseldorf"));
+        assert!(is_english_word("DÜSSELDORF"));
+        assert!(is_english_word("aff'd"));
+    }
+
     #[test]
     fn test_dict_size() {
-        assert!(DICT.len() >= 17000); // Should have ~18k words (10k + double telex)
+        assert!(WORDS.len() >= 17000); // Should have ~18k words (10k + double telex)
     }
 }
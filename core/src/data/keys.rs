@@ -51,6 +51,8 @@ pub const LEFT: u16 = 123;
 pub const RIGHT: u16 = 124;
 pub const DOWN: u16 = 125;
 pub const UP: u16 = 126;
+pub const PAGE_UP: u16 = 116;
+pub const PAGE_DOWN: u16 = 121;
 
 // Punctuation
 pub const DOT: u16 = 47;
@@ -0,0 +1,111 @@
+//! Language packs for auto-restore
+//!
+//! Telex mangles French, German, Spanish and Latin just like English:
+//! "pour" → "pỏu", "adresse" loses its double s to a mark. Each pack is a
+//! word list that auto-restore treats as foreign words; packs are enabled
+//! individually.
+//!
+//! Word lists are compiled into perfect hash tables by `build.rs`. Only
+//! words spelled in plain ASCII are listed: accented words ("très", "año")
+//! can't be restored from raw keystrokes anyway.
+
+use super::english_dict;
+use super::phf::PhfSet;
+
+mod french {
+    use super::PhfSet;
+    include!(concat!(env!("OUT_DIR"), "/french_words.rs"));
+}
+
+mod german {
+    use super::PhfSet;
+    include!(concat!(env!("OUT_DIR"), "/german_words.rs"));
+}
+
+mod spanish {
+    use super::PhfSet;
+    include!(concat!(env!("OUT_DIR"), "/spanish_words.rs"));
+}
+
+mod latin {
+    use super::PhfSet;
+    include!(concat!(env!("OUT_DIR"), "/latin_words.rs"));
+}
+
+/// Bundled word list for one language (ids are stable, used over FFI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LanguagePack {
+    English = 0,
+    French = 1,
+    German = 2,
+    Spanish = 3,
+    /// Latin, including scientific names ("drosophila", "quercus")
+    Latin = 4,
+}
+
+impl LanguagePack {
+    pub const ALL: [LanguagePack; 5] = [
+        LanguagePack::English,
+        LanguagePack::French,
+        LanguagePack::German,
+        LanguagePack::Spanish,
+        LanguagePack::Latin,
+    ];
+
+    /// Pack for an FFI id, None if unknown
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    /// Bit used in the engine's enabled-pack mask
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Number of bundled words
+    pub fn word_count(self) -> usize {
+        match self {
+            LanguagePack::English => english_dict::WORDS.len(),
+            LanguagePack::French => french::WORDS.len(),
+            LanguagePack::German => german::WORDS.len(),
+            LanguagePack::Spanish => spanish::WORDS.len(),
+            LanguagePack::Latin => latin::WORDS.len(),
+        }
+    }
+
+    /// Check if a word is in this pack (case-insensitive)
+    pub fn contains(self, word: &str) -> bool {
+        match self {
+            LanguagePack::English => english_dict::is_english_word(word),
+            LanguagePack::French => french::WORDS.contains(word),
+            LanguagePack::German => german::WORDS.contains(word),
+            LanguagePack::Spanish => spanish::WORDS.contains(word),
+            LanguagePack::Latin => latin::WORDS.contains(word),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_lookup() {
+        assert!(LanguagePack::French.contains("pour"));
+        assert!(LanguagePack::French.contains("Adresse"));
+        assert!(LanguagePack::German.contains("strasse"));
+        assert!(LanguagePack::Spanish.contains("gracias"));
+        assert!(LanguagePack::Latin.contains("drosophila"));
+        assert!(!LanguagePack::French.contains("gracias"));
+    }
+
+    #[test]
+    fn test_ids() {
+        for pack in LanguagePack::ALL {
+            assert_eq!(LanguagePack::from_id(pack as u8), Some(pack));
+            assert!(pack.word_count() > 0);
+        }
+        assert_eq!(LanguagePack::from_id(5), None);
+    }
+}
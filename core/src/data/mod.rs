@@ -5,12 +5,24 @@
 //! - `chars`: Unicode character conversion (includes tone/mark constants)
 //! - `vowel`: Vietnamese vowel phonology system
 //! - `telex_doubles`: English words with Telex double patterns for auto-restore
+//! - `emoji`: Emoji shortcodes (`:smile:`, Vietnamese aliases)
+//! - `shortcut_pack`: Bundled shortcut packs (arrows, math, currency, ...)
+//! - `language_pack`: Foreign word lists (English, French, ...) for auto-restore
+//! - `phf`: Build-time perfect hash tables backing the word lists
+//! - `vietnamese_freq`: Word frequencies for completion and prediction
+//! - `ngram`: Character trigram classifier (English vs Vietnamese keystrokes)
 
 pub mod chars;
 pub mod constants;
+pub mod emoji;
 pub mod english_dict;
 pub mod keys;
+pub mod language_pack;
+pub mod ngram;
+pub mod phf;
+pub mod shortcut_pack;
 pub mod telex_doubles;
+pub mod vietnamese_freq;
 pub mod vowel;
 
 pub use chars::{get_d, mark, to_char, tone};
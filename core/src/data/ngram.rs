@@ -0,0 +1,73 @@
+//! Character n-gram classifier: English vs Vietnamese keystrokes
+//!
+//! Trained offline by `scripts/train-ngram-classifier.js` from
+//! `tests/data/english_100k.txt` (English spellings) and
+//! `tests/data/vietnamese_22k.txt` converted to Telex keystrokes. The table
+//! holds one signed byte per trigram over a-z plus a word boundary:
+//! `SCALE * (ln P_en(c | ab) - ln P_vi(c | ab))`.
+//!
+//! Used as a fallback for auto-restore when the dictionary and the
+//! phonology rules have no opinion: "ebooks" and "rebase" look nothing like
+//! Telex for Vietnamese syllables, even if no word list contains them.
+
+/// Trigram log-ratio weights (27 × 27 × 27)
+static WEIGHTS: &[u8; 19683] = include_bytes!("ngram_en_vi.bin");
+
+/// Fixed-point scale used by the training script
+const SCALE: f32 = 16.0;
+
+const SYMBOLS: usize = 27;
+const BOUNDARY: usize = 26;
+
+fn weight(a: usize, b: usize, c: usize) -> i32 {
+    i32::from(WEIGHTS[(a * SYMBOLS + b) * SYMBOLS + c] as i8)
+}
+
+/// How much more English than Vietnamese Telex the keystrokes look
+///
+/// Mean log-likelihood ratio per trigram (nats): positive leans English,
+/// negative leans Vietnamese. None if the input is empty or not all ASCII
+/// letters.
+pub fn english_score(raw: &str) -> Option<f32> {
+    if raw.is_empty() || !raw.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    let (mut a, mut b) = (BOUNDARY, BOUNDARY);
+    let mut total = 0;
+    for c in raw
+        .bytes()
+        .map(|byte| usize::from(byte.to_ascii_lowercase() - b'a'))
+        .chain(std::iter::once(BOUNDARY))
+    {
+        total += weight(a, b, c);
+        (a, b) = (b, c);
+    }
+    Some(total as f32 / SCALE / (raw.len() + 1) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_words_score_positive() {
+        for word in ["ebooks", "rebase", "workflow", "strength"] {
+            assert!(english_score(word).unwrap() > 0.0, "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_vietnamese_keystrokes_score_negative() {
+        for word in ["vieejt", "nguwowfi", "dduowcj", "thuongw"] {
+            assert!(english_score(word).unwrap() < 0.0, "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_case_insensitive_and_invalid_input() {
+        assert_eq!(english_score("Rebase"), english_score("rebase"));
+        assert_eq!(english_score(""), None);
+        assert_eq!(english_score("a1"), None);
+        assert_eq!(english_score("việt"), None);
+    }
+}
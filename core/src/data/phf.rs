@@ -0,0 +1,81 @@
+//! Perfect hash word sets generated at build time
+//!
+//! `build.rs` turns the bundled word lists into minimal perfect hash tables
+//! (hash-and-displace): every word gets its own slot, so a lookup is two
+//! hashes, one displacement read and one byte comparison. Nothing is parsed
+//! or allocated at runtime.
+//!
+//! Hashing folds ASCII case, so lookups are case-insensitive for ASCII
+//! input without building a lowercase copy.
+//!
+//! This file is also compiled into `build.rs` (via `#[path]`) so both sides
+//! share the same hash function.
+
+/// Average number of words per displacement bucket
+pub const BUCKET_SIZE: usize = 4;
+
+/// FNV-1a over ASCII-lowercased bytes, seeded, with a final avalanche
+pub fn hash(word: &[u8], seed: u32) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325 ^ u64::from(seed).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    for &b in word {
+        h ^= u64::from(b.to_ascii_lowercase());
+        h = h.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^ (h >> 33)
+}
+
+/// Bucket of a word (selects its displacement)
+pub fn bucket(word: &[u8], buckets: usize) -> usize {
+    (hash(word, 0) % buckets as u64) as usize
+}
+
+/// Slot of a word for a given displacement
+pub fn slot(word: &[u8], displacement: u16, len: usize) -> usize {
+    (hash(word, u32::from(displacement)) % len as u64) as usize
+}
+
+/// Static word set produced by `build.rs`
+pub struct PhfSet {
+    /// Words (lowercase) concatenated in slot order
+    pub words: &'static [u8],
+    /// Start of each slot's word in `words`, plus the final end offset
+    pub offsets: &'static [u32],
+    /// Displacement per bucket
+    pub displacements: &'static [u16],
+}
+
+impl PhfSet {
+    /// Number of words
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Word stored in a slot
+    fn word_at(&self, slot: usize) -> &'static [u8] {
+        let start = self.offsets[slot] as usize;
+        let end = self.offsets[slot + 1] as usize;
+        &self.words[start..end]
+    }
+
+    /// Check membership, ignoring ASCII case (entries are lowercase)
+    pub fn contains(&self, word: &str) -> bool {
+        if self.is_empty() || self.displacements.is_empty() {
+            return false;
+        }
+        let bytes = word.as_bytes();
+        let b = bucket(bytes, self.displacements.len());
+        let s = slot(bytes, self.displacements[b], self.len());
+        self.word_at(s).eq_ignore_ascii_case(bytes)
+    }
+
+    /// All words in slot order
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        (0..self.len()).filter_map(|i| std::str::from_utf8(self.word_at(i)).ok())
+    }
+}
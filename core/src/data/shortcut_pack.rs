@@ -0,0 +1,193 @@
+//! Bundled shortcut packs
+//!
+//! Curated shortcuts users can turn on per pack instead of typing them in
+//! one by one. Every entry has a stable id ("arrows.right") so a user's edit
+//! of a bundled entry is stored against the id and survives upgrades that
+//! change or add entries.
+//!
+//! Each entry names its trigger condition: symbol triggers ("->", "!=")
+//! fire immediately; triggers with letters or digits ("vnd", "ko", "^2")
+//! fire on the next word boundary, since those keys go to the word buffer.
+//! Word triggers must survive Telex and VNI as typed ("usd" would become
+//! "úd").
+
+use crate::engine::shortcut::TriggerCondition;
+
+/// One bundled shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackEntry {
+    /// Stable id, `<pack>.<name>`
+    pub id: &'static str,
+    pub trigger: &'static str,
+    pub replacement: &'static str,
+    pub condition: TriggerCondition,
+}
+
+/// Entry that fires as soon as the trigger is typed
+const fn immediate(
+    id: &'static str,
+    trigger: &'static str,
+    replacement: &'static str,
+) -> PackEntry {
+    PackEntry {
+        id,
+        trigger,
+        replacement,
+        condition: TriggerCondition::Immediate,
+    }
+}
+
+/// Entry that fires on the next word boundary
+const fn on_boundary(
+    id: &'static str,
+    trigger: &'static str,
+    replacement: &'static str,
+) -> PackEntry {
+    PackEntry {
+        id,
+        trigger,
+        replacement,
+        condition: TriggerCondition::OnWordBoundary,
+    }
+}
+
+static ARROWS: &[PackEntry] = &[
+    immediate("arrows.right", "->", "→"),
+    immediate("arrows.left", "<-", "←"),
+    immediate("arrows.double_right", "=>", "⇒"),
+];
+
+static MATH: &[PackEntry] = &[
+    immediate("math.not_equal", "!=", "≠"),
+    immediate("math.less_equal", "<=", "≤"),
+    immediate("math.greater_equal", ">=", "≥"),
+    immediate("math.plus_minus", "+-", "±"),
+    immediate("math.approx", "~=", "≈"),
+    on_boundary("math.squared", "^2", "²"),
+    on_boundary("math.cubed", "^3", "³"),
+    on_boundary("math.superscript_n", "^n", "ⁿ"),
+];
+
+static CURRENCY: &[PackEntry] = &[
+    on_boundary("currency.vnd", "vnd", "₫"),
+    on_boundary("currency.gbp", "gbp", "£"),
+    on_boundary("currency.jpy", "jpy", "¥"),
+    on_boundary("currency.btc", "btc", "₿"),
+];
+
+static TYPOGRAPHY: &[PackEntry] = &[
+    immediate("typography.en_dash", "--", "–"),
+    immediate("typography.ellipsis", "...", "…"),
+];
+
+static VIETNAMESE: &[PackEntry] = &[
+    on_boundary("vietnamese.ko", "ko", "không"),
+    on_boundary("vietnamese.dc", "dc", "được"),
+    on_boundary("vietnamese.vs", "vs", "với"),
+    on_boundary("vietnamese.ntn", "ntn", "như thế nào"),
+    on_boundary("vietnamese.mn", "mn", "mọi người"),
+    on_boundary("vietnamese.vn", "vn", "Việt Nam"),
+    on_boundary("vietnamese.hn", "hn", "Hà Nội"),
+    on_boundary("vietnamese.hcm", "hcm", "Hồ Chí Minh"),
+];
+
+/// Bundled shortcut pack (ids are stable, used over FFI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ShortcutPack {
+    /// `->` → `→`, `<-` → `←`, `=>` → `⇒`
+    Arrows = 0,
+    /// `!=` → `≠`, `<=` → `≤`, `+-` → `±`, `^2` → `²`, ...
+    Math = 1,
+    /// `vnd` → `₫`, ...
+    Currency = 2,
+    /// `--` → `–`, `...` → `…`
+    Typography = 3,
+    /// `ko` → `không`, `dc` → `được`, `hcm` → `Hồ Chí Minh`, ...
+    Vietnamese = 4,
+}
+
+impl ShortcutPack {
+    pub const ALL: [ShortcutPack; 5] = [
+        ShortcutPack::Arrows,
+        ShortcutPack::Math,
+        ShortcutPack::Currency,
+        ShortcutPack::Typography,
+        ShortcutPack::Vietnamese,
+    ];
+
+    /// Pack for an FFI id, None if unknown
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    /// Bit used in the enabled-pack mask
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Name used as the entry id prefix
+    pub fn name(self) -> &'static str {
+        match self {
+            ShortcutPack::Arrows => "arrows",
+            ShortcutPack::Math => "math",
+            ShortcutPack::Currency => "currency",
+            ShortcutPack::Typography => "typography",
+            ShortcutPack::Vietnamese => "vietnamese",
+        }
+    }
+
+    pub fn entries(self) -> &'static [PackEntry] {
+        match self {
+            ShortcutPack::Arrows => ARROWS,
+            ShortcutPack::Math => MATH,
+            ShortcutPack::Currency => CURRENCY,
+            ShortcutPack::Typography => TYPOGRAPHY,
+            ShortcutPack::Vietnamese => VIETNAMESE,
+        }
+    }
+
+    /// Pack and entry for an entry id ("math.not_equal")
+    pub fn find_entry(id: &str) -> Option<(Self, &'static PackEntry)> {
+        let (name, _) = id.split_once('.')?;
+        let pack = Self::ALL.into_iter().find(|p| p.name() == name)?;
+        pack.entries()
+            .iter()
+            .find(|e| e.id == id)
+            .map(|e| (pack, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_ids() {
+        for pack in ShortcutPack::ALL {
+            assert_eq!(ShortcutPack::from_id(pack as u8), Some(pack));
+        }
+        assert_eq!(ShortcutPack::from_id(5), None);
+    }
+
+    #[test]
+    fn test_entry_ids_unique_and_prefixed() {
+        let mut ids = HashSet::new();
+        let mut triggers = HashSet::new();
+        for pack in ShortcutPack::ALL {
+            for e in pack.entries() {
+                assert!(e.id.starts_with(&format!("{}.", pack.name())), "{}", e.id);
+                assert!(ids.insert(e.id), "duplicate id {}", e.id);
+                assert!(
+                    triggers.insert(e.trigger),
+                    "duplicate trigger {}",
+                    e.trigger
+                );
+                assert_eq!(ShortcutPack::find_entry(e.id), Some((pack, e)));
+            }
+        }
+        assert_eq!(ShortcutPack::find_entry("math.nope"), None);
+        assert_eq!(ShortcutPack::find_entry("nope"), None);
+    }
+}
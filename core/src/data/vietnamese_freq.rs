@@ -0,0 +1,115 @@
+//! Vietnamese word frequencies for completion and prediction
+//!
+//! Derived from tests/data/vietnamese_22k.txt by
+//! scripts/generate-vietnamese-freq.js. Each line of vietnamese_freq.txt is
+//! "<count>\t<text>" where text is a syllable or two adjacent syllables
+//! ("chuyên môn"). `build.rs` turns the list into a syllable table sorted
+//! by search key and a sorted pair table, so nothing is parsed at runtime.
+//!
+//! Prefix lookup ignores tone marks (sắc, huyền, ...) but keeps vowel
+//! modifiers and đ, so "chuyê" matches "chuyên", "chuyển" and "chuyện".
+
+use super::chars::{self, mark};
+
+pub use super::chars::strip_marks;
+
+// UNIGRAMS: (mark-stripped key, syllable, count), sorted by key then most
+// frequent first. BIGRAMS: (word, following words most frequent first),
+// sorted by word.
+include!(concat!(env!("OUT_DIR"), "/vietnamese_freq.rs"));
+
+/// First tone mark in a word (mark::NONE if unmarked)
+pub fn mark_of(s: &str) -> u8 {
+    s.chars()
+        .filter_map(chars::parse_char)
+        .map(|p| p.mark)
+        .find(|&m| m != mark::NONE)
+        .unwrap_or(mark::NONE)
+}
+
+/// Syllables starting with `prefix` (lowercase) with their counts
+///
+/// A tone mark in the prefix must match the candidate's mark:
+/// "chuyể" → "chuyển" only, "chuyê" → "chuyên", "chuyển", "chuyện".
+pub fn completions(prefix: &str) -> impl Iterator<Item = (&'static str, u32)> + '_ {
+    let key = strip_marks(prefix);
+    let wanted = mark_of(prefix);
+    let start = UNIGRAMS.partition_point(|(k, _, _)| *k < key.as_str());
+    UNIGRAMS[start..]
+        .iter()
+        .take_while(move |(k, _, _)| k.starts_with(key.as_str()))
+        .filter(move |(_, word, _)| wanted == mark::NONE || mark_of(word) == wanted)
+        .map(|&(_, word, count)| (word, count))
+}
+
+/// Bundled count for a syllable (lowercase)
+pub fn unigram_count(word: &str) -> u32 {
+    let key = strip_marks(word);
+    let start = UNIGRAMS.partition_point(|(k, _, _)| *k < key.as_str());
+    UNIGRAMS[start..]
+        .iter()
+        .take_while(|(k, _, _)| *k == key)
+        .find(|(_, w, _)| *w == word)
+        .map_or(0, |&(_, _, count)| count)
+}
+
+/// Words seen after `word` (lowercase), most frequent first
+pub fn followers(word: &str) -> &'static [(&'static str, u32)] {
+    BIGRAMS
+        .binary_search_by(|(w, _)| (*w).cmp(word))
+        .map_or(&[], |i| BIGRAMS[i].1)
+}
+
+/// Bundled count for the pair "word next" (lowercase)
+pub fn bigram_count(word: &str, next: &str) -> u32 {
+    followers(word)
+        .iter()
+        .find(|(w, _)| *w == next)
+        .map_or(0, |&(_, count)| count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_marks() {
+        assert_eq!(strip_marks("chuyển"), "chuyên");
+        assert_eq!(strip_marks("Đường"), "Đương");
+        assert_eq!(strip_marks("abc"), "abc");
+    }
+
+    #[test]
+    fn test_completions_ignore_marks() {
+        let words: Vec<_> = completions("chuyê").map(|(w, _)| w).collect();
+        for w in ["chuyên", "chuyển", "chuyện"] {
+            assert!(words.contains(&w), "missing {} in {:?}", w, words);
+        }
+    }
+
+    #[test]
+    fn test_completions_respect_typed_mark() {
+        let words: Vec<_> = completions("chuyể").map(|(w, _)| w).collect();
+        assert_eq!(words, vec!["chuyển"]);
+    }
+
+    #[test]
+    fn test_counts() {
+        assert!(unigram_count("không") > 0);
+        assert_eq!(unigram_count("qwz"), 0);
+        assert!(bigram_count("chuyên", "môn") > 0);
+        assert!(!followers("chuyên").is_empty());
+    }
+
+    #[test]
+    fn test_generated_tables_sorted() {
+        assert!(UNIGRAMS.iter().all(|&(k, w, _)| strip_marks(w) == k));
+        assert!(UNIGRAMS
+            .windows(2)
+            .all(|p| (p[0].0, p[1].2) <= (p[1].0, p[0].2)));
+        assert!(BIGRAMS.windows(2).all(|p| p[0].0 < p[1].0));
+        assert!(BIGRAMS
+            .iter()
+            .all(|(_, next)| next.windows(2).all(|p| p[0].1 >= p[1].1)));
+    }
+}
@@ -0,0 +1,435 @@
+//! Virtual Document - text field simulation
+//!
+//! Owns a piece of text plus a cursor and feeds keystrokes through an
+//! [`Engine`], applying every [`Result`] exactly like a platform frontend:
+//!
+//! - `Send`/`Restore`: delete `backspace` chars before the cursor, insert `chars`
+//! - Break keys (not Space) are still typed after a replacement unless the
+//!   result carries [`FLAG_KEY_CONSUMED`]
+//! - Long or multi-line output continues in parts fetched with
+//!   [`Engine::next_output`] while [`FLAG_MORE_OUTPUT`] is set
+//! - The caret then moves left `caret_left` chars (shortcut `{cursor}`)
+//! - `None`: the key passes through (letters, punctuation, Backspace, arrows)
+//!
+//! Parts and caret moves only come once the engine is told the frontend
+//! handles them (`Engine::set_frontend_caps`).
+//!
+//! Used by tests, the CLI and frontends that need a faithful, headless
+//! picture of what the user would see on screen.
+//!
+//! ```
+//! use gonhanh_core::document::VirtualDocument;
+//!
+//! let mut doc = VirtualDocument::new();
+//! doc.type_str("vieetj nam");
+//! assert_eq!(doc.text(), "việt nam");
+//! ```
+
+use crate::data::keys;
+use crate::engine::{
+    break_key_to_char, Action, Engine, Result, FLAG_KEY_CONSUMED, FLAG_MORE_OUTPUT,
+};
+use crate::utils;
+
+/// One keystroke applied to a [`VirtualDocument`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edit {
+    /// Key code that was pressed
+    pub key: u16,
+    /// Engine action for this key (0=None, 1=Send, 2=Restore)
+    pub action: u8,
+    /// Whether the engine consumed the key (it was not passed through)
+    pub key_consumed: bool,
+    /// Cursor position (in chars) where the change starts
+    pub at: usize,
+    /// Text removed before the cursor
+    pub removed: String,
+    /// Text inserted at the cursor (engine output plus any passed-through char)
+    pub inserted: String,
+}
+
+impl Edit {
+    /// True when the keystroke left the text unchanged
+    pub fn is_noop(&self) -> bool {
+        self.removed.is_empty() && self.inserted.is_empty()
+    }
+}
+
+/// Text field driven by an [`Engine`]
+pub struct VirtualDocument {
+    engine: Engine,
+    text: Vec<char>,
+    /// Cursor position in chars (0 = before first char)
+    cursor: usize,
+    edits: Vec<Edit>,
+}
+
+impl Default for VirtualDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VirtualDocument {
+    /// Create an empty document with a default (Telex) engine
+    pub fn new() -> Self {
+        Self::with_engine(Engine::new())
+    }
+
+    /// Create an empty document driven by a pre-configured engine
+    pub fn with_engine(engine: Engine) -> Self {
+        Self {
+            engine,
+            text: Vec::new(),
+            cursor: 0,
+            edits: Vec::new(),
+        }
+    }
+
+    pub fn engine(&self) -> &Engine {
+        &self.engine
+    }
+
+    pub fn engine_mut(&mut self) -> &mut Engine {
+        &mut self.engine
+    }
+
+    /// Consume the document, returning the engine (with its state intact)
+    pub fn into_engine(self) -> Engine {
+        self.engine
+    }
+
+    /// Current text
+    pub fn text(&self) -> String {
+        self.text.iter().collect()
+    }
+
+    /// Cursor position in chars
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// All edits applied so far, one per keystroke
+    pub fn edits(&self) -> &[Edit] {
+        &self.edits
+    }
+
+    /// Forget recorded edits (text and engine state are kept)
+    pub fn clear_edits(&mut self) {
+        self.edits.clear();
+    }
+
+    /// Replace the whole text and put the cursor at the end
+    ///
+    /// Like a frontend after paste or focus change: engine state is cleared.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.chars().collect();
+        self.cursor = self.text.len();
+        self.engine.clear_all();
+    }
+
+    /// Move the cursor (clamped to text length)
+    ///
+    /// Like a mouse click: engine state, including word history, is cleared.
+    pub fn set_cursor(&mut self, pos: usize) {
+        self.cursor = pos.min(self.text.len());
+        self.engine.clear_all();
+    }
+
+    /// Press a key
+    ///
+    /// # Arguments
+    /// * `key` - macOS virtual keycode
+    /// * `caps` - true for uppercase letters
+    /// * `shift` - true if Shift is held (for symbols like @, #, $)
+    pub fn key(&mut self, key: u16, caps: bool, shift: bool) -> &Edit {
+        let typed = typed_char(key, caps, shift);
+        self.press(key, caps, false, shift, typed)
+    }
+
+    /// Press a key with Cmd/Ctrl/Alt held (engine bypass, text unchanged)
+    pub fn key_ctrl(&mut self, key: u16, caps: bool, shift: bool) -> &Edit {
+        self.press(key, caps, true, shift, None)
+    }
+
+    /// Press Backspace
+    pub fn backspace(&mut self) -> &Edit {
+        self.key(keys::DELETE, false, false)
+    }
+
+    /// Press ESC
+    pub fn escape(&mut self) -> &Edit {
+        self.key(keys::ESC, false, false)
+    }
+
+    /// Type a single character on a US keyboard layout
+    ///
+    /// Characters without a key (e.g. "ế" pasted or from another layout)
+    /// are inserted as-is and the engine is reset, as a frontend would
+    /// after text it did not compose.
+    pub fn type_char(&mut self, c: char) -> &Edit {
+        match utils::char_to_key_ext(c) {
+            Some((key, shift)) => self.press(key, c.is_uppercase(), false, shift, Some(c)),
+            None => {
+                self.engine.clear_all();
+                let at = self.cursor;
+                self.insert(&[c]);
+                self.record(Edit {
+                    key: u16::MAX,
+                    action: Action::None as u8,
+                    key_consumed: false,
+                    at,
+                    removed: String::new(),
+                    inserted: c.to_string(),
+                })
+            }
+        }
+    }
+
+    /// Accept a completion/prediction candidate (see [`Engine::accept_candidate`])
+    pub fn accept_candidate(&mut self, index: usize) -> &Edit {
+        let r = self.engine.accept_candidate(index);
+        self.apply(u16::MAX, false, false, None, &r)
+    }
+
+    /// Type every character of `s` (see [`type_char`](Self::type_char))
+    pub fn type_str(&mut self, s: &str) {
+        for c in s.chars() {
+            self.type_char(c);
+        }
+    }
+
+    /// Feed one keystroke to the engine and apply its result
+    fn press(
+        &mut self,
+        key: u16,
+        caps: bool,
+        ctrl: bool,
+        shift: bool,
+        typed: Option<char>,
+    ) -> &Edit {
+        let r = self.engine.on_key_ext(key, caps, ctrl, shift);
+        self.apply(key, shift, ctrl, typed, &r)
+    }
+
+    /// Apply an engine result the way platform frontends do
+    fn apply(
+        &mut self,
+        key: u16,
+        shift: bool,
+        ctrl: bool,
+        typed: Option<char>,
+        r: &Result,
+    ) -> &Edit {
+        let key_consumed = r.flags & FLAG_KEY_CONSUMED != 0;
+        let start = self.cursor;
+        let mut removed = Vec::new();
+        let mut inserted = Vec::new();
+
+        if r.action == Action::Send as u8 || r.action == Action::Restore as u8 {
+            removed = self.delete_before(r.backspace as usize);
+            let output = result_chars(r);
+            self.insert(&output);
+            inserted.extend(output);
+
+            let mut caret_left = r.caret_left;
+            let mut more = r.flags & FLAG_MORE_OUTPUT != 0;
+            while more {
+                let next = self.engine.next_output();
+                let output = result_chars(&next);
+                self.insert(&output);
+                inserted.extend(output);
+                caret_left = next.caret_left;
+                more = next.flags & FLAG_MORE_OUTPUT != 0;
+            }
+
+            // Break keys are posted after the replacement (auto-restore, word
+            // shortcuts on punctuation/Enter). Space is already in the output.
+            if keys::is_break_ext(key, shift) && key != keys::SPACE && !key_consumed {
+                if let Some(c) = typed {
+                    self.insert(&[c]);
+                    inserted.push(c);
+                }
+            }
+            self.cursor = self.cursor.saturating_sub(caret_left as usize);
+        } else if !ctrl {
+            // Pass through: the application handles the key itself
+            match key {
+                keys::DELETE => removed = self.delete_before(1),
+                keys::LEFT => self.cursor = self.cursor.saturating_sub(1),
+                keys::RIGHT => self.cursor = (self.cursor + 1).min(self.text.len()),
+                _ => {
+                    if let Some(c) = typed {
+                        self.insert(&[c]);
+                        inserted.push(c);
+                    }
+                }
+            }
+        }
+
+        let at = start - removed.len();
+        self.record(Edit {
+            key,
+            action: r.action,
+            key_consumed,
+            at,
+            removed: removed.into_iter().collect(),
+            inserted: inserted.into_iter().collect(),
+        })
+    }
+
+    fn record(&mut self, edit: Edit) -> &Edit {
+        self.edits.push(edit);
+        self.edits.last().unwrap()
+    }
+
+    /// Delete up to `n` chars before the cursor, returning them in order
+    fn delete_before(&mut self, n: usize) -> Vec<char> {
+        let n = n.min(self.cursor);
+        let from = self.cursor - n;
+        self.cursor = from;
+        self.text.drain(from..from + n).collect()
+    }
+
+    fn insert(&mut self, chars: &[char]) {
+        let at = self.cursor;
+        self.text.splice(at..at, chars.iter().copied());
+        self.cursor += chars.len();
+    }
+}
+
+/// Chars to insert for a Send/Restore result
+fn result_chars(r: &Result) -> Vec<char> {
+    (0..r.count as usize)
+        .filter_map(|i| char::from_u32(r.chars[i]))
+        .collect()
+}
+
+/// Character a key produces when passed through to the application
+fn typed_char(key: u16, caps: bool, shift: bool) -> Option<char> {
+    match key {
+        keys::SPACE => Some(' '),
+        keys::TAB => Some('\t'),
+        keys::RETURN | keys::ENTER => Some('\n'),
+        _ => break_key_to_char(key, shift).or_else(|| utils::key_to_char(key, caps)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_str() {
+        let mut doc = VirtualDocument::new();
+        doc.type_str("Vieetj Nam");
+        assert_eq!(doc.text(), "Việt Nam");
+        assert_eq!(doc.cursor(), 8);
+    }
+
+    #[test]
+    fn test_edits_record_diffs() {
+        let mut doc = VirtualDocument::new();
+        doc.type_str("as");
+        let edits = doc.edits();
+        assert_eq!(edits.len(), 2);
+        // 'a' passes through
+        assert_eq!(edits[0].action, Action::None as u8);
+        assert_eq!(edits[0].inserted, "a");
+        // 's' replaces 'a' with 'á'
+        assert_eq!(edits[1].action, Action::Send as u8);
+        assert_eq!(edits[1].at, 0);
+        assert_eq!(edits[1].removed, "a");
+        assert_eq!(edits[1].inserted, "á");
+    }
+
+    #[test]
+    fn test_backspace_after_space_restores_word() {
+        let mut doc = VirtualDocument::new();
+        doc.type_str("ban ");
+        doc.backspace();
+        doc.type_char('s');
+        assert_eq!(doc.text(), "bán");
+    }
+
+    #[test]
+    fn test_esc_restore() {
+        let mut e = Engine::new();
+        e.set_esc_restore(true);
+        let mut doc = VirtualDocument::with_engine(e);
+        doc.type_str("tesxt");
+        doc.escape();
+        assert_eq!(doc.text(), "tesxt");
+    }
+
+    #[test]
+    fn test_key_consumed_shortcut() {
+        let mut doc = VirtualDocument::new();
+        doc.engine_mut()
+            .shortcuts_mut()
+            .add(crate::engine::shortcut::Shortcut::immediate("->", "→"));
+        doc.type_str("a ->");
+        assert_eq!(doc.text(), "a →");
+        assert!(doc.edits().last().unwrap().key_consumed);
+    }
+
+    #[test]
+    fn test_break_key_after_auto_restore() {
+        let mut e = Engine::new();
+        e.set_english_auto_restore(true);
+        let mut doc = VirtualDocument::with_engine(e);
+        doc.type_str("text,");
+        assert_eq!(doc.text(), "text,");
+    }
+
+    #[test]
+    fn test_typing_in_middle_of_text() {
+        let mut doc = VirtualDocument::new();
+        doc.set_text("xin  nhé");
+        doc.set_cursor(4);
+        doc.type_str("chaof");
+        assert_eq!(doc.text(), "xin chào nhé");
+        assert_eq!(doc.cursor(), 8);
+    }
+
+    #[test]
+    fn test_arrow_keys_move_cursor() {
+        let mut doc = VirtualDocument::new();
+        doc.type_str("ab");
+        doc.key(keys::LEFT, false, false);
+        doc.type_char('x');
+        assert_eq!(doc.text(), "axb");
+    }
+
+    #[test]
+    fn test_unmapped_char_inserted_raw() {
+        let mut doc = VirtualDocument::new();
+        doc.type_str("ế ");
+        assert_eq!(doc.text(), "ế ");
+    }
+
+    #[test]
+    fn test_ctrl_key_leaves_text_unchanged() {
+        let mut doc = VirtualDocument::new();
+        doc.type_str("a");
+        assert!(doc.key_ctrl(keys::C, false, false).is_noop());
+        assert_eq!(doc.text(), "a");
+    }
+
+    #[test]
+    fn test_shortcut_cursor_marker() {
+        use crate::engine::shortcut::Shortcut;
+        use crate::engine::CAP_CARET_MOVE;
+
+        let mut doc = VirtualDocument::new();
+        doc.engine_mut().set_frontend_caps(CAP_CARET_MOVE);
+        doc.engine_mut()
+            .shortcuts_mut()
+            .add(Shortcut::new("html", "<p>{cursor}</p>"));
+        doc.type_str("html ");
+        assert_eq!(doc.text(), "<p></p> ");
+        assert_eq!(doc.cursor(), 3);
+        doc.type_str("chaof");
+        assert_eq!(doc.text(), "<p>chào</p> ");
+    }
+}
@@ -0,0 +1,218 @@
+//! Autocorrect Table - common Vietnamese misspellings
+//!
+//! Validation only rejects impossible syllables. This table fixes valid-looking
+//! mistakes at word commit: hỏi/ngã confusion ("sữa chữa" → "sửa chữa"),
+//! regional s/x, ch/tr, l/n swaps ("xuất xắc" → "xuất sắc") and d/gi mix-ups.
+//!
+//! Entries are one word or two words (the committed word plus the word
+//! before it). User entries take priority over bundled ones; mapping a typo
+//! to itself disables a bundled correction.
+
+use std::collections::HashMap;
+
+/// Bundled corrections (typo → correct), lowercase
+const BUNDLED: &[(&str, &str)] = &[
+    // hỏi/ngã confusion
+    ("sữa chữa", "sửa chữa"),
+    ("sửa chửa", "sửa chữa"),
+    ("nhửng", "những"),
+    ("giúp đở", "giúp đỡ"),
+    ("chặt chẻ", "chặt chẽ"),
+    ("ngẩm nghĩ", "ngẫm nghĩ"),
+    ("sẳn sàng", "sẵn sàng"),
+    ("bở ngỡ", "bỡ ngỡ"),
+    ("rãnh rỗi", "rảnh rỗi"),
+    ("mảnh liệt", "mãnh liệt"),
+    ("kỹ niệm", "kỷ niệm"),
+    ("giải bày", "giãi bày"),
+    // s/x confusion
+    ("xuất xắc", "xuất sắc"),
+    ("suất sắc", "xuất sắc"),
+    ("sắc xảo", "sắc sảo"),
+    ("sử lý", "xử lý"),
+    ("xử dụng", "sử dụng"),
+    ("xáng tạo", "sáng tạo"),
+    ("xác xuất", "xác suất"),
+    ("sơ xuất", "sơ suất"),
+    ("đề suất", "đề xuất"),
+    ("cọ sát", "cọ xát"),
+    ("xát nhập", "sáp nhập"),
+    ("sáng lạng", "xán lạn"),
+    // ch/tr confusion
+    ("chân trọng", "trân trọng"),
+    ("chở thành", "trở thành"),
+    ("trú ý", "chú ý"),
+    ("chuẩn đoán", "chẩn đoán"),
+    ("chính chắn", "chín chắn"),
+    // l/n confusion
+    ("nàm việc", "làm việc"),
+    ("no lắng", "lo lắng"),
+    ("lỗ lực", "nỗ lực"),
+    ("lội dung", "nội dung"),
+    // d/gi confusion
+    ("giành dụm", "dành dụm"),
+    ("để giành", "để dành"),
+    ("tranh dành", "tranh giành"),
+    ("dành giật", "giành giật"),
+    ("dấu diếm", "giấu giếm"),
+    // Spelling
+    ("nghành", "ngành"),
+    ("thăm quan", "tham quan"),
+];
+
+/// Correction table: bundled list plus user entries
+#[derive(Debug, Clone)]
+pub struct Autocorrect {
+    bundled: HashMap<&'static str, &'static str>,
+    /// User entries indexed by typo (lowercase)
+    user: HashMap<String, String>,
+}
+
+impl Default for Autocorrect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Autocorrect {
+    /// Create with the bundled correction list
+    pub fn new() -> Self {
+        Self {
+            bundled: BUNDLED.iter().copied().collect(),
+            user: HashMap::new(),
+        }
+    }
+
+    /// Create without bundled corrections (user entries only)
+    pub fn empty() -> Self {
+        Self {
+            bundled: HashMap::new(),
+            user: HashMap::new(),
+        }
+    }
+
+    /// Add a user correction (one or two words, case-insensitive)
+    ///
+    /// Overrides a bundled entry for the same typo.
+    pub fn add(&mut self, typo: &str, correction: &str) {
+        let typo = normalize(typo);
+        if !typo.is_empty() {
+            self.user.insert(typo, correction.trim().to_string());
+        }
+    }
+
+    /// Remove a user correction, returning its replacement
+    pub fn remove(&mut self, typo: &str) -> Option<String> {
+        self.user.remove(&normalize(typo))
+    }
+
+    /// Remove all user corrections (bundled list is kept)
+    pub fn clear_user(&mut self) {
+        self.user.clear();
+    }
+
+    /// Number of user corrections
+    pub fn user_len(&self) -> usize {
+        self.user.len()
+    }
+
+    /// Look up the correction for a typo (case-insensitive, user first)
+    pub fn lookup(&self, typo: &str) -> Option<&str> {
+        let key = normalize(typo);
+        self.user
+            .get(&key)
+            .map(String::as_str)
+            .or_else(|| self.bundled.get(key.as_str()).copied())
+    }
+
+    /// Correct typed text, matching its case word by word
+    ///
+    /// Returns None when there is no entry or the entry maps to itself.
+    /// Example: "Xuất xắc" → "Xuất sắc", "SỮA CHỮA" → "SỬA CHỮA"
+    pub fn correct(&self, typed: &str) -> Option<String> {
+        let correction = self.lookup(typed)?;
+        if correction.to_lowercase() == normalize(typed) {
+            return None;
+        }
+        let typed_words: Vec<&str> = typed.split(' ').collect();
+        let out: Vec<String> = correction
+            .split(' ')
+            .enumerate()
+            .map(|(i, word)| match typed_words.get(i) {
+                Some(t) => match_case(t, word),
+                None => word.to_string(),
+            })
+            .collect();
+        Some(out.join(" "))
+    }
+}
+
+/// Lowercase and collapse whitespace
+fn normalize(s: &str) -> String {
+    s.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Apply the case of `typed` to `word` (all caps / title case / as-is)
+pub(crate) fn match_case(typed: &str, word: &str) -> String {
+    let letters = || typed.chars().filter(|c| c.is_alphabetic());
+    if letters().count() > 1 && letters().all(char::is_uppercase) {
+        return word.to_uppercase();
+    }
+    if typed.chars().next().is_some_and(char::is_uppercase) {
+        let mut chars = word.chars();
+        return match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            None => String::new(),
+        };
+    }
+    word.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_lookup() {
+        let ac = Autocorrect::new();
+        assert_eq!(ac.lookup("xuất xắc"), Some("xuất sắc"));
+        assert_eq!(ac.lookup("nhửng"), Some("những"));
+        assert_eq!(ac.lookup("những"), None);
+    }
+
+    #[test]
+    fn test_correct_matches_case() {
+        let ac = Autocorrect::new();
+        assert_eq!(ac.correct("Xuất xắc").as_deref(), Some("Xuất sắc"));
+        assert_eq!(ac.correct("SỮA CHỮA").as_deref(), Some("SỬA CHỮA"));
+        assert_eq!(ac.correct("Nhửng").as_deref(), Some("Những"));
+    }
+
+    #[test]
+    fn test_user_entries_override_bundled() {
+        let mut ac = Autocorrect::new();
+        ac.add("hok", "không");
+        assert_eq!(ac.correct("hok").as_deref(), Some("không"));
+
+        // Mapping to itself disables the bundled correction
+        ac.add("Nhửng", "nhửng");
+        assert_eq!(ac.correct("nhửng"), None);
+
+        assert_eq!(ac.remove("nhửng").as_deref(), Some("nhửng"));
+        assert_eq!(ac.correct("nhửng").as_deref(), Some("những"));
+        assert_eq!(ac.user_len(), 1);
+    }
+
+    #[test]
+    fn test_empty_has_no_bundled() {
+        let mut ac = Autocorrect::empty();
+        assert_eq!(ac.lookup("xuất xắc"), None);
+        ac.add("  xuất   xắc ", "xuất sắc");
+        assert_eq!(ac.lookup("Xuất xắc"), Some("xuất sắc"));
+        ac.clear_user();
+        assert_eq!(ac.user_len(), 0);
+    }
+}
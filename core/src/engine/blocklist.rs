@@ -0,0 +1,149 @@
+//! Blocklist - words that are never transformed
+//!
+//! Tokens like "vscode", "dotfiles" or "pnpm" get mangled by Telex long
+//! before the word ends. Entries are case-insensitive. Unlike the English
+//! dictionary this does not depend on auto-restore being enabled.
+//!
+//! - "dotf*" (prefix): as soon as the keystrokes start with "dotf", the
+//!   engine puts the raw ASCII back on screen and types the rest of the
+//!   word without marks or tones.
+//! - "pnpm" (whole word): checked when the word ends; if exactly those keys
+//!   were typed, the raw ASCII replaces the word. Longer words that start
+//!   with it transform as usual ("go" blocked, "gocs" is still "góc").
+
+use std::collections::BTreeSet;
+
+/// User list of words/prefixes typed as raw ASCII
+#[derive(Debug, Clone, Default)]
+pub struct Blocklist {
+    /// Whole words (lowercase)
+    words: BTreeSet<String>,
+    /// Prefixes, stored without the trailing '*' (lowercase)
+    prefixes: BTreeSet<String>,
+}
+
+impl Blocklist {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a word ("pnpm") or prefix ("dotf*")
+    ///
+    /// Returns false if the entry is empty or not ASCII letters/digits.
+    pub fn add(&mut self, entry: &str) -> bool {
+        match parse(entry) {
+            Some((word, true)) => self.prefixes.insert(word),
+            Some((word, false)) => self.words.insert(word),
+            None => return false,
+        };
+        true
+    }
+
+    /// Remove a word or prefix (same syntax as `add`). Returns true if it existed.
+    pub fn remove(&mut self, entry: &str) -> bool {
+        match parse(entry) {
+            Some((word, true)) => self.prefixes.remove(&word),
+            Some((word, false)) => self.words.remove(&word),
+            None => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.prefixes.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty() && self.prefixes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len() + self.prefixes.len()
+    }
+
+    /// All entries, words first, prefixes with a trailing '*'
+    pub fn entries(&self) -> Vec<String> {
+        self.words
+            .iter()
+            .cloned()
+            .chain(self.prefixes.iter().map(|p| format!("{}*", p)))
+            .collect()
+    }
+
+    /// Check if typed keys (raw ASCII) match a word or start with a prefix
+    pub fn matches(&self, raw: &str) -> bool {
+        self.matches_word(raw) || self.matches_prefix(raw)
+    }
+
+    /// Check if typed keys (raw ASCII) are exactly a whole-word entry
+    pub fn matches_word(&self, raw: &str) -> bool {
+        raw.is_ascii() && self.words.contains(&raw.to_ascii_lowercase())
+    }
+
+    /// Check if typed keys (raw ASCII) start with a prefix entry
+    pub fn matches_prefix(&self, raw: &str) -> bool {
+        if !raw.is_ascii() || raw.is_empty() {
+            return false;
+        }
+        let raw = raw.to_ascii_lowercase();
+        (1..=raw.len()).any(|n| self.prefixes.contains(&raw[..n]))
+    }
+}
+
+/// Lowercase entry and whether it is a prefix, None if invalid
+fn parse(entry: &str) -> Option<(String, bool)> {
+    let entry = entry.trim();
+    let (word, is_prefix) = match entry.strip_suffix('*') {
+        Some(prefix) => (prefix, true),
+        None => (entry, false),
+    };
+    let valid = !word.is_empty() && word.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then(|| (word.to_ascii_lowercase(), is_prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_and_prefix_matching() {
+        let mut b = Blocklist::new();
+        assert!(b.add("pnpm"));
+        assert!(b.add("DotF*"));
+
+        assert!(b.matches("pnpm"));
+        assert!(b.matches("PNPM"));
+        assert!(!b.matches("pnp"));
+        assert!(!b.matches("pnpmx"));
+
+        assert!(b.matches("dotf"));
+        assert!(b.matches("dotfiles"));
+        assert!(!b.matches("dot"));
+
+        assert!(b.matches_word("pnpm") && !b.matches_prefix("pnpm"));
+        assert!(b.matches_prefix("dotfiles") && !b.matches_word("dotf"));
+    }
+
+    #[test]
+    fn test_invalid_entries() {
+        let mut b = Blocklist::new();
+        assert!(!b.add(""));
+        assert!(!b.add("*"));
+        assert!(!b.add("two words"));
+        assert!(!b.add("việt"));
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_remove_and_entries() {
+        let mut b = Blocklist::new();
+        b.add("vscode");
+        b.add("dotf*");
+        assert_eq!(b.entries(), vec!["vscode", "dotf*"]);
+        assert!(b.remove("dotf*"));
+        assert!(!b.remove("dotf"));
+        assert_eq!(b.len(), 1);
+        b.clear();
+        assert!(b.is_empty());
+    }
+}
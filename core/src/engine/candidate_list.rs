@@ -0,0 +1,272 @@
+//! Candidate List - options published for the user to choose from
+//!
+//! Features that need a choice (word prediction, suggestions, emoji, ...)
+//! publish a list here; frontends show the current page and the engine
+//! handles the selection keys while the list is active:
+//!
+//! - Digit 1-9, 0: select the item with that label on the current page
+//! - Up/Down: move the highlight (crossing pages)
+//! - Page Up/Page Down: change page
+//! - Enter/Tab: select the highlighted item (only after navigating)
+//! - ESC: close the list
+//!
+//! Prediction lists open passive, since they follow every word: digits,
+//! arrows and page keys keep their usual meaning ("năm 2024") until the
+//! user activates the list with Tab (which also highlights the first
+//! item) or the frontend does (`Engine::activate_candidates`). Custom
+//! lists are shown on request and are active right away.
+//!
+//! Labels are "1".."9" then "0", so a page holds at most 10 items.
+
+/// Default items per page
+pub const DEFAULT_PAGE_SIZE: usize = 9;
+/// Maximum items per page (one per digit key)
+pub const MAX_PAGE_SIZE: usize = 10;
+
+const LABELS: [&str; MAX_PAGE_SIZE] = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "0"];
+
+/// Feature that published the list (decides what selecting an item does)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandidateSource {
+    /// Word completion/prediction: replace the word and commit with a space
+    Prediction,
+    /// Published through `Engine::show_candidates`: replace the current word
+    Custom,
+}
+
+/// Paged list of candidates
+#[derive(Debug, Clone)]
+pub struct CandidateList {
+    items: Vec<String>,
+    source: CandidateSource,
+    page_size: usize,
+    page: usize,
+    /// Highlighted item (absolute index), None until the user navigates
+    highlighted: Option<usize>,
+    /// Whether selection keys go to the list (see module docs)
+    active: bool,
+}
+
+impl Default for CandidateList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CandidateList {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            source: CandidateSource::Custom,
+            page_size: DEFAULT_PAGE_SIZE,
+            page: 0,
+            highlighted: None,
+            active: false,
+        }
+    }
+
+    /// Replace the items, going back to the first page
+    ///
+    /// Returns true if the list changed.
+    pub fn set(&mut self, source: CandidateSource, items: Vec<String>) -> bool {
+        if self.source == source && self.items == items {
+            return false;
+        }
+        self.source = source;
+        self.items = items;
+        self.page = 0;
+        self.highlighted = None;
+        self.active = source == CandidateSource::Custom;
+        true
+    }
+
+    /// Close the list. Returns true if it was open.
+    pub fn close(&mut self) -> bool {
+        let was_open = self.is_open();
+        self.items.clear();
+        self.page = 0;
+        self.highlighted = None;
+        self.active = false;
+        was_open
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.items.is_empty()
+    }
+
+    /// Whether digits, arrows and page keys select in the list
+    pub fn is_active(&self) -> bool {
+        self.active && self.is_open()
+    }
+
+    /// Let selection keys go to the list. Returns false if it is closed.
+    pub fn activate(&mut self) -> bool {
+        self.active = self.is_open();
+        self.active
+    }
+
+    pub fn source(&self) -> CandidateSource {
+        self.source
+    }
+
+    /// All items, best first
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Set items per page (clamped to 1..=10)
+    pub fn set_page_size(&mut self, size: usize) {
+        self.page_size = size.clamp(1, MAX_PAGE_SIZE);
+        self.page = 0;
+        self.highlighted = None;
+    }
+
+    /// Current page (0-based)
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.items.len().div_ceil(self.page_size)
+    }
+
+    /// Items on the current page with their labels
+    pub fn page_items(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        let start = self.page * self.page_size;
+        self.items
+            .iter()
+            .skip(start)
+            .take(self.page_size)
+            .enumerate()
+            .map(|(i, text)| (LABELS[i], text.as_str()))
+    }
+
+    /// Highlighted item as an index on the current page
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted.map(|i| i - self.page * self.page_size)
+    }
+
+    /// Absolute index of the item at `index` on the current page
+    pub fn index_on_page(&self, index: usize) -> Option<usize> {
+        let abs = self.page * self.page_size + index;
+        (index < self.page_size && abs < self.items.len()).then_some(abs)
+    }
+
+    /// Absolute index of the item labelled `label` on the current page
+    pub fn index_for_label(&self, label: char) -> Option<usize> {
+        let pos = LABELS.iter().position(|l| l.starts_with(label))?;
+        self.index_on_page(pos)
+    }
+
+    /// Absolute index of the highlighted item
+    pub fn highlighted_index(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Go to the next page (wraps). Returns false if there is only one page.
+    pub fn next_page(&mut self) -> bool {
+        self.turn_page(1)
+    }
+
+    /// Go to the previous page (wraps). Returns false if there is only one page.
+    pub fn prev_page(&mut self) -> bool {
+        self.turn_page(self.page_count().saturating_sub(1))
+    }
+
+    /// Move the highlight by one item (wraps), switching pages as needed
+    pub fn move_highlight(&mut self, forward: bool) {
+        let len = self.items.len();
+        if len == 0 {
+            return;
+        }
+        let next = match self.highlighted {
+            None if forward => self.page * self.page_size,
+            None => (self.page * self.page_size + len - 1) % len,
+            Some(i) if forward => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+        };
+        self.highlighted = Some(next);
+        self.page = next / self.page_size;
+    }
+
+    fn turn_page(&mut self, step: usize) -> bool {
+        let count = self.page_count();
+        if count <= 1 {
+            return false;
+        }
+        self.page = (self.page + step) % count;
+        self.highlighted = None;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(n: usize) -> CandidateList {
+        let mut l = CandidateList::new();
+        l.set(
+            CandidateSource::Custom,
+            (0..n).map(|i| format!("w{}", i)).collect(),
+        );
+        l
+    }
+
+    #[test]
+    fn test_paging_and_labels() {
+        let mut l = list(12);
+        assert_eq!(l.page_count(), 2);
+        let first: Vec<_> = l.page_items().collect();
+        assert_eq!(first.len(), 9);
+        assert_eq!(first[0], ("1", "w0"));
+
+        assert!(l.next_page());
+        let second: Vec<_> = l.page_items().collect();
+        assert_eq!(second, vec![("1", "w9"), ("2", "w10"), ("3", "w11")]);
+        assert_eq!(l.index_for_label('2'), Some(10));
+        assert_eq!(l.index_for_label('4'), None);
+
+        assert!(l.next_page());
+        assert_eq!(l.page(), 0);
+        assert!(l.prev_page());
+        assert_eq!(l.page(), 1);
+    }
+
+    #[test]
+    fn test_highlight_crosses_pages() {
+        let mut l = list(10);
+        l.move_highlight(false);
+        assert_eq!(l.highlighted_index(), Some(9));
+        assert_eq!(l.page(), 1);
+        assert_eq!(l.highlighted(), Some(0));
+        l.move_highlight(true);
+        assert_eq!(l.highlighted_index(), Some(0));
+        assert_eq!(l.page(), 0);
+    }
+
+    #[test]
+    fn test_page_size_ten_uses_zero_label() {
+        let mut l = list(10);
+        l.set_page_size(20);
+        assert_eq!(l.page_size(), 10);
+        assert_eq!(l.index_for_label('0'), Some(9));
+        assert!(!l.next_page());
+    }
+
+    #[test]
+    fn test_set_reports_changes() {
+        let mut l = list(3);
+        assert!(!l.set(
+            CandidateSource::Custom,
+            vec!["w0".into(), "w1".into(), "w2".into()]
+        ));
+        assert!(l.set(CandidateSource::Prediction, vec!["w0".into()]));
+        assert!(l.close());
+        assert!(!l.close());
+    }
+}
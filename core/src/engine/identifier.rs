@@ -0,0 +1,183 @@
+//! Identifier detection - code-like tokens typed without transforms
+//!
+//! Developers type `getUserName`, `user_id`, `/usr/local`, `foo@bar.com`
+//! and `https://...` between Vietnamese words. The engine keeps the text of
+//! the current token (everything since the last whitespace, across break
+//! keys like `_ / @ .`) and, once its shape matches an enabled kind, types
+//! the rest of the token as raw ASCII.
+//!
+//! Raw prefixes (`@user`, `#channel`, `:emoji_name:`, `/command`) work the
+//! same way: a token starting with an enabled prefix is typed raw until the
+//! next whitespace.
+//!
+//! Detection looks at what has been typed so far, so the part before the
+//! telltale character is only fixed up when it is part of the same word:
+//! "isV" restores "ís" to "isV", but "user" in "user_id" was committed by `_`.
+
+/// Token shape that suppresses Vietnamese transforms (ids used over FFI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum IdentifierKind {
+    /// Lowercase followed by uppercase: `getUserName`, `iPhone`
+    CamelCase = 0,
+    /// Underscore after a letter or digit: `user_id`, `MAX_LEN`
+    /// (not `_emphasis_`: a leading underscore is Markdown)
+    SnakeCase = 1,
+    /// `~/`, `./`, `../` or two slashes: `src/engine/mod.rs`, `/usr/local`
+    /// (a single one is too common in prose: "và/hoặc")
+    Path = 2,
+    /// `scheme://` or `www.`
+    Url = 3,
+    /// `name@domain`
+    Email = 4,
+}
+
+impl IdentifierKind {
+    pub const ALL: [IdentifierKind; 5] = [
+        IdentifierKind::CamelCase,
+        IdentifierKind::SnakeCase,
+        IdentifierKind::Path,
+        IdentifierKind::Url,
+        IdentifierKind::Email,
+    ];
+
+    /// Kind for an FFI id, None if unknown
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    /// Bit used in the engine's enabled-kind mask
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Check if the token typed so far has this shape
+    pub fn matches(self, token: &str) -> bool {
+        let chars: Vec<char> = token.chars().collect();
+        match self {
+            IdentifierKind::CamelCase => chars
+                .windows(2)
+                .any(|w| w[0].is_ascii_lowercase() && w[1].is_ascii_uppercase()),
+            IdentifierKind::SnakeCase => {
+                !token.starts_with('_')
+                    && chars
+                        .windows(2)
+                        .any(|w| w[0].is_ascii_alphanumeric() && w[1] == '_')
+            }
+            IdentifierKind::Path => {
+                ["~/", "./", "../"].iter().any(|p| token.starts_with(p))
+                    || token.matches('/').count() >= 2
+            }
+            IdentifierKind::Url => {
+                token.contains("://") || token.to_ascii_lowercase().starts_with("www.")
+            }
+            IdentifierKind::Email => chars
+                .windows(2)
+                .any(|w| w[0].is_ascii_alphanumeric() && w[1] == '@'),
+        }
+    }
+}
+
+/// Token prefix for chat mentions and commands (ids used over FFI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RawPrefix {
+    /// `@username`
+    Mention = 0,
+    /// `#channel`
+    Hashtag = 1,
+    /// `:emoji_name:`
+    Emoji = 2,
+    /// `/command`
+    Command = 3,
+}
+
+impl RawPrefix {
+    pub const ALL: [RawPrefix; 4] = [
+        RawPrefix::Mention,
+        RawPrefix::Hashtag,
+        RawPrefix::Emoji,
+        RawPrefix::Command,
+    ];
+
+    /// Prefix for an FFI id, None if unknown
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    /// Bit used in the engine's enabled-prefix mask
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    pub fn char(self) -> char {
+        match self {
+            RawPrefix::Mention => '@',
+            RawPrefix::Hashtag => '#',
+            RawPrefix::Emoji => ':',
+            RawPrefix::Command => '/',
+        }
+    }
+
+    /// Prefix a token starts with, if any
+    pub fn of_token(token: &str) -> Option<Self> {
+        let first = token.chars().next()?;
+        Self::ALL.into_iter().find(|p| p.char() == first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shapes() {
+        use IdentifierKind::*;
+        let cases: &[(&str, Option<IdentifierKind>)] = &[
+            ("getU", Some(CamelCase)),
+            ("user_i", Some(SnakeCase)),
+            ("/usr/l", Some(Path)),
+            ("src/engine/m", Some(Path)),
+            ("vaf/hoawcj", None),
+            ("~/d", Some(Path)),
+            ("https://e", Some(Url)),
+            ("www.g", Some(Url)),
+            ("foo@b", Some(Email)),
+            ("Việt", None),
+            ("HTML", None),
+            ("_nghiêng_", None),
+            ("/help", None),
+            ("@user", None),
+        ];
+        for &(token, kind) in cases {
+            match kind {
+                Some(kind) => assert!(kind.matches(token), "{}", token),
+                None => assert!(
+                    !IdentifierKind::ALL.iter().any(|k| k.matches(token)),
+                    "{}",
+                    token
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ids() {
+        for kind in IdentifierKind::ALL {
+            assert_eq!(IdentifierKind::from_id(kind as u8), Some(kind));
+        }
+        assert_eq!(IdentifierKind::from_id(5), None);
+        for prefix in RawPrefix::ALL {
+            assert_eq!(RawPrefix::from_id(prefix as u8), Some(prefix));
+        }
+        assert_eq!(RawPrefix::from_id(4), None);
+    }
+
+    #[test]
+    fn test_raw_prefix_of_token() {
+        assert_eq!(RawPrefix::of_token("@nam"), Some(RawPrefix::Mention));
+        assert_eq!(RawPrefix::of_token(":smile"), Some(RawPrefix::Emoji));
+        assert_eq!(RawPrefix::of_token("a@b"), None);
+        assert_eq!(RawPrefix::of_token(""), None);
+    }
+}
@@ -10,24 +10,39 @@
 //! 3. **Shortcut Support**: User-defined abbreviations with priority
 //! 4. **Longest-Match-First**: For diacritic placement
 
+pub mod autocorrect;
+pub mod blocklist;
 pub mod buffer;
+pub mod candidate_list;
+pub mod identifier;
+pub mod prediction;
 pub mod shortcut;
 pub mod syllable;
 pub mod transform;
+pub mod user_dictionary;
 pub mod validation;
 
 use crate::data::{
     chars::{self, mark, tone},
-    constants, english_dict, keys, telex_doubles,
+    constants, emoji, keys,
+    language_pack::LanguagePack,
+    ngram, telex_doubles, vietnamese_freq,
     vowel::{Phonology, Vowel},
 };
 use crate::input::{self, ToneType};
 use crate::utils;
+use autocorrect::Autocorrect;
+use blocklist::Blocklist;
 use buffer::{Buffer, Char, MAX};
-use shortcut::{InputMethod, ShortcutTable};
+use candidate_list::{CandidateList, CandidateSource};
+use identifier::{IdentifierKind, RawPrefix};
+use prediction::{Candidate, Predictor};
+use shortcut::{InputMethod, ShortcutMatch, ShortcutTable};
+use std::collections::VecDeque;
+use user_dictionary::UserDictionary;
 use validation::{
     is_foreign_word_pattern, is_valid, is_valid_for_transform_with_foreign, is_valid_with_foreign,
-    is_valid_with_tones, is_valid_with_tones_and_foreign,
+    is_valid_with_tones, is_valid_with_tones_and_foreign, validate_syllable,
 };
 
 /// Engine action result
@@ -40,6 +55,10 @@ pub enum Action {
 }
 
 /// Result for FFI
+///
+/// With `CAP_MORE_OUTPUT`, `'\n'` and `'\t'` in `chars` mean Enter and Tab:
+/// they come as a one-char result so frontends can post the key instead of
+/// typing text. Without it they are typed inline.
 #[repr(C)]
 pub struct Result {
     pub chars: [u32; MAX],
@@ -49,11 +68,42 @@ pub struct Result {
     /// Flags byte:
     /// - bit 0 (0x01): key_consumed - if set, the trigger key should NOT be passed through
     ///   Used for shortcuts where the trigger key is part of the replacement
+    /// - bit 1 (0x02): candidates_changed - the candidate list was opened, changed
+    ///   or closed; frontends should re-query it
+    /// - bit 2 (0x04): more_output - the output continues; fetch the next part
+    ///   with `Engine::next_output` (long or multi-line shortcut expansions,
+    ///   only with `CAP_MORE_OUTPUT`)
     pub flags: u8,
+    /// Move the caret left this many chars after applying the result
+    /// (shortcut `{cursor}` marker: `html` → `<p>|</p>`, only with
+    /// `CAP_CARET_MOVE`)
+    pub caret_left: u16,
 }
 
 /// Flag: key was consumed by shortcut, don't pass through
 pub const FLAG_KEY_CONSUMED: u8 = 0x01;
+/// Flag: candidate list changed (opened, updated, paged or closed)
+pub const FLAG_CANDIDATES_CHANGED: u8 = 0x02;
+/// Flag: more output follows, fetch it with `Engine::next_output`
+pub const FLAG_MORE_OUTPUT: u8 = 0x04;
+
+/// Frontend capability: moves the caret by `Result::caret_left`
+pub const CAP_CARET_MOVE: u8 = 0x01;
+/// Frontend capability: fetches `FLAG_MORE_OUTPUT` parts with
+/// `Engine::next_output` and posts lone `'\n'`/`'\t'` as Enter/Tab
+pub const CAP_MORE_OUTPUT: u8 = 0x02;
+
+/// Most chars sent in one result (`count` is a u8)
+const OUTPUT_CHUNK: usize = MAX - 1;
+
+/// Maximum candidates published for prediction (all pages)
+const MAX_PREDICTION_CANDIDATES: usize = 30;
+
+/// Shortest raw word the English classifier may restore
+const MIN_CLASSIFIER_WORD_LEN: usize = 4;
+
+/// Default English classifier threshold (mean log-ratio per trigram, nats)
+pub const DEFAULT_ENGLISH_CLASSIFIER_THRESHOLD: f32 = 1.0;
 
 impl Result {
     pub fn none() -> Self {
@@ -63,6 +113,7 @@ impl Result {
             backspace: 0,
             count: 0,
             flags: 0,
+            caret_left: 0,
         }
     }
 
@@ -73,6 +124,7 @@ impl Result {
             backspace,
             count: chars.len().min(MAX) as u8,
             flags: 0,
+            caret_left: 0,
         };
         for (i, &c) in chars.iter().take(MAX).enumerate() {
             result.chars[i] = c as u32;
@@ -91,6 +143,40 @@ impl Result {
     pub fn key_consumed(&self) -> bool {
         self.flags & FLAG_KEY_CONSUMED != 0
     }
+
+    /// Check if the candidate list changed
+    pub fn candidates_changed(&self) -> bool {
+        self.flags & FLAG_CANDIDATES_CHANGED != 0
+    }
+
+    /// Check if more output follows (see `Engine::next_output`)
+    pub fn more_output(&self) -> bool {
+        self.flags & FLAG_MORE_OUTPUT != 0
+    }
+}
+
+/// Split output into results: text runs of at most `OUTPUT_CHUNK` chars,
+/// Enter and Tab on their own
+fn output_chunks(output: &[char]) -> VecDeque<Vec<char>> {
+    let mut chunks = VecDeque::new();
+    let mut text = Vec::new();
+    for &c in output {
+        if c == '\n' || c == '\t' {
+            if !text.is_empty() {
+                chunks.push_back(std::mem::take(&mut text));
+            }
+            chunks.push_back(vec![c]);
+        } else {
+            text.push(c);
+            if text.len() == OUTPUT_CHUNK {
+                chunks.push_back(std::mem::take(&mut text));
+            }
+        }
+    }
+    if !text.is_empty() {
+        chunks.push_back(text);
+    }
+    chunks
 }
 
 /// Transform type for revert tracking
@@ -108,6 +194,8 @@ enum Transform {
     WShortcutSkipped,
     /// Bracket as vowel: ] → ư, [ → ơ (Issue #159)
     BracketAsVowel,
+    /// Quick Telex consonant expansion (cc → ch, nn → ng), key that triggered it
+    QuickTelex(u16),
 }
 
 /// Word history ring buffer capacity (stores last N committed words)
@@ -197,7 +285,7 @@ fn should_reset_pending_capitalize(key: u16, shift: bool) -> bool {
 /// Convert break key to its character representation
 /// Handles both shifted and unshifted break characters for shortcut matching.
 /// Examples: MINUS → '-', Shift+DOT → '>', Shift+MINUS → '_'
-fn break_key_to_char(key: u16, shift: bool) -> Option<char> {
+pub(crate) fn break_key_to_char(key: u16, shift: bool) -> Option<char> {
     if shift {
         // Shifted break characters
         match key {
@@ -274,11 +362,54 @@ pub struct Engine {
     /// When true, automatically restores English words that were transformed
     /// e.g., "tẽt" → "text", "ễpct" → "expect"
     english_auto_restore: bool,
+    /// Statistical fallback for auto-restore (n-gram English vs Vietnamese)
+    /// Restores words the rules keep when their keystrokes score above the threshold
+    english_classifier: bool,
+    english_classifier_threshold: f32,
+    /// Enabled language packs (bit mask of `LanguagePack::bit`)
+    language_packs: u8,
+    /// User additions/removals for the English dictionary (priority over bundled)
+    user_dictionary: UserDictionary,
+    /// Words typed as raw ASCII, never transformed ("pnpm", "dotf*")
+    blocklist: Blocklist,
+    /// Current word is typed raw (blocklist or identifier): keys are added without transforms
+    raw_word: bool,
+    /// Enabled identifier kinds (bit mask of `IdentifierKind::bit`)
+    identifier_kinds: u8,
+    /// Enabled raw prefixes: @ # : / (bit mask of `RawPrefix::bit`)
+    raw_prefixes: u8,
+    /// Replace `:name:` emoji shortcodes (works with IME disabled too)
+    emoji_enabled: bool,
+    /// Text of the current token: keys and symbols since the last whitespace
+    /// Survives break keys, so "user_id" is still one token at 'i'
+    token: String,
     /// Word history for backspace-after-space feature
     word_history: WordHistory,
     /// Number of spaces typed after committing a word (for backspace tracking)
     /// When this reaches 0 on backspace, we restore the committed word
     spaces_after_commit: u8,
+    /// Autocorrect common misspellings at word commit (opt-in)
+    autocorrect_enabled: bool,
+    autocorrect: Autocorrect,
+    /// Text of the last committed word as shown on screen (after autocorrect)
+    /// Used as context for two-word corrections ("xuất xắc" → "xuất sắc")
+    last_committed: Option<String>,
+    /// Last autocorrection (typed, corrected) for backspace-after-space undo
+    autocorrect_undo: Option<(String, String)>,
+    /// Word the user reverted with backspace - not corrected again on next commit
+    autocorrect_skip: Option<String>,
+    /// Word completion and next-word prediction (opt-in)
+    /// Committed words are learned only while enabled
+    prediction_enabled: bool,
+    predictor: Predictor,
+    /// Options shown to the user (prediction, custom lists)
+    candidate_list: CandidateList,
+    /// What the frontend can apply beyond backspace + text (`CAP_*`)
+    frontend_caps: u8,
+    /// Rest of a long expansion, sent by `next_output`
+    pending_output: VecDeque<Vec<char>>,
+    /// Caret move applied after the last part of `pending_output`
+    pending_caret_left: u16,
     /// Pending breve position: position of 'a' that has deferred breve
     /// Breve on 'a' in open syllables (like "raw") is invalid Vietnamese
     /// We defer applying breve until a valid final consonant is typed
@@ -352,6 +483,15 @@ pub struct Engine {
     /// Allow foreign consonants (z, w, j, f) as valid initial consonants
     /// When true, these letters are accepted as Vietnamese consonants for loanwords
     allow_foreign_consonants: bool,
+    /// Quick Telex: double consonant expands to digraph (cc→ch, gg→gi, kk→kh,
+    /// nn→ng, pp→ph, qq→qu, tt→th), like UniKey's "Quick Telex" option
+    quick_telex: bool,
+    /// Tracks if a Quick Telex expansion is active in current word
+    /// Used by auto-restore: "running" → "rung..." restores to English
+    had_quick_telex: bool,
+    /// Tracks if Quick Telex was reverted in current word (ccc → cc)
+    /// When true, further doubled consonants are typed literally (cccc → ccc)
+    quick_telex_reverted: bool,
 }
 
 impl Default for Engine {
@@ -376,8 +516,29 @@ impl Engine {
             free_tone_enabled: false,
             modern_tone: true,           // Default: modern style (hoà, thuý)
             english_auto_restore: false, // Default: OFF (experimental feature)
+            english_classifier: false,   // Default: OFF
+            english_classifier_threshold: DEFAULT_ENGLISH_CLASSIFIER_THRESHOLD,
+            language_packs: LanguagePack::English.bit(), // Default: English only
+            user_dictionary: UserDictionary::new(),
+            blocklist: Blocklist::new(),
+            raw_word: false,
+            identifier_kinds: 0,  // Default: OFF
+            raw_prefixes: 0,      // Default: OFF
+            emoji_enabled: false, // Default: OFF
+            token: String::new(),
             word_history: WordHistory::new(),
             spaces_after_commit: 0,
+            autocorrect_enabled: false, // Default: OFF
+            autocorrect: Autocorrect::new(),
+            last_committed: None,
+            autocorrect_undo: None,
+            autocorrect_skip: None,
+            prediction_enabled: false, // Default: OFF
+            predictor: Predictor::new(),
+            candidate_list: CandidateList::new(),
+            frontend_caps: 0,
+            pending_output: VecDeque::new(),
+            pending_caret_left: 0,
             pending_breve_pos: None,
             pending_u_horn_pos: None,
             stroke_reverted: false,
@@ -398,6 +559,9 @@ impl Engine {
             auto_capitalize_used: false,
             saw_sentence_ending: false,
             allow_foreign_consonants: false, // Default: OFF
+            quick_telex: false,              // Default: OFF
+            had_quick_telex: false,
+            quick_telex_reverted: false,
         }
     }
 
@@ -439,11 +603,43 @@ impl Engine {
         self.modern_tone = modern;
     }
 
+    /// Spell-check text using this engine's tone placement style
+    pub fn spellcheck(&self, text: &str) -> Vec<crate::spellcheck::Issue> {
+        crate::spellcheck::spellcheck_with(text, self.modern_tone)
+    }
+
     /// Set whether to enable English auto-restore (experimental)
     pub fn set_english_auto_restore(&mut self, enabled: bool) {
         self.english_auto_restore = enabled;
     }
 
+    /// Set whether auto-restore falls back to the n-gram English classifier
+    pub fn set_english_classifier(&mut self, enabled: bool) {
+        self.english_classifier = enabled;
+    }
+
+    /// Set the classifier score above which a word is restored to English
+    ///
+    /// Higher is more conservative. See `data::ngram::english_score`.
+    pub fn set_english_classifier_threshold(&mut self, threshold: f32) {
+        self.english_classifier_threshold = threshold;
+    }
+
+    /// Declare what the frontend can apply (`CAP_CARET_MOVE`,
+    /// `CAP_MORE_OUTPUT`)
+    ///
+    /// Without `CAP_CARET_MOVE` the caret stays after a shortcut expansion
+    /// (`{cursor}` is dropped). Without `CAP_MORE_OUTPUT` an expansion comes
+    /// in one result, line breaks and tabs included, cut at 255 chars.
+    pub fn set_frontend_caps(&mut self, caps: u8) {
+        self.frontend_caps = caps;
+        self.pending_output.clear();
+    }
+
+    pub fn frontend_caps(&self) -> u8 {
+        self.frontend_caps
+    }
+
     /// Set whether to enable auto-capitalize after sentence-ending punctuation
     pub fn set_auto_capitalize(&mut self, enabled: bool) {
         self.auto_capitalize = enabled;
@@ -463,6 +659,11 @@ impl Engine {
         self.allow_foreign_consonants
     }
 
+    /// Set whether Quick Telex consonant shortcuts are enabled (cc→ch, nn→ng, ...)
+    pub fn set_quick_telex(&mut self, enabled: bool) {
+        self.quick_telex = enabled;
+    }
+
     pub fn shortcuts(&self) -> &ShortcutTable {
         &self.shortcuts
     }
@@ -471,6 +672,194 @@ impl Engine {
         &mut self.shortcuts
     }
 
+    pub fn user_dictionary(&self) -> &UserDictionary {
+        &self.user_dictionary
+    }
+
+    pub fn user_dictionary_mut(&mut self) -> &mut UserDictionary {
+        &mut self.user_dictionary
+    }
+
+    /// Set whether a language pack's words are restored (English is on by default)
+    pub fn set_language_pack(&mut self, pack: LanguagePack, enabled: bool) {
+        if enabled {
+            self.language_packs |= pack.bit();
+        } else {
+            self.language_packs &= !pack.bit();
+        }
+    }
+
+    pub fn language_pack_enabled(&self, pack: LanguagePack) -> bool {
+        self.language_packs & pack.bit() != 0
+    }
+
+    /// Check if a word is foreign: user dictionary first, then enabled packs
+    fn is_foreign_word(&self, word: &str) -> bool {
+        self.user_dictionary.lookup(word).unwrap_or_else(|| {
+            LanguagePack::ALL
+                .iter()
+                .any(|&pack| self.language_pack_enabled(pack) && pack.contains(word))
+        })
+    }
+
+    pub fn blocklist(&self) -> &Blocklist {
+        &self.blocklist
+    }
+
+    pub fn blocklist_mut(&mut self) -> &mut Blocklist {
+        &mut self.blocklist
+    }
+
+    /// Set whether tokens of this kind are typed raw (camelCase, paths, ...)
+    pub fn set_identifier_detection(&mut self, kind: IdentifierKind, enabled: bool) {
+        if enabled {
+            self.identifier_kinds |= kind.bit();
+        } else {
+            self.identifier_kinds &= !kind.bit();
+        }
+    }
+
+    pub fn identifier_detection_enabled(&self, kind: IdentifierKind) -> bool {
+        self.identifier_kinds & kind.bit() != 0
+    }
+
+    /// Set whether tokens starting with this prefix are typed raw (@user, /cmd)
+    pub fn set_raw_prefix(&mut self, prefix: RawPrefix, enabled: bool) {
+        if enabled {
+            self.raw_prefixes |= prefix.bit();
+        } else {
+            self.raw_prefixes &= !prefix.bit();
+        }
+    }
+
+    pub fn raw_prefix_enabled(&self, prefix: RawPrefix) -> bool {
+        self.raw_prefixes & prefix.bit() != 0
+    }
+
+    /// Check if the current token starts with an enabled raw prefix ("@na")
+    ///
+    /// With emoji shortcodes on, ":" is always a raw prefix so the name on
+    /// screen matches the keys when the closing colon replaces it.
+    fn in_raw_prefix(&self) -> bool {
+        RawPrefix::of_token(&self.token).is_some_and(|p| {
+            self.raw_prefix_enabled(p) || (p == RawPrefix::Emoji && self.emoji_enabled)
+        })
+    }
+
+    /// Set whether `:name:` emoji shortcodes are replaced (":smile:" → 😄)
+    pub fn set_emoji_shortcodes(&mut self, enabled: bool) {
+        self.emoji_enabled = enabled;
+    }
+
+    /// Replace a `:name:` shortcode when its closing colon is typed
+    ///
+    /// The shortcode must start the token (after whitespace or another emoji).
+    /// The colon key is consumed; the opening colon and name are backspaced.
+    fn try_emoji_shortcode(&mut self, key: u16, shift: bool) -> Option<Result> {
+        if !self.emoji_enabled || key != keys::SEMICOLON || !shift {
+            return None;
+        }
+        let name = self.token.strip_prefix(':')?.strip_suffix(':')?;
+        if name.is_empty() || !name.chars().all(emoji::is_name_char) {
+            return None;
+        }
+        let output: Vec<char> = emoji::lookup(name)?.chars().collect();
+        let backspace = self.token.chars().count() as u8 - 1;
+        self.clear();
+        self.token.clear();
+        self.word_history.clear();
+        self.spaces_after_commit = 0;
+        Some(Result::send_consumed(backspace, &output))
+    }
+
+    /// Check if the current token looks like an enabled identifier kind
+    fn in_identifier(&self) -> bool {
+        self.identifier_kinds != 0
+            && IdentifierKind::ALL
+                .iter()
+                .any(|&kind| self.identifier_detection_enabled(kind) && kind.matches(&self.token))
+    }
+
+    /// Set whether to autocorrect common misspellings at word commit
+    pub fn set_autocorrect(&mut self, enabled: bool) {
+        self.autocorrect_enabled = enabled;
+    }
+
+    pub fn autocorrect(&self) -> &Autocorrect {
+        &self.autocorrect
+    }
+
+    pub fn autocorrect_mut(&mut self) -> &mut Autocorrect {
+        &mut self.autocorrect
+    }
+
+    /// Set whether word completion/prediction candidates are offered
+    pub fn set_prediction(&mut self, enabled: bool) {
+        self.prediction_enabled = enabled;
+        if !enabled {
+            self.candidate_list.close();
+        }
+    }
+
+    /// Published candidate list (current page, labels, highlight)
+    pub fn candidate_list(&self) -> &CandidateList {
+        &self.candidate_list
+    }
+
+    /// Set candidates shown per page (1-10, labelled 1-9 then 0)
+    pub fn set_candidate_page_size(&mut self, size: usize) {
+        self.candidate_list.set_page_size(size);
+    }
+
+    /// Publish a custom candidate list
+    ///
+    /// Selecting an item replaces the word being typed with it. The list
+    /// closes on the next key that is not a selection key.
+    pub fn show_candidates(&mut self, items: Vec<String>) {
+        self.candidate_list.set(CandidateSource::Custom, items);
+    }
+
+    /// Select candidate `index` on the current page
+    ///
+    /// Returns `Result::none()` when there is no such candidate.
+    pub fn select_candidate(&mut self, index: usize) -> Result {
+        match self.candidate_list.index_on_page(index) {
+            Some(abs) => self.select_candidate_at(abs),
+            None => Result::none(),
+        }
+    }
+
+    /// Let digits, arrows and page keys select in the candidate list (a
+    /// prediction list ignores them until activated). Returns false if no
+    /// list is open.
+    pub fn activate_candidates(&mut self) -> bool {
+        self.candidate_list.activate()
+    }
+
+    /// Go to the next (or previous) candidate page, activating the list.
+    /// Returns false if there is only one page.
+    pub fn turn_candidate_page(&mut self, forward: bool) -> bool {
+        self.candidate_list.activate();
+        if forward {
+            self.candidate_list.next_page()
+        } else {
+            self.candidate_list.prev_page()
+        }
+    }
+
+    /// Close the candidate list
+    pub fn close_candidates(&mut self) {
+        self.candidate_list.close();
+    }
+
+    pub fn predictor(&self) -> &Predictor {
+        &self.predictor
+    }
+
+    pub fn predictor_mut(&mut self) -> &mut Predictor {
+        &mut self.predictor
+    }
+
     /// Debug: get buffer length
     pub fn debug_buffer_len(&self) -> usize {
         self.buf.len()
@@ -536,26 +925,6 @@ impl Engine {
         self.on_key_ext(key, caps, ctrl, false)
     }
 
-    /// Check if key+shift combo is a raw mode prefix character
-    /// Raw prefixes: @ # : /
-    #[allow(dead_code)] // TEMP DISABLED
-    fn is_raw_prefix(key: u16, shift: bool) -> bool {
-        // / doesn't need shift
-        if key == keys::SLASH && !shift {
-            return true;
-        }
-        // @ # : need shift
-        if !shift {
-            return false;
-        }
-        matches!(
-            key,
-            keys::N2              // @ = Shift+2
-                | keys::N3        // # = Shift+3
-                | keys::SEMICOLON // : = Shift+;
-        )
-    }
-
     /// Handle key event with extended parameters
     ///
     /// # Arguments
@@ -564,15 +933,229 @@ impl Engine {
     /// * `ctrl` - true if Cmd/Ctrl/Alt is pressed (bypasses IME)
     /// * `shift` - true if Shift key is pressed (for symbols like @, #, $)
     pub fn on_key_ext(&mut self, key: u16, caps: bool, ctrl: bool, shift: bool) -> Result {
+        // A key before the rest of an expansion was fetched drops it
+        self.pending_output.clear();
+        if !ctrl {
+            if let Some(result) = self.handle_candidate_key(key, shift) {
+                return result;
+            }
+        }
+        let mut result = self.handle_key(key, caps, ctrl, shift);
+        if result.caret_left > 0 || result.more_output() {
+            // Caret moved into the expansion or past a line break: like a
+            // click, the text around it is no longer what the engine typed
+            self.clear_all();
+        }
+        if self.refresh_candidates() {
+            result.flags |= FLAG_CANDIDATES_CHANGED;
+        }
+        result
+    }
+
+    /// Next part of a long or multi-line expansion
+    ///
+    /// Call while the previous result has `FLAG_MORE_OUTPUT`. Parts have no
+    /// backspace; the last one carries the caret move. Returns `Result::none()`
+    /// if nothing is pending.
+    pub fn next_output(&mut self) -> Result {
+        let Some(chunk) = self.pending_output.pop_front() else {
+            return Result::none();
+        };
+        let mut result = Result::send_consumed(0, &chunk);
+        if self.pending_output.is_empty() {
+            result.caret_left = std::mem::take(&mut self.pending_caret_left);
+        } else {
+            result.flags |= FLAG_MORE_OUTPUT;
+        }
+        result
+    }
+
+    /// Result for a shortcut expansion
+    ///
+    /// With `CAP_MORE_OUTPUT`, output longer than one result, or with line
+    /// breaks and tabs, is split into parts sent by `next_output`; without
+    /// it, output is cut to one result. With `CAP_CARET_MOVE` the caret
+    /// moves back to `{cursor}` after the last part.
+    ///
+    /// `pass_through` is a trigger key the frontend types after the
+    /// replacement (punctuation, Enter). When the caret moves or the output
+    /// is split it would land in the wrong place, so it goes into the output
+    /// and is consumed.
+    fn expansion(
+        &mut self,
+        backspace: u8,
+        m: &ShortcutMatch,
+        pass_through: Option<char>,
+        consumed: bool,
+    ) -> Result {
+        let mut output: Vec<char> = m.output.chars().collect();
+        let mut caret_left = if self.frontend_caps & CAP_CARET_MOVE != 0 {
+            m.caret_left
+        } else {
+            0
+        };
+        let mut consumed = consumed;
+        let stream = self.frontend_caps & CAP_MORE_OUTPUT != 0;
+        let split = stream
+            && (output.len() > OUTPUT_CHUNK || output.contains(&'\n') || output.contains(&'\t'));
+        if caret_left > 0 || split {
+            if let Some(c) = pass_through {
+                output.push(c);
+                if caret_left > 0 {
+                    caret_left += 1;
+                }
+                consumed = true;
+            }
+        }
+        if !stream && output.len() > OUTPUT_CHUNK {
+            let caret_at = output.len() - caret_left;
+            output.truncate(OUTPUT_CHUNK);
+            caret_left = OUTPUT_CHUNK.saturating_sub(caret_at);
+        }
+        let caret_left = caret_left.min(u16::MAX as usize) as u16;
+
+        let mut chunks = if stream {
+            output_chunks(&output)
+        } else {
+            VecDeque::from([output])
+        };
+        let first = chunks.pop_front().unwrap_or_default();
+        let mut result = if consumed {
+            Result::send_consumed(backspace, &first)
+        } else {
+            Result::send(backspace, &first)
+        };
+        if chunks.is_empty() {
+            result.caret_left = caret_left;
+        } else {
+            result.flags |= FLAG_MORE_OUTPUT;
+            self.pending_output = chunks;
+            self.pending_caret_left = caret_left;
+        }
+        result
+    }
+
+    /// Selection keys while the candidate list is open
+    ///
+    /// Returns None to process the key normally. Digits stay VNI tone keys
+    /// while a word is being typed.
+    fn handle_candidate_key(&mut self, key: u16, shift: bool) -> Option<Result> {
+        if !self.candidate_list.is_open() {
+            return None;
+        }
+        let changed = || {
+            let mut r = Result::send_consumed(0, &[]);
+            r.flags |= FLAG_CANDIDATES_CHANGED;
+            r
+        };
+        if !self.candidate_list.is_active() {
+            // Passive list: keys type as usual, Tab activates it
+            return match key {
+                keys::TAB if !shift => {
+                    self.candidate_list.activate();
+                    self.candidate_list.move_highlight(true);
+                    Some(changed())
+                }
+                keys::ESC => {
+                    self.candidate_list.close();
+                    None
+                }
+                _ => None,
+            };
+        }
+        let navigated = self.candidate_list.highlighted_index().is_some();
+        match key {
+            keys::UP | keys::DOWN => {
+                self.candidate_list.move_highlight(key == keys::DOWN);
+                Some(changed())
+            }
+            keys::PAGE_UP => {
+                self.candidate_list.prev_page();
+                Some(changed())
+            }
+            keys::PAGE_DOWN => {
+                self.candidate_list.next_page();
+                Some(changed())
+            }
+            keys::RETURN | keys::ENTER | keys::TAB if navigated => {
+                let index = self.candidate_list.highlighted_index()?;
+                Some(self.select_candidate_at(index))
+            }
+            keys::ESC if navigated => {
+                self.candidate_list.close();
+                Some(changed())
+            }
+            keys::ESC => {
+                self.candidate_list.close();
+                None
+            }
+            _ if keys::is_number(key) && !shift => {
+                if self.method == 1 && !self.buf.is_empty() {
+                    return None;
+                }
+                let label = utils::key_to_char(key, false)?;
+                let index = self.candidate_list.index_for_label(label)?;
+                Some(self.select_candidate_at(index))
+            }
+            _ => None,
+        }
+    }
+
+    /// Recompute the published candidates after a key
+    ///
+    /// Prediction keeps the list in sync with the word being typed; custom
+    /// lists close on the next key. Returns true if the list changed.
+    fn refresh_candidates(&mut self) -> bool {
+        if self.prediction_enabled {
+            let items = self
+                .candidates(MAX_PREDICTION_CANDIDATES)
+                .into_iter()
+                .map(|c| c.text)
+                .collect();
+            self.candidate_list.set(CandidateSource::Prediction, items)
+        } else {
+            self.candidate_list.close()
+        }
+    }
+
+    /// Select candidate by absolute index and refresh the list
+    fn select_candidate_at(&mut self, index: usize) -> Result {
+        let Some(text) = self.candidate_list.items().get(index).cloned() else {
+            return Result::none();
+        };
+        let mut result = match self.candidate_list.source() {
+            CandidateSource::Prediction => self.accept_candidate(index),
+            CandidateSource::Custom => {
+                let backspace = self.buf.len() as u8;
+                let output: Vec<char> = text.chars().collect();
+                self.clear();
+                self.word_history.clear();
+                self.spaces_after_commit = 0;
+                Result::send(backspace, &output)
+            }
+        };
+        self.refresh_candidates();
+        result.flags |= FLAG_KEY_CONSUMED | FLAG_CANDIDATES_CHANGED;
+        result
+    }
+
+    /// Process a key (candidate list keys already handled)
+    fn handle_key(&mut self, key: u16, caps: bool, ctrl: bool, shift: bool) -> Result {
         // Issue #129: Process shortcuts even when IME is disabled
         // Only bypass completely for Ctrl/Cmd modifier keys
         if ctrl {
             self.clear();
             self.word_history.clear();
             self.spaces_after_commit = 0;
+            self.token.clear();
             return Result::none();
         }
 
+        self.track_token(key, caps, shift);
+        if let Some(result) = self.try_emoji_shortcode(key, shift) {
+            return result;
+        }
+
         // When IME is disabled, process shortcuts but skip Vietnamese transforms
         // This allows both word shortcuts (btw → by the way) and symbol shortcuts (-> → →)
         if !self.enabled {
@@ -592,16 +1175,18 @@ impl Engine {
                         true, // is_word_boundary = true for word shortcuts
                         input_method,
                     ) {
-                        let output: Vec<char> = m.output.chars().collect();
                         let backspace_count = m.backspace_count as u8;
                         self.shortcut_prefix.clear();
                         // For Space, include space in output; for Enter, don't
                         if key == keys::SPACE {
-                            let mut output_with_space = output;
-                            output_with_space.push(' ');
-                            return Result::send(backspace_count, &output_with_space);
+                            let mut m = m;
+                            m.output.push(' ');
+                            if m.caret_left > 0 {
+                                m.caret_left += 1;
+                            }
+                            return self.expansion(backspace_count, &m, None, false);
                         } else {
-                            return Result::send(backspace_count, &output);
+                            return self.expansion(backspace_count, &m, Some('\n'), false);
                         }
                     }
                 }
@@ -621,10 +1206,9 @@ impl Engine {
                         false,
                         input_method,
                     ) {
-                        let output: Vec<char> = m.output.chars().collect();
                         let backspace_count = (m.backspace_count as u8).saturating_sub(1);
                         self.shortcut_prefix.clear();
-                        return Result::send_consumed(backspace_count, &output);
+                        return self.expansion(backspace_count, &m, None, true);
                     }
                     return Result::none();
                 }
@@ -661,6 +1245,11 @@ impl Engine {
                 // telex_double_raw for restore which has the correct original chars.
             }
 
+            // A new word is being committed: older autocorrection can't be undone
+            if !self.buf.is_empty() {
+                self.autocorrect_undo = None;
+            }
+
             // First check for shortcut
             let shortcut_result = self.try_word_boundary_shortcut();
             if shortcut_result.action != 0 {
@@ -668,9 +1257,17 @@ impl Engine {
                 return shortcut_result;
             }
 
+            // Autocorrect known misspellings ("xuất xắc" → "xuất sắc")
+            // Buffer keeps the typed word so backspace-after-space can undo it
+            let correction = self.try_autocorrect(Some(' '));
+
             // Auto-restore: if buffer has transforms but is invalid Vietnamese,
             // restore to raw English (like ESC but triggered by space)
-            let restore_result = self.try_auto_restore_on_space();
+            let restore_result = if correction.is_some() {
+                Result::none()
+            } else {
+                self.try_auto_restore_on_space()
+            };
 
             // If auto-restore happened, repopulate buffer with plain chars from raw_input
             // This ensures word_history stores the correct restored word (not transformed)
@@ -685,8 +1282,24 @@ impl Engine {
 
             // Push buffer to history before clearing (for backspace-after-space feature)
             if !self.buf.is_empty() {
+                if restore_result.action == 0 && !self.has_non_letter_prefix {
+                    let word = match &correction {
+                        Some((_, corrected, _)) => {
+                            corrected.rsplit(' ').next().unwrap_or_default().to_string()
+                        }
+                        None => self.buf.to_full_string(),
+                    };
+                    self.learn_committed_word(&word);
+                }
                 self.word_history.push(self.buf.clone());
                 self.spaces_after_commit = 1; // First space after word
+                self.last_committed = match &correction {
+                    Some((_, corrected, _)) => corrected.rsplit(' ').next().map(str::to_string),
+                    None => Some(self.buf.to_full_string()),
+                };
+                self.autocorrect_undo = correction
+                    .as_ref()
+                    .map(|(typed, corrected, _)| (typed.clone(), corrected.clone()));
             } else if self.spaces_after_commit > 0 {
                 // Additional space after commit - increment counter
                 self.spaces_after_commit = self.spaces_after_commit.saturating_add(1);
@@ -701,7 +1314,10 @@ impl Engine {
             }
 
             self.clear();
-            return restore_result;
+            return match correction {
+                Some((_, _, result)) => result,
+                None => restore_result,
+            };
         }
 
         // ESC key: restore to raw ASCII (undo all Vietnamese transforms)
@@ -763,10 +1379,9 @@ impl Engine {
                         // Found a match! Send the replacement with key_consumed flag
                         // Note: backspace_count - 1 because current key hasn't been typed yet
                         // Example: "->" trigger has backspace_count=2, but only '-' is on screen
-                        let output: Vec<char> = m.output.chars().collect();
                         let backspace_count = (m.backspace_count as u8).saturating_sub(1);
                         self.shortcut_prefix.clear();
-                        return Result::send_consumed(backspace_count, &output);
+                        return self.expansion(backspace_count, &m, None, true);
                     }
 
                     // Issue #185: Only set saw_sentence_ending for punctuation (not Enter)
@@ -798,6 +1413,13 @@ impl Engine {
             }
             self.auto_capitalize_used = false; // Reset on word boundary
 
+            // The break key made the token an identifier ("user_", "/usr/", "foo@"):
+            // the word before it is restored to raw keys, no shortcut or autocorrect
+            let identifier = !self.buf.is_empty() && self.in_identifier();
+            if identifier {
+                self.has_non_letter_prefix = true;
+            }
+
             // Issue #167: Check for word boundary shortcuts on punctuation and ENTER
             // Example: "ko." → "không." or "ko<Enter>" → "không<Enter>"
             // ENTER doesn't have a printable char, so check it separately
@@ -816,7 +1438,20 @@ impl Engine {
                 }
             }
 
-            let restore_result = self.try_auto_restore_on_break();
+            // Autocorrect before punctuation/Enter (no undo: history is cleared)
+            self.autocorrect_undo = None;
+            if let Some((_, _, result)) = self.try_autocorrect(None) {
+                self.clear();
+                self.word_history.clear();
+                self.spaces_after_commit = 0;
+                return result;
+            }
+
+            let restore_result = if identifier {
+                self.restore_to_raw()
+            } else {
+                self.try_auto_restore_on_break()
+            };
             self.clear();
             self.word_history.clear();
             self.spaces_after_commit = 0;
@@ -845,6 +1480,14 @@ impl Engine {
                         // Mark that buffer was restored - if user types new letter,
                         // clear buffer first (they want fresh word, not append)
                         self.restored_pending_clear = true;
+
+                        // Undo autocorrect: replace corrected text + space with what was typed
+                        if let Some((typed, corrected)) = self.autocorrect_undo.take() {
+                            self.autocorrect_skip = Some(self.buf.to_full_string());
+                            let output: Vec<char> = typed.chars().collect();
+                            let backspace = corrected.chars().count() as u8 + 1;
+                            return Result::send(backspace, &output);
+                        }
                     }
                 }
                 // Delete one space
@@ -875,6 +1518,7 @@ impl Engine {
             // but actually didn't - let them start fresh on next letter input
             if self.buf.is_empty() {
                 self.restored_pending_clear = false;
+                self.raw_word = false;
                 // Restore pending_capitalize if user deleted the auto-capitalized letter
                 // This allows: ". B" → delete B → ". " → type again → auto-capitalizes
                 if self.auto_capitalize_used {
@@ -947,6 +1591,10 @@ impl Engine {
             self.raw_input.push((key, effective_caps, shift));
         }
 
+        if let Some(result) = self.try_raw_word(key, effective_caps) {
+            return result;
+        }
+
         let result = self.process(key, effective_caps, shift);
 
         // If auto-capitalize triggered for first letter of a new word and process returned none,
@@ -960,6 +1608,70 @@ impl Engine {
         result
     }
 
+    /// Keep the current token's text up to date (see `token`)
+    fn track_token(&mut self, key: u16, caps: bool, shift: bool) {
+        if key == keys::DELETE {
+            self.token.pop();
+            return;
+        }
+        let ch = if keys::is_break_ext(key, shift) {
+            break_key_to_char(key, shift)
+        } else {
+            utils::key_to_char(key, caps)
+        };
+        match ch {
+            Some(c) => self.token.push(c),
+            // Space, Enter, Tab, ESC, arrows: the token ends
+            None => self.token.clear(),
+        }
+    }
+
+    /// Type blocklisted words, identifiers and raw-prefixed tokens as raw ASCII
+    ///
+    /// When the keys typed so far match the blocklist, or the token looks like
+    /// an identifier ("getU", "user_i") or starts with a raw prefix ("@na"),
+    /// replaces the word on screen with the raw keys ("dòtf" → "dotf"). Later
+    /// keys of the same word pass through untransformed. Called after the key
+    /// is recorded in raw_input.
+    fn try_raw_word(&mut self, key: u16, caps: bool) -> Option<Result> {
+        if !keys::is_letter(key) && !keys::is_number(key) {
+            return None;
+        }
+        if self.raw_word {
+            self.buf.push(Char::new(key, caps));
+            return Some(Result::none());
+        }
+        let identifier = self.in_identifier();
+        // Prefixed tokens still match prefixed shortcuts ("#fne") at word end
+        let raw_token = identifier || self.in_raw_prefix();
+        if self.blocklist.is_empty() && !raw_token {
+            return None;
+        }
+        let raw = self.build_raw_chars_exact()?;
+        // Whole-word entries wait for the word boundary (`restore_blocked_word`)
+        if !raw_token
+            && !self
+                .blocklist
+                .matches_prefix(&raw.iter().collect::<String>())
+        {
+            return None;
+        }
+        // Identifiers never trigger shortcuts ("user_ko" is not "user_không")
+        if identifier {
+            self.has_non_letter_prefix = true;
+        }
+
+        let backspace = self.buf.len() as u8;
+        self.buf.clear();
+        for &c in &raw {
+            if let Some((k, _)) = utils::char_to_key_ext(c) {
+                self.buf.push(Char::new(k, c.is_uppercase()));
+            }
+        }
+        self.raw_word = true;
+        Some(Result::send(backspace, &raw))
+    }
+
     /// Main processing pipeline - pattern-based
     fn process(&mut self, key: u16, caps: bool, shift: bool) -> Result {
         let m = input::get(self.method);
@@ -1080,6 +1792,13 @@ impl Engine {
             }
         }
 
+        // 6. Quick Telex: doubled consonant → digraph ("cc" → "ch", "bann" → "bang")
+        if self.method == 0 && self.quick_telex {
+            if let Some(result) = self.try_quick_telex(key, caps) {
+                return result;
+            }
+        }
+
         // Not a modifier - normal letter
         self.handle_normal_letter(key, caps)
     }
@@ -1093,12 +1812,6 @@ impl Engine {
             return Result::none();
         }
 
-        // Don't trigger shortcut if word has non-letter prefix (like "149k")
-        // But DO allow shortcut_prefix (like "#fne") - that's intentional
-        if self.has_non_letter_prefix {
-            return Result::none();
-        }
-
         // Build full trigger string including shortcut_prefix if present
         let full_trigger = if self.shortcut_prefix.is_empty() {
             self.buf.to_full_string()
@@ -1117,13 +1830,21 @@ impl Engine {
         } else {
             None // Punctuation: don't append, let platform type it
         };
-        if let Some(m) =
+        // Don't trigger fixed shortcuts if word has non-letter prefix (like "149k")
+        // But DO allow shortcut_prefix (like "#fne") - that's intentional.
+        // Pattern shortcuts ("(\d+)k") still see such words.
+        let matched = if self.has_non_letter_prefix {
+            self.shortcuts
+                .try_match_pattern(&full_trigger, key_char, true, input_method)
+        } else {
             self.shortcuts
                 .try_match_for_method(&full_trigger, key_char, true, input_method)
-        {
-            let output: Vec<char> = m.output.chars().collect();
+        };
+        if let Some(m) = matched {
             // backspace_count = trigger.len() which already includes prefix (e.g., "#fne" = 4)
-            return Result::send(m.backspace_count as u8, &output);
+            // Punctuation/Enter is typed by the platform after the replacement
+            let pass_through = key_char.is_none().then_some(trigger_char);
+            return self.expansion(m.backspace_count as u8, &m, pass_through, false);
         }
 
         Result::none()
@@ -1134,6 +1855,67 @@ impl Engine {
         self.try_word_boundary_shortcut_with_char(' ')
     }
 
+    /// Last committed word, if it is still on screen right before the cursor
+    /// (exactly one space after it)
+    fn adjacent_prev_word(&self) -> Option<&str> {
+        self.last_committed
+            .as_deref()
+            .filter(|_| self.spaces_after_commit == 1 && self.word_history.len > 0)
+    }
+
+    /// Learn a committed word for prediction, with the previous word as context
+    ///
+    /// Only valid Vietnamese syllables are learned (not English or typos).
+    fn learn_committed_word(&mut self, word: &str) {
+        if !self.prediction_enabled || validate_syllable(word).is_err() {
+            return;
+        }
+        let prev = self.adjacent_prev_word().map(str::to_lowercase);
+        self.predictor.learn(prev.as_deref(), word);
+    }
+
+    /// Try autocorrect at word commit
+    ///
+    /// Checks previous word + current word first ("xuất xắc"), then the
+    /// current word alone ("nhửng"). `trigger_char` (space) is appended to the
+    /// output; punctuation is typed by the platform after the replacement.
+    /// Returns (typed, corrected, result).
+    fn try_autocorrect(&mut self, trigger_char: Option<char>) -> Option<(String, String, Result)> {
+        let skip = self.autocorrect_skip.take();
+        if !self.autocorrect_enabled
+            || self.raw_word
+            || self.buf.is_empty()
+            || self.has_non_letter_prefix
+            || !self.shortcut_prefix.is_empty()
+        {
+            return None;
+        }
+
+        let word = self.buf.to_full_string();
+        if skip.as_deref() == Some(word.as_str()) {
+            return None;
+        }
+
+        let prev = self.adjacent_prev_word();
+        let phrases = prev
+            .map(|p| format!("{} {}", p, word))
+            .into_iter()
+            .chain(std::iter::once(word.clone()));
+
+        for typed in phrases {
+            if let Some(corrected) = self.autocorrect.correct(&typed) {
+                let mut output: Vec<char> = corrected.chars().collect();
+                output.extend(trigger_char);
+                // Phrases past what one result can erase are left alone
+                let Ok(backspace) = u8::try_from(typed.chars().count()) else {
+                    continue;
+                };
+                return Some((typed, corrected, Result::send(backspace, &output)));
+            }
+        }
+        None
+    }
+
     /// Try "w" as vowel "ư" in Telex mode
     ///
     /// Rules:
@@ -2347,7 +3129,7 @@ impl Engine {
                             .filter_map(|&(k, caps, _)| utils::key_to_char(k, caps))
                             .collect::<String>()
                             .to_lowercase();
-                        if english_dict::is_english_word(&raw_str) {
+                        if self.is_foreign_word(&raw_str) {
                             // Raw input is English - don't apply delayed circumflex
                             // Let the letter be added normally, auto-restore will handle it
                         } else {
@@ -2711,7 +3493,7 @@ impl Engine {
             .filter_map(|&(k, caps, _)| utils::key_to_char(k, caps))
             .collect::<String>()
             .to_lowercase();
-        let is_english_word = english_dict::is_english_word(&raw_str);
+        let is_english_word = self.is_foreign_word(&raw_str);
 
         // Find vowel with tone mark (sắc/huyền/hỏi/ngã/nặng)
         let tone_info: Option<(usize, u8)> = self
@@ -2880,7 +3662,7 @@ impl Engine {
             .filter_map(|&(key, caps, _)| utils::key_to_char(key, caps))
             .collect::<String>()
             .to_lowercase();
-        if english_dict::is_english_word(&raw_str) {
+        if self.is_foreign_word(&raw_str) {
             return None;
         }
 
@@ -3167,6 +3949,80 @@ impl Engine {
         None
     }
 
+    /// Try Quick Telex consonant expansion
+    ///
+    /// Rules:
+    /// - Initial: "cc" → "ch", "gg" → "gi", "kk" → "kh", "nn" → "ng",
+    ///   "pp" → "ph", "qq" → "qu", "tt" → "th" (must be a valid initial)
+    /// - Final: "sacc" → "sach", "bann" → "bang" (must be a valid final)
+    /// - Third press reverts: "ccc" → "cc", then "cccc" → "ccc"
+    fn try_quick_telex(&mut self, key: u16, caps: bool) -> Option<Result> {
+        // Third press: revert expansion to literal double consonant
+        if self.last_transform == Some(Transform::QuickTelex(key)) {
+            let last = self.buf.len().checked_sub(1)?;
+            if let Some(c) = self.buf.get_mut(last) {
+                *c = Char::new(key, caps);
+            }
+            // Screen shows one char per buffer entry: drop the consumed key
+            // from raw_input so restore keeps the literal "cc" the user chose
+            self.raw_input.pop();
+            self.last_transform = None;
+            self.had_quick_telex = false;
+            self.quick_telex_reverted = true;
+            let ch = utils::key_to_char(key, caps)?;
+            return Some(Result::send(1, &[ch]));
+        }
+
+        if self.quick_telex_reverted {
+            return None;
+        }
+
+        let second = match key {
+            keys::C | keys::K | keys::P | keys::T => keys::H,
+            keys::N => keys::G,
+            keys::G => keys::I,
+            keys::Q => keys::U,
+            _ => return None,
+        };
+
+        let last = self.buf.last()?;
+        if last.key != key || last.has_tone() || last.has_mark() || last.stroke {
+            return None;
+        }
+        let len = self.buf.len();
+        let pair = [key, second];
+        let is_initial = len == 1;
+        let is_final = len >= 2 && self.buf.get(len - 2).is_some_and(|c| keys::is_vowel(c.key));
+        let allowed = (is_initial && constants::VALID_INITIALS_2.contains(&pair))
+            || (is_final && constants::VALID_FINALS_2.contains(&pair));
+        if !allowed {
+            return None;
+        }
+
+        // Result must still be valid Vietnamese ("tinn" → "ting" ok, "oann" → "oang" ok)
+        if !self.free_tone_enabled && !is_initial {
+            let mut keys: Vec<u16> = self.buf.iter().map(|c| c.key).collect();
+            keys.push(second);
+            let mut tones: Vec<u8> = self.buf.iter().map(|c| c.tone).collect();
+            tones.push(0);
+            if !is_valid_with_tones_and_foreign(&keys, &tones, self.allow_foreign_consonants) {
+                return None;
+            }
+        }
+
+        self.buf.push(Char::new(second, caps));
+        self.last_transform = Some(Transform::QuickTelex(key));
+        self.had_any_transform = true;
+        self.had_quick_telex = true;
+
+        // New final can move the tone mark ("hoaf" + "nn" → "hoàng")
+        if let Some((old_pos, _)) = self.reposition_tone_if_needed() {
+            return Some(self.rebuild_from_after_insert(old_pos));
+        }
+        let ch = utils::key_to_char(second, caps)?;
+        Some(Result::send(0, &[ch]))
+    }
+
     /// Handle normal letter input
     fn handle_normal_letter(&mut self, key: u16, caps: bool) -> Result {
         // Special case: "o" after "w→ư" should form "ươ" compound
@@ -3277,7 +4133,7 @@ impl Engine {
                     .filter_map(|&(k, caps, _)| utils::key_to_char(k, caps))
                     .collect::<String>()
                     .to_lowercase();
-                if english_dict::is_english_word(&raw_str) {
+                if self.is_foreign_word(&raw_str) {
                     // Raw input is English - skip circumflex, add vowel normally
                     // The auto-restore will handle restoring the English word
                 } else {
@@ -3656,6 +4512,9 @@ impl Engine {
         self.restored_pending_clear = false;
         self.restored_is_ascii = false;
         self.shortcut_prefix.clear();
+        self.had_quick_telex = false;
+        self.quick_telex_reverted = false;
+        self.raw_word = false;
     }
 
     /// Clear everything including word history
@@ -3663,8 +4522,10 @@ impl Engine {
     /// to prevent accidental restore from stale history
     pub fn clear_all(&mut self) {
         self.clear();
+        self.token.clear();
         self.word_history.clear();
         self.spaces_after_commit = 0;
+        self.candidate_list.close();
     }
 
     /// Get the full composed buffer as a Vietnamese string with diacritics.
@@ -3674,6 +4535,74 @@ impl Engine {
         self.buf.to_full_string()
     }
 
+    /// Suggest valid syllables for the current word (candidate popup)
+    ///
+    /// Works from the raw keystrokes, so it also helps when the buffer is
+    /// invalid and transforms stopped ("ngiaf" → "nghìa").
+    pub fn suggestions(&self, limit: usize) -> Vec<crate::suggest::Suggestion> {
+        let raw = self.get_raw_input_string_preserve_case();
+        let text = crate::suggest::compose(&raw, self.method);
+        crate::suggest::suggest_with(&text, limit, self.modern_tone)
+    }
+
+    /// Word completion/prediction candidates, best first
+    ///
+    /// While typing, completes the current word ("chuyê" → "chuyên",
+    /// "chuyển", "chuyện") using the previous word as context. Right after a
+    /// committed word and one space, predicts the next word. Completions
+    /// follow the case of the typed prefix. Empty when prediction is off.
+    pub fn candidates(&self, limit: usize) -> Vec<Candidate> {
+        if !self.prediction_enabled
+            || self.has_non_letter_prefix
+            || !self.shortcut_prefix.is_empty()
+        {
+            return Vec::new();
+        }
+        let prev = self.adjacent_prev_word();
+        if self.buf.is_empty() {
+            return prev.map_or_else(Vec::new, |p| self.predictor.predict(p, limit));
+        }
+        let typed = self.buf.to_full_string();
+        let mut list = self.predictor.complete(&typed, prev, limit);
+        for c in &mut list {
+            c.text = autocorrect::match_case(&typed, &c.text);
+        }
+        list
+    }
+
+    /// Accept candidate `index` from `candidates()`
+    ///
+    /// Replaces the current word (or inserts after the previous word) with the
+    /// candidate plus a space, and commits it like a typed space so
+    /// backspace-after-space and next-word prediction keep working.
+    /// Returns `Result::none()` when there is no such candidate.
+    pub fn accept_candidate(&mut self, index: usize) -> Result {
+        let Some(candidate) = self
+            .candidates(index.saturating_add(1))
+            .into_iter()
+            .nth(index)
+        else {
+            return Result::none();
+        };
+        let text = candidate.text;
+        let backspace = self.buf.len() as u8;
+        let mut output: Vec<char> = text.chars().collect();
+        output.push(' ');
+
+        let prev = self.adjacent_prev_word().map(str::to_lowercase);
+        self.predictor.learn(prev.as_deref(), &text);
+
+        // Commit the candidate as if it had been typed and followed by space
+        self.auto_capitalize_used = false;
+        self.restore_word(&text);
+        self.word_history.push(self.buf.clone());
+        self.spaces_after_commit = 1;
+        self.last_committed = Some(text);
+        self.autocorrect_undo = None;
+        self.clear();
+        Result::send(backspace, &output)
+    }
+
     /// Debug: Check if vowel-triggered circumflex flag is set
     pub fn had_vowel_circumflex(&self) -> bool {
         self.had_vowel_triggered_circumflex
@@ -3726,12 +4655,103 @@ impl Engine {
     /// `is_word_complete`: true when called on space/break (word is complete)
     ///                     false when called mid-word (during typing)
     fn should_auto_restore(&self, is_word_complete: bool) -> Option<Vec<char>> {
+        if let Some(raw) = self.restore_blocked_word() {
+            return Some(raw);
+        }
+        let restored = self.restore_by_rules(is_word_complete);
+        if restored.is_some() || !is_word_complete {
+            return restored;
+        }
+        self.restore_by_language_pack()
+            .or_else(|| self.restore_by_classifier())
+    }
+
+    /// Raw keystrokes of a complete word that is a whole-word blocklist
+    /// entry, if they differ from what is on screen
+    ///
+    /// Applies whether or not auto-restore is enabled.
+    fn restore_blocked_word(&self) -> Option<Vec<char>> {
+        if self.blocklist.is_empty() || self.buf.is_empty() || self.raw_word {
+            return None;
+        }
+        let raw = self.build_raw_chars_exact()?;
+        let raw_str: String = raw.iter().collect();
+        (self.blocklist.matches_word(&raw_str) && raw_str != self.buf.to_full_string())
+            .then_some(raw)
+    }
+
+    /// Raw keystrokes of a complete word the rules kept, if it may be restored
+    ///
+    /// Shared gate for the fallbacks below: never for blocked or user-removed
+    /// words, nor when the keystrokes were left untouched.
+    fn fallback_restore_candidate(&self) -> Option<(Vec<char>, String)> {
+        if !self.english_auto_restore {
+            return None;
+        }
+        if self.raw_input.is_empty() || self.buf.is_empty() || self.raw_word {
+            return None;
+        }
+        if !self.had_any_transform {
+            return None;
+        }
+        let raw = self.build_raw_chars_exact()?;
+        let raw_str: String = raw.iter().collect();
+        if raw_str == self.buf.to_full_string() {
+            return None;
+        }
+        if self.user_dictionary.lookup(&raw_str) == Some(false) {
+            return None;
+        }
+        Some((raw, raw_str))
+    }
+
+    /// Restore words from enabled non-English language packs
+    ///
+    /// The rules are tuned for English; a French or Spanish word is restored
+    /// whenever Telex turned it into something that isn't a known Vietnamese
+    /// word: "adresse" (not "adrese"), "heure" (not "hểu"), but "cas" stays "cá".
+    fn restore_by_language_pack(&self) -> Option<Vec<char>> {
+        if self.language_packs & !LanguagePack::English.bit() == 0 {
+            return None;
+        }
+        let (raw, raw_str) = self.fallback_restore_candidate()?;
+        let in_pack = LanguagePack::ALL.iter().any(|&pack| {
+            pack != LanguagePack::English
+                && self.language_pack_enabled(pack)
+                && pack.contains(&raw_str)
+        });
+        if !in_pack {
+            return None;
+        }
+        let output = self.buf.to_full_string().to_lowercase();
+        (vietnamese_freq::unigram_count(&output) == 0).then_some(raw)
+    }
+
+    /// Statistical fallback when the rules keep the word
+    ///
+    /// Scores the raw keystrokes with the n-gram classifier: "ebooks" or
+    /// "rebase" never look like Telex for a Vietnamese syllable.
+    fn restore_by_classifier(&self) -> Option<Vec<char>> {
+        if !self.english_classifier {
+            return None;
+        }
+        let (raw, raw_str) = self.fallback_restore_candidate()?;
+        // Too few trigrams to tell "des" from "dé"
+        if raw_str.len() < MIN_CLASSIFIER_WORD_LEN {
+            return None;
+        }
+        let score = ngram::english_score(&raw_str)?;
+        (score > self.english_classifier_threshold).then_some(raw)
+    }
+
+    /// Rule-based auto-restore (dictionary, phonology, Telex patterns)
+    fn restore_by_rules(&self, is_word_complete: bool) -> Option<Vec<char>> {
         // Only run auto-restore if the feature is enabled
         if !self.english_auto_restore {
             return None;
         }
 
-        if self.raw_input.is_empty() || self.buf.is_empty() {
+        if self.raw_input.is_empty() || self.buf.is_empty() || self.raw_word {
             return None;
         }
 
@@ -3744,6 +4764,24 @@ impl Engine {
             return None;
         }
 
+        // User dictionary has priority over all heuristics below:
+        // added words always restore, removed words never do
+        if is_word_complete {
+            if let Some(raw) = self.build_raw_chars_exact() {
+                match self.user_dictionary.lookup(&raw.iter().collect::<String>()) {
+                    Some(true) => return Some(raw),
+                    Some(false) if !self.is_buffer_invalid_vietnamese() => return None,
+                    _ => {}
+                }
+            }
+        }
+
+        // Quick Telex: doubled consonants are everyday English ("running", "annex")
+        // If the raw keystrokes form a known English word, restore them
+        if self.had_quick_telex && self.is_foreign_word(&self.get_raw_input_string()) {
+            return self.build_raw_chars_exact();
+        }
+
         // Issue #211: Skip auto-restore for extended vowel patterns
         // When user types "áaa" or "hảaa", this is intentional Vietnamese (casual messaging)
         // not English that needs to be restored. Detect by checking if:
@@ -3844,7 +4882,7 @@ impl Engine {
 
                 let has_stroke = self.buf.iter().any(|c| c.stroke);
                 let buffer_invalid_vn = self.is_buffer_invalid_vietnamese();
-                let raw_in_english_dict = english_dict::is_english_word(&raw_str);
+                let raw_in_english_dict = self.is_foreign_word(&raw_str);
 
                 // W at end pattern: foreign words like moscow, warsaw, saw, law
                 let w_at_end = self
@@ -3868,7 +4906,7 @@ impl Engine {
                     // If buffer is a known English word, keep it (e.g., "lissa" → "lisa")
                     // If buffer is NOT a known word, restore original (e.g., "larissa" → "larissa")
                     let buffer_str = self.get_buffer_string().to_lowercase();
-                    if !english_dict::is_english_word(&buffer_str) {
+                    if !self.is_foreign_word(&buffer_str) {
                         // Buffer not in dict → restore to original English
                         return self.build_raw_chars_exact();
                     }
@@ -3899,7 +4937,7 @@ impl Engine {
 
                         if is_double_ss || is_double_ff {
                             let original_lower = stored.to_lowercase();
-                            if english_dict::is_english_word(&original_lower) {
+                            if self.is_foreign_word(&original_lower) {
                                 // EXCEPTIONS: "off", "iff", "ass" should keep reverted form
                                 let is_exception = if chars.len() == 3 {
                                     let first = chars[0].to_ascii_lowercase();
@@ -3969,7 +5007,7 @@ impl Engine {
                 //    - "herer": e-r-e-r (V-M-V-M) → keep "her"
                 //    - "harare": a-r-a-r-e (different vowels a≠e) → skip fix
                 let raw_input_str = self.get_raw_input_string();
-                let raw_is_english = english_dict::is_english_word(&raw_input_str);
+                let raw_is_english = self.is_foreign_word(&raw_input_str);
                 let chars: Vec<char> = raw_input_str.chars().collect();
 
                 if !raw_is_english && chars.len() >= 4 {
@@ -5289,7 +6327,7 @@ impl Engine {
                             .iter()
                             .filter_map(|&(k, c, s)| utils::key_to_char_ext(k, c, s))
                             .collect();
-                        let raw_in_dict = english_dict::is_english_word(&raw_str);
+                        let raw_in_dict = self.is_foreign_word(&raw_str);
 
                         // If raw is NOT in English dict AND buffer is valid Vietnamese, keep it
                         if !raw_in_dict && !self.is_buffer_invalid_vietnamese() {
@@ -5816,7 +6854,7 @@ impl Engine {
                                 .iter()
                                 .filter_map(|&(k, c, s)| utils::key_to_char_ext(k, c, s))
                                 .collect();
-                            if english_dict::is_english_word(&raw_str) {
+                            if self.is_foreign_word(&raw_str) {
                                 return true; // Restore to English
                             }
                             // Not English word, keep Vietnamese
@@ -5881,7 +6919,7 @@ impl Engine {
                                                 utils::key_to_char_ext(k, c, s)
                                             })
                                             .collect();
-                                        if english_dict::is_english_word(&raw_str) {
+                                        if self.is_foreign_word(&raw_str) {
                                             return true; // English word (param, etc.)
                                         }
                                         // Not in English dict → keep Vietnamese (vận, hận, etc.)
@@ -5968,7 +7006,7 @@ impl Engine {
                                     .iter()
                                     .filter_map(|&(k, c, s)| utils::key_to_char_ext(k, c, s))
                                     .collect();
-                                if !english_dict::is_english_word(&raw_str) {
+                                if !self.is_foreign_word(&raw_str) {
                                     // Not a common English word, keep Vietnamese
                                     continue;
                                 }
@@ -6016,7 +7054,7 @@ impl Engine {
                                     .iter()
                                     .filter_map(|&(k, c, s)| utils::key_to_char_ext(k, c, s))
                                     .collect();
-                                if !english_dict::is_english_word(&raw_str) {
+                                if !self.is_foreign_word(&raw_str) {
                                     // Not a common English word, keep Vietnamese
                                     continue;
                                 }
@@ -0,0 +1,362 @@
+//! Word Prediction - completion and next-word candidates
+//!
+//! Ranks syllables from the bundled frequency list
+//! (`data::vietnamese_freq`) together with counts learned on device from the
+//! words the user commits. The previous word boosts candidates that often
+//! follow it, so "chuyê" after "môn" differs from "chuyê" at sentence start.
+//!
+//! Learned counts can be exported and imported ("<count>\t<text>" lines, the
+//! same format as the bundled list) so platforms can persist them.
+//!
+//! Learned data is bounded: past `MAX_LEARNED_WORDS` words or
+//! `MAX_CONTEXTS` previous words the least used are dropped, and each
+//! previous word keeps its `MAX_FOLLOWERS` most frequent next words.
+
+use crate::data::chars::mark;
+use crate::data::vietnamese_freq as freq;
+use std::collections::{BTreeSet, HashMap};
+
+/// Learned counts weigh more than bundled ones: the user's own words win
+const LEARNED_WEIGHT: u32 = 4;
+/// Weight of "previous word + candidate" pair counts
+const CONTEXT_WEIGHT: u32 = 8;
+/// Words longer than this are never learned (pasted text, garbage)
+const MAX_WORD_CHARS: usize = 12;
+/// Learned words kept before the least used are dropped
+pub const MAX_LEARNED_WORDS: usize = 20_000;
+/// Previous words with learned pairs kept before the least used are dropped
+pub const MAX_CONTEXTS: usize = 5_000;
+/// Next words kept per previous word
+pub const MAX_FOLLOWERS: usize = 32;
+
+/// A ranked candidate
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    /// Candidate word, lowercase
+    pub text: String,
+    /// Ranking score (higher is better)
+    pub score: u32,
+}
+
+/// Completion and next-word predictor
+#[derive(Debug, Clone, Default)]
+pub struct Predictor {
+    /// Learned word counts (lowercase)
+    unigrams: HashMap<String, u32>,
+    /// Learned words as (mark-stripped key, word), for prefix search
+    index: BTreeSet<(String, String)>,
+    /// Learned pair counts: previous word → next word → count
+    bigrams: HashMap<String, HashMap<String, u32>>,
+}
+
+impl Predictor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a committed word and the word before it
+    pub fn learn(&mut self, prev: Option<&str>, word: &str) {
+        let word = word.to_lowercase();
+        if word.is_empty() || word.chars().count() > MAX_WORD_CHARS {
+            return;
+        }
+        if let Some(prev) = prev.map(str::to_lowercase).filter(|p| !p.is_empty()) {
+            let next = self.bigrams.entry(prev).or_default();
+            *next.entry(word.clone()).or_insert(0) += 1;
+            evict_least_used(next, MAX_FOLLOWERS, &word);
+        }
+        self.add_unigram(word, 1);
+        self.prune();
+    }
+
+    /// Add to a learned word's count, indexing words seen for the first time
+    fn add_unigram(&mut self, word: String, count: u32) {
+        match self.unigrams.get_mut(&word) {
+            Some(c) => *c = c.saturating_add(count),
+            None => {
+                self.index.insert((freq::strip_marks(&word), word.clone()));
+                self.unigrams.insert(word, count);
+            }
+        }
+    }
+
+    /// Drop the least used words and contexts once past the limits
+    ///
+    /// Cuts down to three quarters of each limit so pruning runs rarely.
+    fn prune(&mut self) {
+        if self.unigrams.len() > MAX_LEARNED_WORDS {
+            keep_most_used(&mut self.unigrams, MAX_LEARNED_WORDS * 3 / 4, |&c| c);
+            let unigrams = &self.unigrams;
+            self.index.retain(|(_, word)| unigrams.contains_key(word));
+        }
+        if self.bigrams.len() > MAX_CONTEXTS {
+            keep_most_used(&mut self.bigrams, MAX_CONTEXTS * 3 / 4, |next| {
+                next.values().fold(0u32, |sum, &c| sum.saturating_add(c))
+            });
+        }
+    }
+
+    /// Complete a partial word (case-insensitive)
+    ///
+    /// The typed prefix itself is not a candidate. Example: "chuyê" →
+    /// "chuyên", "chuyển", "chuyện", ...
+    pub fn complete(&self, prefix: &str, prev: Option<&str>, limit: usize) -> Vec<Candidate> {
+        let prefix = prefix.to_lowercase();
+        let prev = prev.map(str::to_lowercase);
+        if prefix.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut scores: HashMap<String, u32> = HashMap::new();
+        for (word, _) in freq::completions(&prefix) {
+            scores.insert(word.to_string(), 0);
+        }
+        // Learned words missing from the bundled list
+        let key = freq::strip_marks(&prefix);
+        let wanted = freq::mark_of(&prefix);
+        let learned = self
+            .index
+            .range((key.clone(), String::new())..)
+            .take_while(|(k, _)| k.starts_with(&key))
+            .filter(|(_, word)| wanted == mark::NONE || freq::mark_of(word) == wanted);
+        for (_, word) in learned {
+            scores.insert(word.clone(), 0);
+        }
+        scores.remove(&prefix);
+
+        let candidates = scores.into_keys().map(|word| {
+            let score = self.score(prev.as_deref(), &word);
+            Candidate { text: word, score }
+        });
+        rank(candidates, limit)
+    }
+
+    /// Predict the word following `prev` (case-insensitive)
+    pub fn predict(&self, prev: &str, limit: usize) -> Vec<Candidate> {
+        let prev = prev.to_lowercase();
+        if prev.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut words: Vec<&str> = freq::followers(&prev).iter().map(|&(w, _)| w).collect();
+        if let Some(learned) = self.bigrams.get(&prev) {
+            words.extend(learned.keys().map(String::as_str));
+        }
+        words.sort_unstable();
+        words.dedup();
+
+        let candidates = words.into_iter().map(|word| Candidate {
+            text: word.to_string(),
+            score: self.pair_count(&prev, word),
+        });
+        rank(candidates, limit)
+    }
+
+    /// Export learned counts as "<count>\t<text>" lines
+    pub fn export_learned(&self) -> String {
+        let mut lines: Vec<String> = self
+            .unigrams
+            .iter()
+            .map(|(w, c)| format!("{}\t{}", c, w))
+            .chain(self.bigrams.iter().flat_map(|(prev, next)| {
+                next.iter()
+                    .map(move |(w, c)| format!("{}\t{} {}", c, prev, w))
+            }))
+            .collect();
+        lines.sort();
+        lines.join("\n")
+    }
+
+    /// Import learned counts exported by `export_learned`, adding to current ones
+    ///
+    /// Returns the number of entries read. Malformed lines are skipped.
+    pub fn import_learned(&mut self, data: &str) -> usize {
+        let mut imported = 0;
+        for line in data.lines() {
+            let Some((count, text)) = line.trim().split_once('\t') else {
+                continue;
+            };
+            let Ok(count) = count.parse::<u32>() else {
+                continue;
+            };
+            let text = text.trim().to_lowercase();
+            match text.split_once(' ') {
+                Some((prev, word)) => {
+                    let next = self.bigrams.entry(prev.to_string()).or_default();
+                    let entry = next.entry(word.to_string()).or_insert(0);
+                    *entry = entry.saturating_add(count);
+                    evict_least_used(next, MAX_FOLLOWERS, word);
+                }
+                None if !text.is_empty() => self.add_unigram(text, count),
+                None => continue,
+            }
+            imported += 1;
+        }
+        self.prune();
+        imported
+    }
+
+    /// Forget everything learned (bundled list is kept)
+    pub fn clear_learned(&mut self) {
+        self.unigrams.clear();
+        self.index.clear();
+        self.bigrams.clear();
+    }
+
+    /// Number of learned words
+    pub fn learned_len(&self) -> usize {
+        self.unigrams.len()
+    }
+
+    fn score(&self, prev: Option<&str>, word: &str) -> u32 {
+        let learned = self.unigrams.get(word).copied().unwrap_or(0);
+        let context = prev.map_or(0, |p| self.pair_count(p, word));
+        freq::unigram_count(word)
+            .saturating_add(learned.saturating_mul(LEARNED_WEIGHT))
+            .saturating_add(context.saturating_mul(CONTEXT_WEIGHT))
+    }
+
+    fn pair_count(&self, prev: &str, word: &str) -> u32 {
+        let learned = self
+            .bigrams
+            .get(prev)
+            .and_then(|next| next.get(word))
+            .copied()
+            .unwrap_or(0);
+        freq::bigram_count(prev, word).saturating_add(learned.saturating_mul(LEARNED_WEIGHT))
+    }
+}
+
+/// Keep the `keep` entries with the highest weight (ties alphabetically)
+fn keep_most_used<V>(map: &mut HashMap<String, V>, keep: usize, weight: impl Fn(&V) -> u32) {
+    let mut ranked: Vec<(u32, String)> = map.iter().map(|(k, v)| (weight(v), k.clone())).collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    for (_, key) in ranked.into_iter().skip(keep) {
+        map.remove(&key);
+    }
+}
+
+/// Past `max` entries, drop the least used one other than `keep`
+/// (the word just learned, so new words can get in)
+fn evict_least_used(map: &mut HashMap<String, u32>, max: usize, keep: &str) {
+    if map.len() <= max {
+        return;
+    }
+    let victim = map
+        .iter()
+        .filter(|(k, _)| k.as_str() != keep)
+        .min_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(k, _)| k.clone());
+    if let Some(victim) = victim {
+        map.remove(&victim);
+    }
+}
+
+/// Sort by score (ties alphabetically) and keep the best `limit`
+fn rank(candidates: impl Iterator<Item = Candidate>, limit: usize) -> Vec<Candidate> {
+    let mut list: Vec<Candidate> = candidates.collect();
+    list.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.text.cmp(&b.text)));
+    list.truncate(limit);
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(list: &[Candidate]) -> Vec<&str> {
+        list.iter().map(|c| c.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_complete_from_bundled() {
+        let p = Predictor::new();
+        let list = p.complete("chuyê", None, 10);
+        for w in ["chuyên", "chuyển", "chuyện"] {
+            assert!(texts(&list).contains(&w), "missing {} in {:?}", w, list);
+        }
+        assert!(list.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn test_complete_excludes_prefix() {
+        let p = Predictor::new();
+        assert!(!texts(&p.complete("chuyên", None, 20)).contains(&"chuyên"));
+    }
+
+    #[test]
+    fn test_learned_words_rank_first() {
+        let mut p = Predictor::new();
+        for _ in 0..20 {
+            p.learn(None, "chuyện");
+        }
+        assert_eq!(p.complete("chuyê", None, 1)[0].text, "chuyện");
+    }
+
+    #[test]
+    fn test_complete_learned_words() {
+        let mut p = Predictor::new();
+        p.learn(None, "xyzơi");
+        p.import_learned("3\txyzới");
+        assert_eq!(texts(&p.complete("xyz", None, 10)), ["xyzới", "xyzơi"]);
+        assert_eq!(texts(&p.complete("xyzớ", None, 10)), ["xyzới"]);
+        p.clear_learned();
+        assert!(p.complete("xyz", None, 10).is_empty());
+    }
+
+    #[test]
+    fn test_context_boost() {
+        let mut p = Predictor::new();
+        p.learn(Some("câu"), "chuyền");
+        p.learn(Some("câu"), "chuyền");
+        let list = p.complete("chuyê", Some("Câu"), 1);
+        assert_eq!(list[0].text, "chuyền");
+    }
+
+    #[test]
+    fn test_predict_next_word() {
+        let mut p = Predictor::new();
+        assert!(texts(&p.predict("chuyên", 10)).contains(&"môn"));
+        p.learn(Some("xin"), "chào");
+        assert_eq!(p.predict("Xin", 1)[0].text, "chào");
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let mut p = Predictor::new();
+        p.learn(Some("xin"), "chào");
+        p.learn(None, "xin");
+        let data = p.export_learned();
+
+        let mut q = Predictor::new();
+        assert_eq!(q.import_learned(&data), 3);
+        assert_eq!(q.export_learned(), data);
+        assert_eq!(q.import_learned("bad line\n\tx\nx\t1"), 0);
+
+        q.clear_learned();
+        assert_eq!(q.learned_len(), 0);
+    }
+
+    #[test]
+    fn test_learned_data_is_bounded() {
+        let mut p = Predictor::new();
+        for _ in 0..5 {
+            p.learn(Some("xin"), "chào");
+        }
+        // Many one-off followers and words: the frequent ones survive
+        for i in 0..MAX_LEARNED_WORDS + 10 {
+            let word = format!("w{}", i);
+            p.learn(Some("xin"), &word);
+            p.learn(Some(&word), "chào");
+        }
+        assert!(p.learned_len() <= MAX_LEARNED_WORDS);
+        assert_eq!(p.index.len(), p.learned_len());
+        assert!(p.bigrams.len() <= MAX_CONTEXTS);
+        assert_eq!(p.bigrams["xin"].len(), MAX_FOLLOWERS);
+        assert_eq!(p.predict("xin", 1)[0].text, "chào");
+        assert!(p.unigrams.contains_key("chào"));
+        // The latest word made it in
+        let last = format!("w{}", MAX_LEARNED_WORDS + 9);
+        assert!(p.bigrams["xin"].contains_key(&last));
+    }
+}
@@ -0,0 +1,272 @@
+//! Shortcut Analysis - likely mistakes in a set of shortcuts
+//!
+//! Triggers that are real words ("an", "la", "hoa") expand whenever the
+//! word is typed, which users read as the IME breaking. `analyze` flags
+//! those and other trouble spots for the settings UI and importers:
+//! Vietnamese syllables, common English words, immediate triggers that
+//! start or end other triggers (they fire before the longer one is typed;
+//! word boundary triggers only match whole words, so they never do),
+//! triggers differing only in case, and triggers or replacements past the
+//! engine's limits.
+
+use super::trie::TriggerTrie;
+use super::TriggerCondition;
+use crate::data::english_dict;
+use crate::engine::buffer;
+use crate::engine::validation;
+use std::collections::HashMap;
+
+/// Triggers longer than the word buffer can never match
+pub const MAX_TRIGGER_CHARS: usize = buffer::MAX;
+
+/// Replacements past this many chars take many output parts and stall typing
+pub const MAX_REPLACEMENT_CHARS: usize = 4096;
+
+/// What looks wrong with a shortcut
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WarningKind {
+    /// Trigger is a valid Vietnamese syllable: typing the word expands it
+    VietnameseWord,
+    /// Trigger is a common English word
+    EnglishWord,
+    /// Longer triggers start with this immediate one ("=>" / "=>>")
+    PrefixOf(Vec<String>),
+    /// Longer triggers end with this immediate one (">" / "->")
+    SuffixOf(Vec<String>),
+    /// Other triggers differ only in case ("VN" / "vn"); only one is kept
+    CaseDuplicate(Vec<String>),
+    /// Trigger has more than `MAX_TRIGGER_CHARS` chars
+    TriggerTooLong(usize),
+    /// Replacement has more than `MAX_REPLACEMENT_CHARS` chars
+    ReplacementTooLong(usize),
+}
+
+impl WarningKind {
+    /// Short name used over FFI ("vietnamese_word", "prefix_of:vnd,vnx")
+    pub fn name(&self) -> String {
+        match self {
+            WarningKind::VietnameseWord => "vietnamese_word".into(),
+            WarningKind::EnglishWord => "english_word".into(),
+            WarningKind::PrefixOf(others) => format!("prefix_of:{}", others.join(",")),
+            WarningKind::SuffixOf(others) => format!("suffix_of:{}", others.join(",")),
+            WarningKind::CaseDuplicate(others) => format!("case_duplicate:{}", others.join(",")),
+            WarningKind::TriggerTooLong(n) => format!("trigger_too_long:{}", n),
+            WarningKind::ReplacementTooLong(n) => format!("replacement_too_long:{}", n),
+        }
+    }
+}
+
+/// A warning about one shortcut
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// Position of the shortcut in the analyzed list
+    pub index: usize,
+    /// Trigger as given
+    pub trigger: String,
+    pub kind: WarningKind,
+}
+
+/// Check (trigger, replacement, condition) triples, triggers as the user
+/// wrote them
+///
+/// Warnings come in input order. Exact repeats of a trigger are not
+/// reported here; importers report them as conflicts.
+pub fn analyze<'a>(
+    shortcuts: impl IntoIterator<Item = (&'a str, &'a str, TriggerCondition)>,
+) -> Vec<Warning> {
+    let shortcuts: Vec<(&str, &str, TriggerCondition)> = shortcuts.into_iter().collect();
+    let mut forward = TriggerTrie::new();
+    let mut backward = TriggerTrie::new();
+    let mut spellings: HashMap<String, Vec<&str>> = HashMap::new();
+    for &(trigger, _, _) in &shortcuts {
+        let lower = trigger.to_lowercase();
+        forward.insert(&lower);
+        backward.insert(&reversed(&lower));
+        let entry = spellings.entry(lower).or_default();
+        if !entry.contains(&trigger) {
+            entry.push(trigger);
+        }
+    }
+
+    let mut warnings = Vec::new();
+    for (index, &(trigger, replacement, condition)) in shortcuts.iter().enumerate() {
+        let mut warn = |kind| {
+            warnings.push(Warning {
+                index,
+                trigger: trigger.to_string(),
+                kind,
+            })
+        };
+        let lower = trigger.to_lowercase();
+
+        let trigger_len = lower.chars().count();
+        if trigger_len > MAX_TRIGGER_CHARS {
+            warn(WarningKind::TriggerTooLong(trigger_len));
+        }
+        if lower.chars().any(char::is_alphabetic) && validation::validate_syllable(&lower).is_ok() {
+            warn(WarningKind::VietnameseWord);
+        }
+        if english_dict::is_english_word(&lower) {
+            warn(WarningKind::EnglishWord);
+        }
+
+        if condition == TriggerCondition::Immediate {
+            let longer: Vec<String> = forward
+                .with_prefix(&lower)
+                .into_iter()
+                .filter(|other| *other != lower)
+                .map(String::from)
+                .collect();
+            if !longer.is_empty() {
+                warn(WarningKind::PrefixOf(longer));
+            }
+            let mut ending: Vec<String> = backward
+                .with_prefix(&reversed(&lower))
+                .into_iter()
+                .map(reversed)
+                .filter(|other| *other != lower)
+                .collect();
+            if !ending.is_empty() {
+                ending.sort();
+                warn(WarningKind::SuffixOf(ending));
+            }
+        }
+
+        let others: Vec<String> = spellings[&lower]
+            .iter()
+            .filter(|other| **other != trigger)
+            .map(|other| other.to_string())
+            .collect();
+        if !others.is_empty() {
+            warn(WarningKind::CaseDuplicate(others));
+        }
+
+        let replacement_len = replacement.chars().count();
+        if replacement_len > MAX_REPLACEMENT_CHARS {
+            warn(WarningKind::ReplacementTooLong(replacement_len));
+        }
+    }
+    warnings
+}
+
+fn reversed(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORD: TriggerCondition = TriggerCondition::OnWordBoundary;
+    const IMMEDIATE: TriggerCondition = TriggerCondition::Immediate;
+
+    /// Warnings for `trigger`; shortcuts are word boundary unless listed in
+    /// `immediate`
+    fn kinds(shortcuts: &[(&str, &str)], immediate: &[&str], trigger: &str) -> Vec<WarningKind> {
+        let condition = |t: &str| {
+            if immediate.contains(&t) {
+                IMMEDIATE
+            } else {
+                WORD
+            }
+        };
+        analyze(shortcuts.iter().map(|&(t, r)| (t, r, condition(t))))
+            .into_iter()
+            .filter(|w| w.trigger == trigger)
+            .map(|w| w.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_real_words() {
+        let shortcuts = [
+            ("hoa", "Hòa"),
+            ("an", "anh"),
+            ("but", "nhưng"),
+            ("vn", "Việt Nam"),
+            ("->", "→"),
+        ];
+        assert_eq!(kinds(&shortcuts, &[], "hoa"), [WarningKind::VietnameseWord]);
+        assert_eq!(
+            kinds(&shortcuts, &[], "an"),
+            [WarningKind::VietnameseWord, WarningKind::EnglishWord]
+        );
+        assert_eq!(kinds(&shortcuts, &[], "but"), [WarningKind::EnglishWord]);
+        assert!(kinds(&shortcuts, &[], "vn").is_empty());
+        assert!(kinds(&shortcuts, &["->"], "->").is_empty());
+    }
+
+    #[test]
+    fn test_prefix_suffix() {
+        let shortcuts = [("=>", ""), ("=>>", ""), ("=>|", ""), (">", ""), ("->", "")];
+        let all = ["=>", "=>>", "=>|", ">", "->"];
+        assert_eq!(
+            kinds(&shortcuts, &all, "=>"),
+            [WarningKind::PrefixOf(vec!["=>>".into(), "=>|".into()])]
+        );
+        assert_eq!(
+            kinds(&shortcuts, &all, ">"),
+            [WarningKind::SuffixOf(vec![
+                "->".into(),
+                "=>".into(),
+                "=>>".into()
+            ])]
+        );
+        assert!(kinds(&shortcuts, &all, "=>|").is_empty());
+    }
+
+    #[test]
+    fn test_word_boundary_overlap_is_fine() {
+        // Whole-word triggers never fire inside a longer trigger
+        let shortcuts = [("vn", "Việt Nam"), ("vnd", "₫"), ("nd", "nữa"), ("=>", "")];
+        for trigger in ["vn", "nd", "=>"] {
+            let overlap = kinds(&shortcuts, &[], trigger)
+                .into_iter()
+                .any(|k| matches!(k, WarningKind::PrefixOf(_) | WarningKind::SuffixOf(_)));
+            assert!(!overlap, "{}", trigger);
+        }
+        // The shorter trigger's condition is what matters
+        assert_eq!(
+            kinds(&shortcuts, &["vn"], "vn"),
+            [WarningKind::PrefixOf(vec!["vnd".into()])]
+        );
+        assert!(kinds(&shortcuts, &["vnd"], "vn").is_empty());
+    }
+
+    #[test]
+    fn test_case_duplicates() {
+        let warnings = analyze([("VN", "A", WORD), ("vn", "B", WORD), ("vn", "C", WORD)]);
+        let got: Vec<(usize, &str)> = warnings
+            .iter()
+            .map(|w| (w.index, w.trigger.as_str()))
+            .collect();
+        assert_eq!(got, [(0, "VN"), (1, "vn"), (2, "vn")]);
+        assert_eq!(
+            warnings[0].kind,
+            WarningKind::CaseDuplicate(vec!["vn".into()])
+        );
+        assert_eq!(
+            warnings[1].kind,
+            WarningKind::CaseDuplicate(vec!["VN".into()])
+        );
+    }
+
+    #[test]
+    fn test_limits() {
+        let trigger = "x".repeat(MAX_TRIGGER_CHARS + 1);
+        let replacement = "y".repeat(MAX_REPLACEMENT_CHARS + 1);
+        let warnings = analyze([(trigger.as_str(), replacement.as_str(), WORD)]);
+        let kinds: Vec<WarningKind> = warnings.into_iter().map(|w| w.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                WarningKind::TriggerTooLong(MAX_TRIGGER_CHARS + 1),
+                WarningKind::ReplacementTooLong(MAX_REPLACEMENT_CHARS + 1),
+            ]
+        );
+        assert_eq!(
+            WarningKind::PrefixOf(vec!["vnd".into(), "vnx".into()]).name(),
+            "prefix_of:vnd,vnx"
+        );
+    }
+}
@@ -0,0 +1,1017 @@
+//! Shortcut Files - import/export for users migrating from other IMEs
+//!
+//! UniKey, OpenKey and EVKey store macros as text, one per line, with the
+//! trigger and replacement split at the first `:`. A header line starting
+//! with `;` names the program; other `;` lines are comments:
+//!
+//! ```text
+//! ;DO NOT DELETE THIS LINE*** version=1 ***
+//! vn:Việt Nam
+//! sig:Trân trọng,\nNam
+//! ```
+//!
+//! Line breaks and tabs in replacements are written as `\n` and `\t`
+//! (`\\` for a backslash). These files carry no per-macro options, so
+//! `ImportOptions` decides the trigger condition, case mode and input
+//! method of every imported shortcut.
+//!
+//! The JSON format keeps every `Shortcut` field. Fields other than
+//! `trigger` and `replacement` are optional and default to the import
+//! options; a bare array of shortcuts is accepted too:
+//!
+//! ```text
+//! {
+//!   "version": 1,
+//!   "shortcuts": [
+//!     {"trigger": "vn", "replacement": "Việt Nam", "condition": "word_boundary", "case": "match", "input_method": "all", "enabled": true},
+//!     {"trigger": "->", "replacement": "→", "condition": "immediate", "case": "exact", "input_method": "telex", "enabled": false},
+//!     {"trigger": "(\\d+)k", "replacement": "$1.000 đ", "condition": "word_boundary", "case": "match", "input_method": "all", "enabled": true, "pattern": true}
+//!   ]
+//! }
+//! ```
+//!
+//! `"pattern": true` marks a pattern trigger (see `pattern`); only JSON
+//! carries those.
+
+use super::analysis::{self, Warning};
+use super::template::Template;
+use super::{CaseMode, InputMethod, Shortcut, TriggerCondition};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const UNIKEY_HEADER: &str = ";DO NOT DELETE THIS LINE*** version=1 ***";
+const OPENKEY_HEADER: &str = ";Compatible OpenKey Macro Data file for UniKey*** version=1 ***";
+const JSON_VERSION: u32 = 1;
+
+/// Import option flags used over FFI
+pub const IMPORT_CASE_SENSITIVE: u8 = 0x01;
+pub const IMPORT_IMMEDIATE: u8 = 0x02;
+pub const IMPORT_OVERWRITE: u8 = 0x04;
+
+/// Shortcut file format (ids are stable, used over FFI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ShortcutFormat {
+    /// UniKey macro file
+    UniKey = 0,
+    /// OpenKey macro export (UniKey format with its own header)
+    OpenKey = 1,
+    /// EVKey macro file (reads and writes UniKey files)
+    EVKey = 2,
+    /// Gõ Nhanh JSON (see module docs)
+    Json = 3,
+}
+
+impl ShortcutFormat {
+    pub const ALL: [ShortcutFormat; 4] = [
+        ShortcutFormat::UniKey,
+        ShortcutFormat::OpenKey,
+        ShortcutFormat::EVKey,
+        ShortcutFormat::Json,
+    ];
+
+    /// Format for an FFI id, None if unknown
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    /// Guess the format from file contents
+    ///
+    /// EVKey files can't be told apart from UniKey files; they parse the same.
+    pub fn detect(text: &str) -> Self {
+        let text = text.trim_start_matches('\u{feff}').trim_start();
+        if text.starts_with('{') || text.starts_with('[') {
+            ShortcutFormat::Json
+        } else if text.starts_with(OPENKEY_HEADER) {
+            ShortcutFormat::OpenKey
+        } else {
+            ShortcutFormat::UniKey
+        }
+    }
+
+    fn header(self) -> &'static str {
+        match self {
+            ShortcutFormat::OpenKey => OPENKEY_HEADER,
+            _ => UNIKEY_HEADER,
+        }
+    }
+}
+
+/// How imported shortcuts are created
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ImportOptions {
+    /// Keep replacements as written (UniKey/OpenKey "auto caps" off)
+    pub case_sensitive: bool,
+    /// Expand as soon as the trigger is typed instead of on word boundary
+    pub immediate: bool,
+    /// Input method the shortcuts apply to
+    pub input_method: InputMethod,
+    /// Replace user shortcuts that have the same trigger
+    pub overwrite: bool,
+}
+
+impl ImportOptions {
+    /// Options from `IMPORT_*` flags (input method is `All`)
+    pub fn from_flags(flags: u8) -> Self {
+        Self {
+            case_sensitive: flags & IMPORT_CASE_SENSITIVE != 0,
+            immediate: flags & IMPORT_IMMEDIATE != 0,
+            input_method: InputMethod::All,
+            overwrite: flags & IMPORT_OVERWRITE != 0,
+        }
+    }
+
+    fn shortcut(&self, trigger: &str, replacement: &str) -> Shortcut {
+        let mut shortcut = if self.immediate {
+            Shortcut::immediate(trigger, replacement)
+        } else {
+            Shortcut::new(trigger, replacement)
+        };
+        if self.case_sensitive {
+            shortcut.case_mode = CaseMode::Exact;
+        }
+        shortcut.for_method(self.input_method)
+    }
+}
+
+/// Why a line was not imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportErrorKind {
+    /// No `:` between trigger and replacement
+    MissingSeparator,
+    /// Trigger is empty or contains whitespace
+    InvalidTrigger,
+    /// Replacement has a malformed placeholder ("{date:qq}")
+    InvalidTemplate,
+    /// JSON syntax error
+    Syntax,
+    /// JSON field missing, of the wrong type, or with an unknown value
+    InvalidField(&'static str),
+}
+
+impl ImportErrorKind {
+    /// Short name used over FFI ("invalid_template", "invalid_field:case")
+    pub fn name(&self) -> String {
+        match self {
+            ImportErrorKind::MissingSeparator => "missing_separator".into(),
+            ImportErrorKind::InvalidTrigger => "invalid_trigger".into(),
+            ImportErrorKind::InvalidTemplate => "invalid_template".into(),
+            ImportErrorKind::Syntax => "syntax".into(),
+            ImportErrorKind::InvalidField(field) => format!("invalid_field:{}", field),
+        }
+    }
+}
+
+/// A line that was not imported (lines start at 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportError {
+    pub line: usize,
+    pub kind: ImportErrorKind,
+}
+
+/// A trigger defined more than once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conflict {
+    /// Defined again later in the file; the later definition wins
+    Duplicate {
+        line: usize,
+        trigger: String,
+        previous_line: usize,
+    },
+    /// A user shortcut with the same trigger and a different replacement
+    /// exists; it is replaced only with `ImportOptions::overwrite`
+    Existing {
+        line: usize,
+        trigger: String,
+        existing: String,
+    },
+}
+
+impl Conflict {
+    pub fn line(&self) -> usize {
+        match self {
+            Conflict::Duplicate { line, .. } | Conflict::Existing { line, .. } => *line,
+        }
+    }
+}
+
+/// Result of reading a shortcut file
+#[derive(Debug, Default)]
+pub struct Import {
+    /// Shortcuts with the line they were read from
+    pub shortcuts: Vec<(usize, Shortcut)>,
+    pub errors: Vec<ImportError>,
+    pub conflicts: Vec<Conflict>,
+    /// Likely mistakes (see `analysis`) with the line they were found on
+    pub warnings: Vec<(usize, Warning)>,
+    /// Index into `shortcuts` by trigger
+    index: HashMap<String, usize>,
+    /// Every accepted fixed-trigger line: (line, trigger as written,
+    /// replacement, condition)
+    read: Vec<(usize, String, String, TriggerCondition)>,
+}
+
+impl Import {
+    fn error(&mut self, line: usize, kind: ImportErrorKind) {
+        self.errors.push(ImportError { line, kind });
+    }
+
+    fn push(&mut self, line: usize, written: &str, shortcut: Shortcut) {
+        if Template::parse(&shortcut.replacement).is_none() {
+            return self.error(line, ImportErrorKind::InvalidTemplate);
+        }
+        if shortcut.pattern.is_none() {
+            self.read.push((
+                line,
+                written.to_string(),
+                shortcut.replacement.clone(),
+                shortcut.condition,
+            ));
+        }
+        match self.index.get(&shortcut.trigger) {
+            Some(&i) => {
+                self.conflicts.push(Conflict::Duplicate {
+                    line,
+                    trigger: shortcut.trigger.clone(),
+                    previous_line: self.shortcuts[i].0,
+                });
+                self.shortcuts[i] = (line, shortcut);
+            }
+            None => {
+                self.index
+                    .insert(shortcut.trigger.clone(), self.shortcuts.len());
+                self.shortcuts.push((line, shortcut));
+            }
+        }
+    }
+}
+
+/// Read shortcuts from file contents
+///
+/// Triggers that look like mistakes ("an" is a Vietnamese word, "VN" and
+/// "vn" both defined) are still imported and listed in `warnings`.
+pub fn parse(text: &str, format: ShortcutFormat, options: &ImportOptions) -> Import {
+    let text = text.trim_start_matches('\u{feff}');
+    let mut import = Import::default();
+    match format {
+        ShortcutFormat::Json => parse_json(text, options, &mut import),
+        _ => parse_lines(text, options, &mut import),
+    }
+    let read = &import.read;
+    import.warnings = analysis::analyze(
+        read.iter()
+            .map(|(_, t, r, condition)| (t.as_str(), r.as_str(), *condition)),
+    )
+    .into_iter()
+    .map(|w| (read[w.index].0, w))
+    .collect();
+    import
+}
+
+/// Write shortcuts in the given format
+///
+/// Macro files can't hold triggers with `:` or whitespace, nor pattern
+/// triggers; those shortcuts are left out. Macro files also drop the condition, case mode, input
+/// method and enabled fields, which only JSON keeps.
+pub fn export<'a>(
+    shortcuts: impl IntoIterator<Item = &'a Shortcut>,
+    format: ShortcutFormat,
+) -> String {
+    let mut out = String::new();
+    match format {
+        ShortcutFormat::Json => {
+            let entries: Vec<String> = shortcuts.into_iter().map(json_entry).collect();
+            out.push_str(&format!(
+                "{{\n  \"version\": {},\n  \"shortcuts\": [",
+                JSON_VERSION
+            ));
+            if !entries.is_empty() {
+                out.push_str("\n    ");
+                out.push_str(&entries.join(",\n    "));
+                out.push_str("\n  ");
+            }
+            out.push_str("]\n}\n");
+        }
+        _ => {
+            out.push_str(format.header());
+            out.push('\n');
+            for s in shortcuts {
+                if s.pattern.is_some()
+                    || s.trigger.contains(':')
+                    || s.trigger.contains(char::is_whitespace)
+                {
+                    continue;
+                }
+                out.push_str(&format!("{}:{}\n", s.trigger, escape_line(&s.replacement)));
+            }
+        }
+    }
+    out
+}
+
+/// Read a shortcut file as text (UTF-8, or UTF-16 with a byte order mark)
+pub fn read_file(path: impl AsRef<Path>) -> io::Result<String> {
+    decode(&fs::read(path)?).ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
+}
+
+/// Decode file bytes; UniKey on Windows may save UTF-16
+fn decode(bytes: &[u8]) -> Option<String> {
+    let utf16 = |be: bool| {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|b| match be {
+                true => u16::from_be_bytes([b[0], b[1]]),
+                false => u16::from_le_bytes([b[0], b[1]]),
+            })
+            .collect();
+        String::from_utf16(&units).ok()
+    };
+    match bytes {
+        [0xff, 0xfe, ..] => utf16(false),
+        [0xfe, 0xff, ..] => utf16(true),
+        _ => String::from_utf8(bytes.to_vec()).ok(),
+    }
+}
+
+// ============================================================
+// Macro files (UniKey, OpenKey, EVKey)
+// ============================================================
+
+fn parse_lines(text: &str, options: &ImportOptions, import: &mut Import) {
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        if line.trim().is_empty() || line.starts_with(';') {
+            continue;
+        }
+        let Some((trigger, replacement)) = line.split_once(':') else {
+            import.error(line_no, ImportErrorKind::MissingSeparator);
+            continue;
+        };
+        let trigger = trigger.trim();
+        if trigger.is_empty() || trigger.contains(char::is_whitespace) {
+            import.error(line_no, ImportErrorKind::InvalidTrigger);
+            continue;
+        }
+        import.push(
+            line_no,
+            trigger,
+            options.shortcut(trigger, &unescape_line(replacement)),
+        );
+    }
+}
+
+/// "\n" → line break, "\t" → tab, "\\" → backslash; other backslashes stay
+pub fn unescape_line(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.clone().next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('\\') => out.push('\\'),
+            _ => {
+                out.push('\\');
+                continue;
+            }
+        }
+        chars.next();
+    }
+    out
+}
+
+/// Escape line breaks, tabs and backslashes as `\n`, `\t` and `\\`, so
+/// a replacement fits on one line
+pub fn escape_line(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+// ============================================================
+// JSON
+// ============================================================
+
+fn parse_json(text: &str, options: &ImportOptions, import: &mut Import) {
+    let root = match JsonParser::new(text).document() {
+        Ok(root) => root,
+        Err(line) => return import.error(line, ImportErrorKind::Syntax),
+    };
+    let entries = match root.value {
+        Json::Array(entries) => entries,
+        Json::Object(fields) => match fields.into_iter().find(|(k, _)| k == "shortcuts") {
+            Some((
+                _,
+                Node {
+                    value: Json::Array(entries),
+                    ..
+                },
+            )) => entries,
+            _ => return import.error(root.line, ImportErrorKind::InvalidField("shortcuts")),
+        },
+        _ => return import.error(root.line, ImportErrorKind::InvalidField("shortcuts")),
+    };
+    for entry in entries {
+        match json_shortcut(&entry, options) {
+            Ok((trigger, shortcut)) => import.push(entry.line, trigger, shortcut),
+            Err((line, kind)) => import.error(line, kind),
+        }
+    }
+}
+
+/// Shortcut for a JSON entry, with its trigger as written
+fn json_shortcut<'a>(
+    entry: &'a Node,
+    options: &ImportOptions,
+) -> Result<(&'a str, Shortcut), (usize, ImportErrorKind)> {
+    let Json::Object(fields) = &entry.value else {
+        return Err((entry.line, ImportErrorKind::InvalidField("shortcut")));
+    };
+    let field = |name: &str| fields.iter().find(|(k, _)| k == name).map(|(_, v)| v);
+    let invalid = |node: Option<&Node>, name: &'static str| {
+        (
+            node.map_or(entry.line, |n| n.line),
+            ImportErrorKind::InvalidField(name),
+        )
+    };
+    let string = |name: &'static str| match field(name) {
+        Some(Node {
+            value: Json::String(s),
+            ..
+        }) => Ok(Some(s.as_str())),
+        None => Ok(None),
+        node => Err(invalid(node, name)),
+    };
+
+    let trigger = string("trigger")?
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| invalid(field("trigger"), "trigger"))?;
+    let replacement = string("replacement")?.ok_or_else(|| invalid(None, "replacement"))?;
+    let mut shortcut = options.shortcut(trigger, replacement);
+
+    if let Some(condition) = string("condition")? {
+        shortcut.condition = match condition {
+            "immediate" => TriggerCondition::Immediate,
+            "word_boundary" => TriggerCondition::OnWordBoundary,
+            _ => return Err(invalid(field("condition"), "condition")),
+        };
+    }
+    if let Some(case) = string("case")? {
+        shortcut.case_mode = match case {
+            "match" => CaseMode::MatchCase,
+            "exact" => CaseMode::Exact,
+            _ => return Err(invalid(field("case"), "case")),
+        };
+    }
+    if let Some(method) = string("input_method")? {
+        shortcut.input_method = match method {
+            "all" => InputMethod::All,
+            "telex" => InputMethod::Telex,
+            "vni" => InputMethod::Vni,
+            _ => return Err(invalid(field("input_method"), "input_method")),
+        };
+    }
+    match field("enabled") {
+        Some(Node {
+            value: Json::Bool(enabled),
+            ..
+        }) => shortcut.enabled = *enabled,
+        None => {}
+        node => return Err(invalid(node, "enabled")),
+    }
+    match field("pattern") {
+        Some(Node {
+            value: Json::Bool(true),
+            ..
+        }) => {
+            let pattern = Shortcut::pattern(trigger, replacement).ok_or_else(|| {
+                (
+                    field("trigger").map_or(entry.line, |n| n.line),
+                    ImportErrorKind::InvalidTrigger,
+                )
+            })?;
+            shortcut.trigger = pattern.trigger;
+            shortcut.pattern = pattern.pattern;
+        }
+        Some(Node {
+            value: Json::Bool(false),
+            ..
+        })
+        | None => {}
+        node => return Err(invalid(node, "pattern")),
+    }
+    Ok((trigger, shortcut))
+}
+
+fn json_entry(s: &Shortcut) -> String {
+    let condition = match s.condition {
+        TriggerCondition::Immediate => "immediate",
+        TriggerCondition::OnWordBoundary => "word_boundary",
+    };
+    let case = match s.case_mode {
+        CaseMode::MatchCase => "match",
+        CaseMode::Exact => "exact",
+    };
+    let method = match s.input_method {
+        InputMethod::All => "all",
+        InputMethod::Telex => "telex",
+        InputMethod::Vni => "vni",
+    };
+    let pattern = if s.pattern.is_some() {
+        ", \"pattern\": true"
+    } else {
+        ""
+    };
+    format!(
+        "{{\"trigger\": {}, \"replacement\": {}, \"condition\": \"{}\", \"case\": \"{}\", \"input_method\": \"{}\", \"enabled\": {}{}}}",
+        json_string(&s.trigger),
+        json_string(&s.replacement),
+        condition,
+        case,
+        method,
+        s.enabled,
+        pattern
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// JSON value with the line it starts on
+#[derive(Debug)]
+struct Node {
+    line: usize,
+    value: Json,
+}
+
+#[derive(Debug)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number,
+    String(String),
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+}
+
+/// Deepest array/object nesting accepted (shortcut files need 3)
+const MAX_JSON_DEPTH: usize = 32;
+
+/// Minimal JSON reader; errors carry the line they were found on
+struct JsonParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    /// Arrays and objects currently open
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars().peekable(),
+            line: 1,
+            depth: 0,
+        }
+    }
+
+    fn document(mut self) -> Result<Node, usize> {
+        let node = self.value()?;
+        self.skip_whitespace();
+        match self.chars.peek() {
+            None => Ok(node),
+            Some(_) => Err(self.line),
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+        }
+        c
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), usize> {
+        self.skip_whitespace();
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(self.line),
+        }
+    }
+
+    fn value(&mut self) -> Result<Node, usize> {
+        self.skip_whitespace();
+        let line = self.line;
+        let value = match self.chars.peek().copied() {
+            Some(c @ ('{' | '[')) => {
+                // Bounded so hostile files can't overflow the stack
+                if self.depth == MAX_JSON_DEPTH {
+                    return Err(line);
+                }
+                self.depth += 1;
+                let value = if c == '{' {
+                    self.object()?
+                } else {
+                    self.array()?
+                };
+                self.depth -= 1;
+                value
+            }
+            Some('"') => Json::String(self.string()?),
+            Some('t') => self.literal("true", Json::Bool(true))?,
+            Some('f') => self.literal("false", Json::Bool(false))?,
+            Some('n') => self.literal("null", Json::Null)?,
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number()?,
+            _ => return Err(line),
+        };
+        Ok(Node { line, value })
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, usize> {
+        for expected in word.chars() {
+            if self.next() != Some(expected) {
+                return Err(self.line);
+            }
+        }
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<Json, usize> {
+        let mut text = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')) {
+                break;
+            }
+            text.push(c);
+            self.next();
+        }
+        text.parse::<f64>()
+            .map(|_| Json::Number)
+            .map_err(|_| self.line)
+    }
+
+    fn string(&mut self) -> Result<String, usize> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.next().ok_or(self.line)? {
+                '"' => return Ok(out),
+                '\\' => match self.next().ok_or(self.line)? {
+                    '"' => out.push('"'),
+                    '\\' => out.push('\\'),
+                    '/' => out.push('/'),
+                    'b' => out.push('\u{8}'),
+                    'f' => out.push('\u{c}'),
+                    'n' => out.push('\n'),
+                    'r' => out.push('\r'),
+                    't' => out.push('\t'),
+                    'u' => out.push(self.unicode_escape()?),
+                    _ => return Err(self.line),
+                },
+                '\n' => return Err(self.line),
+                c => out.push(c),
+            }
+        }
+    }
+
+    /// Code point after "\u", joining surrogate pairs
+    fn unicode_escape(&mut self) -> Result<char, usize> {
+        let high = self.hex4()?;
+        if !(0xd800..0xdc00).contains(&high) {
+            return char::from_u32(high).ok_or(self.line);
+        }
+        if self.next() != Some('\\') || self.next() != Some('u') {
+            return Err(self.line);
+        }
+        let low = self.hex4()?;
+        if !(0xdc00..0xe000).contains(&low) {
+            return Err(self.line);
+        }
+        char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)).ok_or(self.line)
+    }
+
+    fn hex4(&mut self) -> Result<u32, usize> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let digit = self.next().and_then(|c| c.to_digit(16)).ok_or(self.line)?;
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+
+    fn array(&mut self) -> Result<Json, usize> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.next();
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Json::Array(items)),
+                _ => return Err(self.line),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, usize> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.next();
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Json::Object(fields)),
+                _ => return Err(self.line),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_default(text: &str, format: ShortcutFormat) -> Import {
+        parse(text, format, &ImportOptions::default())
+    }
+
+    #[test]
+    fn test_macro_lines() {
+        let text = "\u{feff};DO NOT DELETE THIS LINE*** version=1 ***\n\
+                    vn:Việt Nam\n\
+                    ; comment\n\
+                    \n\
+                    url:http://x.vn\n\
+                    sig:A\\nB\\tC\\\\d\\q\n\
+                    nosep\n\
+                    :empty\n\
+                    a b:space\n\
+                    bad:{date:qq}\n\
+                    VN:Việt Nam!\n";
+        let import = parse_default(text, ShortcutFormat::UniKey);
+        let got: Vec<(usize, &str, &str)> = import
+            .shortcuts
+            .iter()
+            .map(|(l, s)| (*l, s.trigger.as_str(), s.replacement.as_str()))
+            .collect();
+        assert_eq!(
+            got,
+            [
+                (11, "vn", "Việt Nam!"),
+                (5, "url", "http://x.vn"),
+                (6, "sig", "A\nB\tC\\d\\q"),
+            ]
+        );
+        let errors: Vec<(usize, ImportErrorKind)> =
+            import.errors.iter().map(|e| (e.line, e.kind)).collect();
+        assert_eq!(
+            errors,
+            [
+                (7, ImportErrorKind::MissingSeparator),
+                (8, ImportErrorKind::InvalidTrigger),
+                (9, ImportErrorKind::InvalidTrigger),
+                (10, ImportErrorKind::InvalidTemplate),
+            ]
+        );
+        assert_eq!(
+            import.conflicts,
+            [Conflict::Duplicate {
+                line: 11,
+                trigger: "vn".into(),
+                previous_line: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_options_map_to_fields() {
+        let options = ImportOptions {
+            case_sensitive: true,
+            immediate: true,
+            input_method: InputMethod::Vni,
+            overwrite: false,
+        };
+        let import = parse("vn:Việt Nam", ShortcutFormat::OpenKey, &options);
+        let s = &import.shortcuts[0].1;
+        assert_eq!(s.condition, TriggerCondition::Immediate);
+        assert_eq!(s.case_mode, CaseMode::Exact);
+        assert_eq!(s.input_method, InputMethod::Vni);
+
+        let s = &parse_default("vn:Việt Nam", ShortcutFormat::UniKey).shortcuts[0].1;
+        assert_eq!(s.condition, TriggerCondition::OnWordBoundary);
+        assert_eq!(s.case_mode, CaseMode::MatchCase);
+        assert_eq!(s.input_method, InputMethod::All);
+    }
+
+    #[test]
+    fn test_json_fields_and_errors() {
+        let text = r#"{
+  "version": 1,
+  "shortcuts": [
+    {"trigger": "vn", "replacement": "Việt \"Nam\"\n😀"},
+    {"trigger": "->", "replacement": "→", "condition": "immediate", "case": "exact", "input_method": "telex", "enabled": false},
+    {"trigger": "x", "replacement": "y", "case": "upper"},
+    {"replacement": "y"},
+    {"trigger": "z", "replacement": 1},
+    "nope"
+  ]
+}"#;
+        let import = parse_default(text, ShortcutFormat::Json);
+        assert_eq!(import.shortcuts.len(), 2);
+        assert_eq!(import.shortcuts[0].1.replacement, "Việt \"Nam\"\n😀");
+        let (line, arrow) = &import.shortcuts[1];
+        assert_eq!(*line, 5);
+        assert_eq!(arrow.condition, TriggerCondition::Immediate);
+        assert_eq!(arrow.case_mode, CaseMode::Exact);
+        assert_eq!(arrow.input_method, InputMethod::Telex);
+        assert!(!arrow.enabled);
+
+        let errors: Vec<(usize, ImportErrorKind)> =
+            import.errors.iter().map(|e| (e.line, e.kind)).collect();
+        assert_eq!(
+            errors,
+            [
+                (6, ImportErrorKind::InvalidField("case")),
+                (7, ImportErrorKind::InvalidField("trigger")),
+                (8, ImportErrorKind::InvalidField("replacement")),
+                (9, ImportErrorKind::InvalidField("shortcut")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_syntax_error_line() {
+        let import = parse_default(
+            "[\n  {\"trigger\": \"a\",\n  \"replacement\" \"b\"}\n]",
+            ShortcutFormat::Json,
+        );
+        assert!(import.shortcuts.is_empty());
+        assert_eq!(
+            import.errors,
+            [ImportError {
+                line: 3,
+                kind: ImportErrorKind::Syntax
+            }]
+        );
+        let import = parse_default(
+            "[{\"trigger\": \"a\", \"replacement\": \"b\"}]",
+            ShortcutFormat::Json,
+        );
+        assert_eq!(import.shortcuts.len(), 1);
+    }
+
+    #[test]
+    fn test_json_nesting_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let import = parse_default(&nested(MAX_JSON_DEPTH), ShortcutFormat::Json);
+        assert!(import
+            .errors
+            .iter()
+            .all(|e| e.kind != ImportErrorKind::Syntax));
+        for depth in [MAX_JSON_DEPTH + 1, 200_000] {
+            let import = parse_default(&nested(depth), ShortcutFormat::Json);
+            assert_eq!(
+                import.errors,
+                [ImportError {
+                    line: 1,
+                    kind: ImportErrorKind::Syntax
+                }]
+            );
+        }
+        let deep_field = format!(
+            r#"[{{"trigger": "a", "replacement": "b", "x": {}}}]"#,
+            nested(MAX_JSON_DEPTH)
+        );
+        let import = parse_default(&deep_field, ShortcutFormat::Json);
+        assert_eq!(import.errors[0].kind, ImportErrorKind::Syntax);
+    }
+
+    #[test]
+    fn test_literal_braces_import() {
+        let import = parse_default("obj:{}\njs:{\"a\": 1}\n", ShortcutFormat::UniKey);
+        assert!(import.errors.is_empty());
+        let replacements: Vec<&str> = import
+            .shortcuts
+            .iter()
+            .map(|(_, s)| s.replacement.as_str())
+            .collect();
+        assert_eq!(replacements, ["{}", "{\"a\": 1}"]);
+    }
+
+    #[test]
+    fn test_export_roundtrip() {
+        let shortcuts = [
+            Shortcut::new("vn", "Việt Nam"),
+            Shortcut::immediate("->", "→").for_method(InputMethod::Telex),
+            Shortcut::new("sig", "A \"quoted\"\n\tB\\"),
+            Shortcut::new("a:b", "colon"),
+        ];
+        for format in ShortcutFormat::ALL {
+            let text = export(&shortcuts, format);
+            assert_eq!(
+                ShortcutFormat::detect(&text),
+                match format {
+                    ShortcutFormat::EVKey => ShortcutFormat::UniKey,
+                    f => f,
+                }
+            );
+            let import = parse_default(&text, format);
+            assert!(
+                import.errors.is_empty(),
+                "{:?}: {:?}",
+                format,
+                import.errors
+            );
+            let expected = if format == ShortcutFormat::Json { 4 } else { 3 };
+            assert_eq!(import.shortcuts.len(), expected, "{:?}", format);
+            for ((_, got), want) in import.shortcuts.iter().zip(&shortcuts) {
+                assert_eq!(got.trigger, want.trigger);
+                assert_eq!(got.replacement, want.replacement);
+            }
+        }
+        let json = parse_default(
+            &export(&shortcuts, ShortcutFormat::Json),
+            ShortcutFormat::Json,
+        );
+        assert_eq!(json.shortcuts[1].1.condition, TriggerCondition::Immediate);
+        assert_eq!(json.shortcuts[1].1.input_method, InputMethod::Telex);
+        assert_eq!(
+            export(&[], ShortcutFormat::Json),
+            "{\n  \"version\": 1,\n  \"shortcuts\": []\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_import_warnings() {
+        use crate::engine::shortcut::analysis::WarningKind;
+        let import = parse_default("hoa:Hòa\nVN:Việt Nam\nvn:Việt Nam", ShortcutFormat::UniKey);
+        let got: Vec<(usize, &str, WarningKind)> = import
+            .warnings
+            .iter()
+            .map(|(line, w)| (*line, w.trigger.as_str(), w.kind.clone()))
+            .collect();
+        assert_eq!(
+            got,
+            [
+                (1, "hoa", WarningKind::VietnameseWord),
+                (2, "VN", WarningKind::CaseDuplicate(vec!["vn".into()])),
+                (3, "vn", WarningKind::CaseDuplicate(vec!["VN".into()])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let text = "vn:Việt Nam";
+        let mut le = vec![0xff, 0xfe];
+        let mut be = vec![0xfe, 0xff];
+        for unit in text.encode_utf16() {
+            le.extend(unit.to_le_bytes());
+            be.extend(unit.to_be_bytes());
+        }
+        assert_eq!(decode(&le).as_deref(), Some(text));
+        assert_eq!(decode(&be).as_deref(), Some(text));
+        assert_eq!(decode(text.as_bytes()).as_deref(), Some(text));
+        assert_eq!(decode(&[0xc3]), None);
+    }
+}
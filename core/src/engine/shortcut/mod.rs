@@ -0,0 +1,1591 @@
+//! Shortcut Table - Abbreviation expansion
+//!
+//! Allows users to define shortcuts like "vn" → "Việt Nam"
+//! Shortcuts can be specific to input methods (Telex/VNI) or apply to all.
+//! Bundled packs (arrows, math, ...) can be enabled on top of user shortcuts.
+//! Replacements can be templates ("{date}", "{counter}"), see `template`.
+//! UniKey/OpenKey/EVKey macro files and JSON are read and written by `format`.
+//! `analysis` flags likely mistakes (triggers that are real words, ...).
+//! Triggers are indexed in a `trie`, so matching cost grows with the
+//! trigger length, not the table size. `pattern` triggers ("(\d+)k" →
+//! "$1.000 đ") are tried only when no fixed trigger matches.
+
+pub mod analysis;
+pub mod format;
+pub mod pattern;
+pub mod template;
+pub mod trie;
+
+use crate::data::shortcut_pack::{PackEntry, ShortcutPack};
+use format::{Conflict, Import, ImportOptions, ShortcutFormat};
+use pattern::{Captures, Pattern};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use template::{Clock, Template};
+use trie::{TriggerCursor, TriggerTrie};
+
+/// Input method that shortcut applies to (ids are stable, used over FFI)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(u8)]
+pub enum InputMethod {
+    /// Apply to all input methods
+    #[default]
+    All = 0,
+    /// Apply only to Telex
+    Telex = 1,
+    /// Apply only to VNI
+    Vni = 2,
+}
+
+impl InputMethod {
+    /// Input method for an FFI id, None if unknown
+    pub fn from_id(id: u8) -> Option<Self> {
+        [Self::All, Self::Telex, Self::Vni]
+            .get(id as usize)
+            .copied()
+    }
+}
+
+/// Trigger condition for shortcut (ids are stable, used over FFI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TriggerCondition {
+    /// Trigger immediately when buffer matches
+    Immediate = 0,
+    /// Trigger when word boundary (space, punctuation) is pressed
+    OnWordBoundary = 1,
+}
+
+impl TriggerCondition {
+    /// Condition for an FFI id, None if unknown
+    pub fn from_id(id: u8) -> Option<Self> {
+        [Self::Immediate, Self::OnWordBoundary]
+            .get(id as usize)
+            .copied()
+    }
+}
+
+/// Case handling mode (ids are stable, used over FFI)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum CaseMode {
+    /// Keep replacement exactly as defined
+    Exact = 0,
+    /// Match case of trigger: "VN" → "VIỆT NAM", "vn" → "Việt Nam"
+    MatchCase = 1,
+}
+
+impl CaseMode {
+    /// Case mode for an FFI id, None if unknown
+    pub fn from_id(id: u8) -> Option<Self> {
+        [Self::Exact, Self::MatchCase].get(id as usize).copied()
+    }
+}
+
+/// A single shortcut entry
+#[derive(Debug, Clone)]
+pub struct Shortcut {
+    /// Trigger string (lowercase for matching)
+    pub trigger: String,
+    /// Replacement text
+    pub replacement: String,
+    /// When to trigger
+    pub condition: TriggerCondition,
+    /// How to handle case
+    pub case_mode: CaseMode,
+    /// Whether this shortcut is enabled
+    pub enabled: bool,
+    /// Which input method this shortcut applies to
+    pub input_method: InputMethod,
+    /// Bundled pack entry id ("arrows.right"), None for user shortcuts
+    pub pack_entry: Option<&'static str>,
+    /// Compiled trigger for pattern shortcuts (`trigger` is its source)
+    pub pattern: Option<Pattern>,
+}
+
+impl Shortcut {
+    /// Normalize line breaks ("\r\n" and "\r" → "\n") so each one is a
+    /// single Enter. Replacements have no length limit: long output is
+    /// sent in parts (see `Engine::next_output`).
+    fn normalize_replacement(replacement: &str) -> String {
+        replacement.replace("\r\n", "\n").replace('\r', "\n")
+    }
+
+    /// Create a new shortcut with word boundary trigger (applies to all input methods)
+    /// Issue #86: Case-insensitive matching, smart case output (ko→không, KO→KHÔNG, Ko→Không)
+    pub fn new(trigger: &str, replacement: &str) -> Self {
+        Self {
+            trigger: trigger.to_lowercase(), // Store lowercase for case-insensitive matching
+            replacement: Self::normalize_replacement(replacement),
+            condition: TriggerCondition::OnWordBoundary,
+            case_mode: CaseMode::MatchCase, // Smart case transformation
+            enabled: true,
+            input_method: InputMethod::All,
+            pack_entry: None,
+            pattern: None,
+        }
+    }
+
+    /// Create an immediate trigger shortcut (applies to all input methods).
+    /// Issue #86: Case-insensitive matching, smart case output
+    pub fn immediate(trigger: &str, replacement: &str) -> Self {
+        Self {
+            trigger: trigger.to_lowercase(), // Store lowercase for case-insensitive matching
+            replacement: Self::normalize_replacement(replacement),
+            condition: TriggerCondition::Immediate,
+            case_mode: CaseMode::MatchCase, // Smart case transformation
+            enabled: true,
+            input_method: InputMethod::All,
+            pack_entry: None,
+            pattern: None,
+        }
+    }
+
+    /// Create a Telex-specific shortcut with immediate trigger.
+    /// Issue #86: Case-insensitive matching, smart case output
+    pub fn telex(trigger: &str, replacement: &str) -> Self {
+        Self {
+            trigger: trigger.to_lowercase(), // Store lowercase for case-insensitive matching
+            replacement: Self::normalize_replacement(replacement),
+            condition: TriggerCondition::Immediate,
+            case_mode: CaseMode::MatchCase, // Smart case transformation
+            enabled: true,
+            input_method: InputMethod::Telex,
+            pack_entry: None,
+            pattern: None,
+        }
+    }
+
+    /// Create a VNI-specific shortcut with immediate trigger.
+    /// Issue #86: Case-insensitive matching, smart case output
+    pub fn vni(trigger: &str, replacement: &str) -> Self {
+        Self {
+            trigger: trigger.to_lowercase(), // Store lowercase for case-insensitive matching
+            replacement: Self::normalize_replacement(replacement),
+            condition: TriggerCondition::Immediate,
+            case_mode: CaseMode::MatchCase, // Smart case transformation
+            enabled: true,
+            input_method: InputMethod::Vni,
+            pack_entry: None,
+            pattern: None,
+        }
+    }
+
+    /// Create a shortcut, picking the trigger condition from the trigger:
+    /// triggers without letters ("->", "1/2") fire immediately, anything
+    /// with a letter ("vn") fires on word boundary.
+    pub fn auto(trigger: &str, replacement: &str) -> Self {
+        if trigger.chars().all(|c| !c.is_alphabetic()) {
+            Self::immediate(trigger, replacement)
+        } else {
+            Self::new(trigger, replacement)
+        }
+    }
+
+    /// Create a word boundary shortcut whose trigger is a pattern
+    ///
+    /// None if the pattern is malformed or the replacement refers to a
+    /// group the pattern doesn't have (see `pattern`).
+    pub fn pattern(pattern: &str, replacement: &str) -> Option<Self> {
+        // Letters match case-insensitively and escapes are lowercase (\d, \w)
+        let source = pattern.to_lowercase();
+        let compiled = Pattern::parse(&source)?;
+        if !compiled.check_replacement(replacement) {
+            return None;
+        }
+        Some(Self {
+            pattern: Some(compiled),
+            ..Self::new(&source, replacement)
+        })
+    }
+
+    /// Shortcut for a bundled pack entry
+    fn from_pack(entry: &PackEntry) -> Self {
+        Self {
+            condition: entry.condition,
+            pack_entry: Some(entry.id),
+            ..Self::new(entry.trigger, entry.replacement)
+        }
+    }
+
+    /// Set the input method for this shortcut
+    pub fn for_method(mut self, method: InputMethod) -> Self {
+        self.input_method = method;
+        self
+    }
+
+    /// Check if shortcut applies to given input method
+    ///
+    /// - If shortcut is for `All`: matches any method
+    /// - If shortcut is for `Telex`: matches `Telex` or `All` query
+    /// - If shortcut is for `Vni`: matches `Vni` or `All` query
+    pub fn applies_to(&self, query_method: InputMethod) -> bool {
+        match self.input_method {
+            // Shortcut for All → matches any query
+            InputMethod::All => true,
+            // Shortcut for specific method → matches if query is same method OR query is All
+            InputMethod::Telex => {
+                query_method == InputMethod::Telex || query_method == InputMethod::All
+            }
+            InputMethod::Vni => {
+                query_method == InputMethod::Vni || query_method == InputMethod::All
+            }
+        }
+    }
+}
+
+/// Shortcut match result
+#[derive(Debug)]
+pub struct ShortcutMatch {
+    /// Number of characters to backspace
+    pub backspace_count: usize,
+    /// Replacement text to output
+    pub output: String,
+    /// Whether to include the trigger key in output
+    pub include_trigger_key: bool,
+    /// Characters after the `{cursor}` marker in `output`, trigger key
+    /// included (0 if the replacement has no marker)
+    pub caret_left: usize,
+}
+
+/// How often a shortcut has fired since the engine started
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ShortcutUsage {
+    /// Number of expansions
+    pub count: u32,
+    /// Unix seconds of the last expansion (0 = never)
+    pub last_used: i64,
+}
+
+/// Shortcut table manager
+#[derive(Debug, Default)]
+pub struct ShortcutTable {
+    /// Shortcuts indexed by trigger (lowercase)
+    shortcuts: HashMap<String, Shortcut>,
+    /// Index of the keys of `shortcuts` (fixed triggers)
+    trie: TriggerTrie,
+    /// Keys of pattern shortcuts, tried in the order they were added
+    patterns: Vec<String>,
+    /// Enabled bundled packs (bit mask of `ShortcutPack::bit`)
+    packs: u8,
+    /// User edits of bundled entries by entry id (None = removed by the user)
+    pack_edits: HashMap<&'static str, Option<Shortcut>>,
+    /// Time source for `{date}` / `{time}` placeholders
+    clock: Clock,
+    /// Expansion count per trigger for `{counter}`
+    counters: RefCell<HashMap<String, u32>>,
+    /// Usage per trigger, kept when the shortcut is removed or replaced
+    usage: RefCell<HashMap<String, ShortcutUsage>>,
+}
+
+impl ShortcutTable {
+    pub fn new() -> Self {
+        Self {
+            shortcuts: HashMap::new(),
+            trie: TriggerTrie::new(),
+            patterns: Vec::new(),
+            packs: 0,
+            pack_edits: HashMap::new(),
+            clock: Clock::default(),
+            counters: RefCell::new(HashMap::new()),
+            usage: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Create with default Vietnamese shortcuts (common abbreviations)
+    ///
+    /// Note: "w" → "ư" is NOT a shortcut, it's handled by the engine
+    /// as a vowel key with Vietnamese validation.
+    ///
+    /// Currently disabled - returns empty table. The common abbreviations
+    /// are in `ShortcutPack::Vietnamese`, which users enable explicitly.
+    pub fn with_defaults() -> Self {
+        Self::new()
+    }
+
+    /// Create with Telex defaults only
+    pub fn with_telex_defaults() -> Self {
+        // No Telex-specific shortcuts
+        // "w" → "ư" is handled by the engine, not shortcuts
+        Self::new()
+    }
+
+    /// Create with VNI defaults only
+    pub fn with_vni_defaults() -> Self {
+        Self::new()
+    }
+
+    /// Create with all defaults (common abbreviations)
+    ///
+    /// Bundled packs stay off until the user enables them.
+    pub fn with_all_defaults() -> Self {
+        let mut table = Self::new();
+
+        // Common abbreviations (apply to all input methods)
+        table.add(Shortcut::new("vn", "Việt Nam"));
+        table.add(Shortcut::new("hcm", "Hồ Chí Minh"));
+        table.add(Shortcut::new("hn", "Hà Nội"));
+        table.add(Shortcut::new("dc", "được"));
+        table.add(Shortcut::new("ko", "không"));
+
+        table
+    }
+
+    /// Add a shortcut
+    ///
+    /// Returns false (and adds nothing) if the replacement has a malformed
+    /// placeholder, e.g. "{date:qq}". Other braces are literal text.
+    pub fn add(&mut self, shortcut: Shortcut) -> bool {
+        if Template::parse(&shortcut.replacement).is_none() {
+            return false;
+        }
+        self.insert(shortcut);
+        true
+    }
+
+    /// Replace all user shortcuts at once
+    ///
+    /// Nothing changes (and false is returned) if any replacement is a
+    /// malformed template. Enabled packs and pack edits are kept.
+    pub fn replace_all(&mut self, shortcuts: Vec<Shortcut>) -> bool {
+        if shortcuts
+            .iter()
+            .any(|s| Template::parse(&s.replacement).is_none())
+        {
+            return false;
+        }
+        self.shortcuts.clear();
+        self.trie.clear();
+        self.patterns.clear();
+        for mut shortcut in shortcuts {
+            shortcut.pack_entry = None;
+            self.insert(shortcut);
+        }
+        self.apply_packs();
+        true
+    }
+
+    /// Shortcut for a trigger (case-insensitive), user or bundled
+    pub fn get(&self, trigger: &str) -> Option<&Shortcut> {
+        self.shortcuts.get(&trigger.to_lowercase())
+    }
+
+    /// All shortcuts in effect, user and bundled, sorted by trigger
+    pub fn entries(&self) -> Vec<&Shortcut> {
+        let mut entries: Vec<&Shortcut> = self.shortcuts.values().collect();
+        entries.sort_by(|a, b| a.trigger.cmp(&b.trigger));
+        entries
+    }
+
+    /// Usage of a trigger (case-insensitive)
+    pub fn usage(&self, trigger: &str) -> ShortcutUsage {
+        self.usage
+            .borrow()
+            .get(&trigger.to_lowercase())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Enable or disable a shortcut without removing it
+    ///
+    /// Disabling a bundled entry is stored as an edit of that entry.
+    /// Returns false if no shortcut has this trigger.
+    pub fn set_enabled(&mut self, trigger: &str, enabled: bool) -> bool {
+        let Some(shortcut) = self.shortcuts.get_mut(&trigger.to_lowercase()) else {
+            return false;
+        };
+        shortcut.enabled = enabled;
+        if let Some(id) = shortcut.pack_entry {
+            let edit = shortcut.clone();
+            self.pack_edits.insert(id, Some(edit));
+        }
+        true
+    }
+
+    /// Warnings for the shortcuts in effect (see `analysis`), in trigger
+    /// order; `Warning::index` is the position in `entries()`. Pattern
+    /// shortcuts are not analyzed.
+    pub fn analyze(&self) -> Vec<analysis::Warning> {
+        let fixed: Vec<(usize, &Shortcut)> = self
+            .entries()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, s)| s.pattern.is_none())
+            .collect();
+        let mut warnings = analysis::analyze(
+            fixed
+                .iter()
+                .map(|(_, s)| (s.trigger.as_str(), s.replacement.as_str(), s.condition)),
+        );
+        for warning in &mut warnings {
+            warning.index = fixed[warning.index].0;
+        }
+        warnings
+    }
+
+    /// Import shortcuts from a shortcut file's contents
+    ///
+    /// A user shortcut with the same trigger and a different replacement
+    /// is reported as a conflict and kept unless `options.overwrite` is set.
+    /// The returned `Import` lists the shortcuts actually added.
+    pub fn import(
+        &mut self,
+        text: &str,
+        format: ShortcutFormat,
+        options: &ImportOptions,
+    ) -> Import {
+        let mut import = format::parse(text, format, options);
+        let mut added = Vec::with_capacity(import.shortcuts.len());
+        for (line, shortcut) in std::mem::take(&mut import.shortcuts) {
+            let existing = self
+                .shortcuts
+                .get(&shortcut.trigger)
+                .filter(|s| s.pack_entry.is_none() && s.replacement != shortcut.replacement);
+            if let Some(existing) = existing {
+                import.conflicts.push(Conflict::Existing {
+                    line,
+                    trigger: shortcut.trigger.clone(),
+                    existing: existing.replacement.clone(),
+                });
+                if !options.overwrite {
+                    continue;
+                }
+            }
+            self.insert(shortcut.clone());
+            added.push((line, shortcut));
+        }
+        import.shortcuts = added;
+        import
+    }
+
+    /// Export user shortcuts (sorted by trigger); bundled packs are left out
+    pub fn export(&self, format: ShortcutFormat) -> String {
+        let shortcuts = self
+            .entries()
+            .into_iter()
+            .filter(|s| s.pack_entry.is_none());
+        format::export(shortcuts, format)
+    }
+
+    /// Set the time source for `{date}` / `{time}` placeholders
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
+    /// Remove a shortcut (exact match, case-sensitive)
+    ///
+    /// A bundled entry hidden by the removed user shortcut comes back.
+    pub fn remove(&mut self, trigger: &str) -> Option<Shortcut> {
+        let result = self.shortcuts.remove(trigger);
+        if let Some(ref shortcut) = result {
+            self.trie.remove(trigger);
+            self.patterns.retain(|key| key != trigger);
+            if shortcut.pack_entry.is_none() {
+                self.apply_packs();
+            }
+        }
+        result
+    }
+
+    /// Enable or disable a bundled pack
+    ///
+    /// User shortcuts with the same trigger as a pack entry take priority.
+    pub fn set_pack_enabled(&mut self, pack: ShortcutPack, enabled: bool) {
+        if enabled {
+            self.packs |= pack.bit();
+        } else {
+            self.packs &= !pack.bit();
+        }
+        self.apply_packs();
+    }
+
+    /// Check if a bundled pack is enabled
+    pub fn pack_enabled(&self, pack: ShortcutPack) -> bool {
+        self.packs & pack.bit() != 0
+    }
+
+    /// Entries of a pack as they apply: bundled, edited by the user, or
+    /// missing if the user removed them
+    pub fn pack_shortcuts(&self, pack: ShortcutPack) -> Vec<Shortcut> {
+        pack.entries()
+            .iter()
+            .filter_map(|entry| match self.pack_edits.get(entry.id) {
+                Some(edit) => edit.clone(),
+                None => Some(Shortcut::from_pack(entry)),
+            })
+            .collect()
+    }
+
+    /// Replace a bundled entry's trigger and replacement
+    ///
+    /// The edit is keyed by entry id, so it keeps applying when a newer
+    /// version changes the bundled entry. Returns false for unknown ids and
+    /// malformed templates.
+    pub fn edit_pack_entry(&mut self, id: &str, trigger: &str, replacement: &str) -> bool {
+        let Some((_, entry)) = ShortcutPack::find_entry(id) else {
+            return false;
+        };
+        if Template::parse(replacement).is_none() {
+            return false;
+        }
+        let mut shortcut = Shortcut::auto(trigger, replacement);
+        // Keep the bundled word boundary ("^2" edited to "^3" still waits)
+        if entry.condition == TriggerCondition::OnWordBoundary {
+            shortcut.condition = entry.condition;
+        }
+        shortcut.pack_entry = Some(entry.id);
+        self.pack_edits.insert(entry.id, Some(shortcut));
+        self.apply_packs();
+        true
+    }
+
+    /// Remove a bundled entry (stays removed when its pack is re-enabled)
+    pub fn remove_pack_entry(&mut self, id: &str) -> bool {
+        let Some((_, entry)) = ShortcutPack::find_entry(id) else {
+            return false;
+        };
+        self.pack_edits.insert(entry.id, None);
+        self.apply_packs();
+        true
+    }
+
+    /// Undo the user's edit or removal of a bundled entry
+    pub fn reset_pack_entry(&mut self, id: &str) -> bool {
+        if self.pack_edits.remove(id).is_none() {
+            return false;
+        }
+        self.apply_packs();
+        true
+    }
+
+    /// Replace pack shortcuts with the entries of enabled packs
+    fn apply_packs(&mut self) {
+        let trie = &mut self.trie;
+        self.shortcuts.retain(|trigger, s| {
+            if s.pack_entry.is_some() {
+                trie.remove(trigger);
+            }
+            s.pack_entry.is_none()
+        });
+        for pack in ShortcutPack::ALL {
+            if !self.pack_enabled(pack) {
+                continue;
+            }
+            for shortcut in self.pack_shortcuts(pack) {
+                if !self.shortcuts.contains_key(&shortcut.trigger) {
+                    self.insert(shortcut);
+                }
+            }
+        }
+    }
+
+    /// Check if buffer matches any shortcut (for any input method)
+    ///
+    /// Returns (trigger, shortcut) if match found
+    pub fn lookup(&self, buffer: &str) -> Option<(&str, &Shortcut)> {
+        self.lookup_for_method(buffer, InputMethod::All)
+    }
+
+    /// Check if buffer matches any shortcut for specific input method
+    ///
+    /// Issue #86: Case-insensitive matching - "ko", "Ko", "KO" all match trigger "ko"
+    /// Returns (trigger, shortcut) if match found. Walks the trigger trie,
+    /// O(buffer length) without allocating.
+    pub fn lookup_for_method(
+        &self,
+        buffer: &str,
+        method: InputMethod,
+    ) -> Option<(&str, &Shortcut)> {
+        let trigger = self.trie.get(buffer)?;
+        let shortcut = self.shortcuts.get(trigger)?;
+        (shortcut.enabled && shortcut.applies_to(method)).then_some((trigger, shortcut))
+    }
+
+    /// First enabled pattern shortcut matching the whole buffer
+    ///
+    /// Only walks the pattern list, which is empty unless the user added
+    /// pattern shortcuts. All patterns spend from one step budget (`steps`),
+    /// so adding more of them can't make a keystroke slower.
+    fn lookup_pattern(
+        &self,
+        buffer: &str,
+        method: InputMethod,
+        steps: &mut usize,
+    ) -> Option<(&Shortcut, Captures)> {
+        self.patterns.iter().find_map(|key| {
+            let shortcut = self.shortcuts.get(key)?;
+            if !shortcut.enabled || !shortcut.applies_to(method) {
+                return None;
+            }
+            let captures = shortcut.pattern.as_ref()?.captures_within(buffer, steps)?;
+            Some((shortcut, captures))
+        })
+    }
+
+    /// Start matching triggers one typed character at a time
+    ///
+    /// Step the cursor with each key; `trigger()` names a complete trigger
+    /// (look it up with `get`), and a failed step means no trigger starts
+    /// with what was typed.
+    pub fn cursor(&self) -> TriggerCursor<'_> {
+        self.trie.cursor()
+    }
+
+    /// Triggers starting with `prefix` (case-insensitive), sorted
+    pub fn triggers_with_prefix(&self, prefix: &str) -> Vec<&str> {
+        self.trie.with_prefix(prefix)
+    }
+
+    /// Try to match buffer with trigger key (for any input method)
+    ///
+    /// # Arguments
+    /// * `buffer` - Current buffer content (as string)
+    /// * `key_char` - The key that was just pressed
+    /// * `is_word_boundary` - Whether key_char is a word boundary
+    ///
+    /// # Returns
+    /// ShortcutMatch if a shortcut should be triggered
+    pub fn try_match(
+        &self,
+        buffer: &str,
+        key_char: Option<char>,
+        is_word_boundary: bool,
+    ) -> Option<ShortcutMatch> {
+        self.try_match_for_method(buffer, key_char, is_word_boundary, InputMethod::All)
+    }
+
+    /// Try to match buffer with trigger key for specific input method
+    ///
+    /// # Arguments
+    /// * `buffer` - Current buffer content (as string)
+    /// * `key_char` - The key that was just pressed
+    /// * `is_word_boundary` - Whether key_char is a word boundary
+    /// * `method` - The current input method (Telex/VNI)
+    ///
+    /// # Returns
+    /// ShortcutMatch if a shortcut should be triggered
+    pub fn try_match_for_method(
+        &self,
+        buffer: &str,
+        key_char: Option<char>,
+        is_word_boundary: bool,
+        method: InputMethod,
+    ) -> Option<ShortcutMatch> {
+        match self.lookup_for_method(buffer, method) {
+            // Use char count, not byte length (UTF-8 chars like đ are multi-byte)
+            Some((trigger, shortcut)) => self.matched(
+                buffer,
+                trigger.chars().count(),
+                shortcut,
+                None,
+                key_char,
+                is_word_boundary,
+            ),
+            None => self.try_match_pattern(buffer, key_char, is_word_boundary, method),
+        }
+    }
+
+    /// Like `try_match_for_method`, but only pattern shortcuts
+    ///
+    /// Used for words that start with digits or symbols ("149k"), where
+    /// fixed triggers must not fire but "(\d+)k" should.
+    pub fn try_match_pattern(
+        &self,
+        buffer: &str,
+        key_char: Option<char>,
+        is_word_boundary: bool,
+        method: InputMethod,
+    ) -> Option<ShortcutMatch> {
+        let (shortcut, captures) = self.lookup_pattern(buffer, method, &mut 0)?;
+        // Patterns match the whole buffer
+        let backspace_count = buffer.chars().count();
+        self.matched(
+            buffer,
+            backspace_count,
+            shortcut,
+            Some(&captures),
+            key_char,
+            is_word_boundary,
+        )
+    }
+
+    /// Expansion of a matched shortcut, if its trigger condition is met
+    fn matched(
+        &self,
+        buffer: &str,
+        backspace_count: usize,
+        shortcut: &Shortcut,
+        captures: Option<&Captures>,
+        key_char: Option<char>,
+        is_word_boundary: bool,
+    ) -> Option<ShortcutMatch> {
+        match shortcut.condition {
+            TriggerCondition::Immediate => {
+                let (output, caret_left) = self.expand(buffer, shortcut, captures);
+                Some(ShortcutMatch {
+                    backspace_count,
+                    output,
+                    include_trigger_key: false,
+                    caret_left,
+                })
+            }
+            TriggerCondition::OnWordBoundary => {
+                if is_word_boundary {
+                    let (mut output, mut caret_left) = self.expand(buffer, shortcut, captures);
+                    // Append the trigger key (space, etc.)
+                    if let Some(ch) = key_char {
+                        output.push(ch);
+                        if caret_left > 0 {
+                            caret_left += 1;
+                        }
+                    }
+                    Some(ShortcutMatch {
+                        backspace_count,
+                        output,
+                        include_trigger_key: true,
+                        caret_left,
+                    })
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Render the replacement (templates evaluated now, then `$1`.. filled
+    /// from `captures` for pattern shortcuts), match case and record the use
+    ///
+    /// Returns the text and the number of chars after `{cursor}` (0 if none).
+    fn expand(
+        &self,
+        buffer: &str,
+        shortcut: &Shortcut,
+        captures: Option<&Captures>,
+    ) -> (String, usize) {
+        {
+            let mut usage = self.usage.borrow_mut();
+            let entry = usage.entry(shortcut.trigger.clone()).or_default();
+            entry.count = entry.count.saturating_add(1);
+            entry.last_used = self.clock.unix_now();
+        }
+        let fill = |text: &str| match captures {
+            Some(captures) => pattern::substitute(text, captures),
+            None => text.to_string(),
+        };
+        let replacement = &shortcut.replacement;
+        if !replacement.contains(['{', '}']) {
+            return (
+                self.apply_case(buffer, &fill(replacement), shortcut.case_mode),
+                0,
+            );
+        }
+        let Some(template) = Template::parse(replacement) else {
+            return (
+                self.apply_case(buffer, &fill(replacement), shortcut.case_mode),
+                0,
+            );
+        };
+        let counter = {
+            let mut counters = self.counters.borrow_mut();
+            let count = counters.entry(shortcut.trigger.clone()).or_insert(0);
+            *count += 1;
+            *count
+        };
+        let (text, cursor) = template.render(&self.clock.now(), counter);
+        // Fill captures on each side of the cursor so the caret stays put
+        let (text, caret_left) = match cursor {
+            Some(at) => {
+                let split = text.char_indices().nth(at).map_or(text.len(), |(i, _)| i);
+                let after = fill(&text[split..]);
+                (fill(&text[..split]) + &after, after.chars().count())
+            }
+            None => (fill(&text), 0),
+        };
+        (
+            self.apply_case(buffer, &text, shortcut.case_mode),
+            caret_left,
+        )
+    }
+
+    /// Apply case transformation based on mode
+    fn apply_case(&self, trigger: &str, replacement: &str, mode: CaseMode) -> String {
+        match mode {
+            CaseMode::Exact => replacement.to_string(),
+            CaseMode::MatchCase => {
+                if trigger.chars().all(|c| c.is_uppercase()) {
+                    // All uppercase → replacement all uppercase
+                    replacement.to_uppercase()
+                } else if trigger
+                    .chars()
+                    .next()
+                    .map(|c| c.is_uppercase())
+                    .unwrap_or(false)
+                {
+                    // First char uppercase → capitalize replacement
+                    let mut chars = replacement.chars();
+                    match chars.next() {
+                        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                        None => String::new(),
+                    }
+                } else {
+                    // Lowercase → keep replacement as-is
+                    replacement.to_string()
+                }
+            }
+        }
+    }
+
+    /// Add or replace a shortcut and index its trigger
+    fn insert(&mut self, shortcut: Shortcut) {
+        let key = &shortcut.trigger;
+        if shortcut.pattern.is_some() {
+            if !self.patterns.contains(key) {
+                self.patterns.push(key.clone());
+            }
+            self.trie.remove(key);
+        } else {
+            self.trie.insert(key);
+            self.patterns.retain(|k| k != key);
+        }
+        self.shortcuts.insert(shortcut.trigger.clone(), shortcut);
+    }
+
+    /// Check if shortcut table is empty
+    pub fn is_empty(&self) -> bool {
+        self.shortcuts.is_empty()
+    }
+
+    /// Get number of shortcuts
+    pub fn len(&self) -> usize {
+        self.shortcuts.len()
+    }
+
+    /// Clear all user shortcuts (enabled packs and pack edits stay)
+    pub fn clear(&mut self) {
+        self.shortcuts.clear();
+        self.trie.clear();
+        self.patterns.clear();
+        self.apply_packs();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use template::DateTime;
+
+    // Helper: Create table with one word-boundary shortcut
+    fn table_with_shortcut(trigger: &str, replacement: &str) -> ShortcutTable {
+        let mut table = ShortcutTable::new();
+        table.add(Shortcut::new(trigger, replacement));
+        table
+    }
+
+    // Helper: Create table with one immediate shortcut
+    fn table_with_immediate(trigger: &str, replacement: &str) -> ShortcutTable {
+        let mut table = ShortcutTable::new();
+        table.add(Shortcut::immediate(trigger, replacement));
+        table
+    }
+
+    // Helper: Create table with Telex-specific shortcut
+    fn table_with_telex_shortcut(trigger: &str, replacement: &str) -> ShortcutTable {
+        let mut table = ShortcutTable::new();
+        table.add(Shortcut::telex(trigger, replacement));
+        table
+    }
+
+    // Helper: Create table with VNI-specific shortcut
+    fn table_with_vni_shortcut(trigger: &str, replacement: &str) -> ShortcutTable {
+        let mut table = ShortcutTable::new();
+        table.add(Shortcut::vni(trigger, replacement));
+        table
+    }
+
+    // Helper: Assert shortcut matches and check output/backspace
+    fn assert_shortcut_match(
+        table: &ShortcutTable,
+        buffer: &str,
+        key_char: Option<char>,
+        is_boundary: bool,
+        expected_output: &str,
+        expected_backspace: usize,
+        method: InputMethod,
+    ) {
+        let result = table.try_match_for_method(buffer, key_char, is_boundary, method);
+        assert!(
+            result.is_some(),
+            "Shortcut should match for buffer: {}",
+            buffer
+        );
+        let m = result.unwrap();
+        assert_eq!(m.output, expected_output);
+        assert_eq!(m.backspace_count, expected_backspace);
+    }
+
+    // Helper: Assert no shortcut match
+    fn assert_no_match(
+        table: &ShortcutTable,
+        buffer: &str,
+        key_char: Option<char>,
+        is_boundary: bool,
+        method: InputMethod,
+    ) {
+        let result = table.try_match_for_method(buffer, key_char, is_boundary, method);
+        assert!(
+            result.is_none(),
+            "Shortcut should NOT match for buffer: {}",
+            buffer
+        );
+    }
+
+    #[test]
+    fn test_basic_shortcut() {
+        let table = table_with_shortcut("vn", "Việt Nam");
+        assert_shortcut_match(
+            &table,
+            "vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::All,
+        );
+    }
+
+    #[test]
+    fn test_case_matching() {
+        let table = table_with_shortcut("vn", "Việt Nam");
+
+        // Lowercase "vn" → "Việt Nam" (as-is)
+        assert_shortcut_match(
+            &table,
+            "vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::All,
+        );
+
+        // Issue #86: Uppercase "VN" → "VIỆT NAM" (smart case)
+        assert_shortcut_match(
+            &table,
+            "VN",
+            Some(' '),
+            true,
+            "VIỆT NAM ",
+            2,
+            InputMethod::All,
+        );
+
+        // Issue #86: Title case "Vn" → "Việt Nam" (smart case)
+        assert_shortcut_match(
+            &table,
+            "Vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::All,
+        );
+    }
+
+    #[test]
+    fn test_immediate_shortcut() {
+        let table = table_with_immediate("w", "ư");
+
+        // Immediate triggers without word boundary
+        let result = table.try_match("w", None, false);
+        assert!(result.is_some());
+        let m = result.unwrap();
+        assert_eq!(m.output, "ư");
+        assert!(!m.include_trigger_key);
+    }
+
+    #[test]
+    fn test_word_boundary_required() {
+        let table = table_with_shortcut("vn", "Việt Nam");
+
+        // Without word boundary - should not match
+        assert_no_match(&table, "vn", Some('a'), false, InputMethod::All);
+
+        // With word boundary - should match
+        assert_shortcut_match(
+            &table,
+            "vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::All,
+        );
+    }
+
+    #[test]
+    fn test_longest_match() {
+        let mut table = ShortcutTable::new();
+        table.add(Shortcut::new("h", "họ"));
+        table.add(Shortcut::new("hcm", "Hồ Chí Minh"));
+
+        // "hcm" should match the longer shortcut
+        let (trigger, _) = table.lookup("hcm").unwrap();
+        assert_eq!(trigger, "hcm");
+    }
+
+    #[test]
+    fn test_disabled_shortcut() {
+        let mut table = ShortcutTable::new();
+        let mut shortcut = Shortcut::new("vn", "Việt Nam");
+        shortcut.enabled = false;
+        table.add(shortcut);
+
+        let result = table.lookup("vn");
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_telex_specific_shortcut() {
+        let table = table_with_telex_shortcut("w", "ư");
+
+        // Should match for Telex
+        assert_shortcut_match(&table, "w", None, false, "ư", 1, InputMethod::Telex);
+
+        // Should NOT match for VNI
+        assert_no_match(&table, "w", None, false, InputMethod::Vni);
+
+        // Should match for All (fallback)
+        assert_shortcut_match(&table, "w", None, false, "ư", 1, InputMethod::All);
+    }
+
+    #[test]
+    fn test_vni_specific_shortcut() {
+        let table = table_with_vni_shortcut("7", "ơ");
+
+        // Should match for VNI
+        assert_shortcut_match(&table, "7", None, false, "ơ", 1, InputMethod::Vni);
+
+        // Should NOT match for Telex
+        assert_no_match(&table, "7", None, false, InputMethod::Telex);
+    }
+
+    #[test]
+    fn test_all_input_method_shortcut() {
+        let table = table_with_shortcut("vn", "Việt Nam");
+
+        // Should match for Telex
+        assert_shortcut_match(
+            &table,
+            "vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::Telex,
+        );
+
+        // Should match for VNI
+        assert_shortcut_match(
+            &table,
+            "vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::Vni,
+        );
+
+        // Should match for All
+        assert_shortcut_match(
+            &table,
+            "vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::All,
+        );
+    }
+
+    #[test]
+    fn test_with_defaults_has_common_shortcuts() {
+        let table = ShortcutTable::with_defaults();
+
+        // Default shortcuts are currently disabled - table should be empty
+        assert!(table.is_empty());
+
+        // "vn" → "Việt Nam" should NOT exist (disabled)
+        let result = table.lookup_for_method("vn", InputMethod::All);
+        assert!(result.is_none());
+
+        // "w" is NOT a shortcut (handled by engine)
+        let result = table.lookup_for_method("w", InputMethod::Telex);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_shortcut_for_method_builder() {
+        let shortcut = Shortcut::new("test", "Test").for_method(InputMethod::Telex);
+        assert_eq!(shortcut.input_method, InputMethod::Telex);
+
+        let shortcut = Shortcut::immediate("x", "y").for_method(InputMethod::Vni);
+        assert_eq!(shortcut.input_method, InputMethod::Vni);
+    }
+
+    #[test]
+    fn test_applies_to() {
+        let all_shortcut = Shortcut::new("vn", "Việt Nam");
+        assert!(all_shortcut.applies_to(InputMethod::All));
+        assert!(all_shortcut.applies_to(InputMethod::Telex));
+        assert!(all_shortcut.applies_to(InputMethod::Vni));
+
+        let telex_shortcut = Shortcut::telex("test", "Test");
+        assert!(telex_shortcut.applies_to(InputMethod::All));
+        assert!(telex_shortcut.applies_to(InputMethod::Telex));
+        assert!(!telex_shortcut.applies_to(InputMethod::Vni));
+
+        let vni_shortcut = Shortcut::vni("7", "ơ");
+        assert!(vni_shortcut.applies_to(InputMethod::All));
+        assert!(!vni_shortcut.applies_to(InputMethod::Telex));
+        assert!(vni_shortcut.applies_to(InputMethod::Vni));
+    }
+
+    #[test]
+    fn test_replacement_validation_within_limit() {
+        // Vietnamese text within limit (21 codepoints)
+        let shortcut = Shortcut::new("tphcm", "Thành phố Hồ Chí Minh");
+        assert_eq!(shortcut.replacement, "Thành phố Hồ Chí Minh");
+        assert_eq!(shortcut.replacement.chars().count(), 21);
+    }
+
+    #[test]
+    fn test_replacement_not_truncated() {
+        // Longer than one FFI result (255 chars): sent in parts, not cut
+        let long_text = "Đây là một đoạn văn bản rất dài để kiểm tra việc cắt ngắn. Nó có nhiều ký tự tiếng Việt có dấu như ồ, ế, ẫ, ơ, ư. Tiếp tục thêm nhiều nội dung để vượt quá giới hạn 255 ký tự. Đây là một câu rất dài với nhiều từ tiếng Việt phức tạp để đảm bảo rằng chúng ta vượt quá giới hạn cho phép của hệ thống.";
+        assert!(long_text.chars().count() > 255);
+
+        let shortcut = Shortcut::new("long", long_text);
+        assert_eq!(shortcut.replacement, long_text);
+    }
+
+    #[test]
+    fn test_replacement_line_breaks_normalized() {
+        let shortcut = Shortcut::new("sig", "Trân trọng,\r\nNam\rGõ Nhanh\n\tHà Nội");
+        assert_eq!(shortcut.replacement, "Trân trọng,\nNam\nGõ Nhanh\n\tHà Nội");
+    }
+
+    #[test]
+    fn test_replacement_validation_vietnamese_diacritics() {
+        // Each Vietnamese character with diacritic is 1 codepoint
+        // "ồ" = 1 codepoint, "ế" = 1 codepoint, "ẫ" = 1 codepoint
+        let vietnamese = "ồếẫơưáàảãạăắằẳẵặâấầẩẫậ"; // 22 Vietnamese chars
+        let shortcut = Shortcut::new("viet", vietnamese);
+        assert_eq!(shortcut.replacement.chars().count(), 22);
+        assert_eq!(shortcut.replacement, vietnamese);
+    }
+
+    // =========================================================================
+    // Issue #178: Shortcuts > 63 chars should work
+    // https://github.com/user/gonhanh/issues/178
+    // =========================================================================
+
+    #[test]
+    fn issue178_long_shortcut_100_chars() {
+        // Test that shortcuts with 100+ chars work (previously limited to 63)
+        let long_replacement = "Đây là một đoạn văn bản dài hơn 63 ký tự để kiểm tra rằng gõ tắt giờ hỗ trợ nội dung dài hơn trước.";
+        let char_count = long_replacement.chars().count();
+        assert!(
+            char_count > 63,
+            "Test text should exceed old limit of 63 (got {})",
+            char_count
+        );
+
+        let table = table_with_shortcut("long", long_replacement);
+        let result = table.try_match("long", Some(' '), true);
+        assert!(result.is_some(), "Shortcut should match");
+
+        let m = result.unwrap();
+        // Full replacement + trailing space
+        assert_eq!(
+            m.output.chars().count(),
+            char_count + 1,
+            "Output should contain full replacement + space"
+        );
+        assert!(
+            m.output.starts_with(long_replacement),
+            "Output should start with full replacement"
+        );
+    }
+
+    #[test]
+    fn issue178_long_shortcut_200_chars() {
+        // Test even longer shortcut (200 chars)
+        let long_replacement = "Thành phố Hồ Chí Minh là thành phố lớn nhất Việt Nam về dân số và kinh tế. Đây là một trong những trung tâm kinh tế, chính trị, văn hóa và giáo dục quan trọng nhất của cả nước. Thành phố này còn được biết đến với nhiều tên gọi khác.";
+        let char_count = long_replacement.chars().count();
+        assert!(
+            char_count > 100,
+            "Test text should be > 100 chars (got {})",
+            char_count
+        );
+
+        let shortcut = Shortcut::new("hcm2", long_replacement);
+        assert_eq!(
+            shortcut.replacement.chars().count(),
+            char_count,
+            "Replacement should not be truncated for {} chars",
+            char_count
+        );
+        assert_eq!(shortcut.replacement, long_replacement);
+    }
+
+    // =========================================================================
+    // Issue #86: Smart Case-Aware Shortcuts
+    // https://github.com/khaphanspace/gonhanh.org/issues/86
+    // =========================================================================
+
+    #[test]
+    fn issue86_smart_case_lowercase() {
+        let table = table_with_shortcut("ko", "không");
+        assert_shortcut_match(&table, "ko", Some(' '), true, "không ", 2, InputMethod::All);
+    }
+
+    #[test]
+    fn issue86_smart_case_uppercase() {
+        let table = table_with_shortcut("ko", "không");
+        assert_shortcut_match(&table, "KO", Some(' '), true, "KHÔNG ", 2, InputMethod::All);
+    }
+
+    #[test]
+    fn issue86_smart_case_titlecase() {
+        let table = table_with_shortcut("ko", "không");
+        assert_shortcut_match(&table, "Ko", Some(' '), true, "Không ", 2, InputMethod::All);
+    }
+
+    #[test]
+    fn issue86_smart_case_vn_lowercase() {
+        let table = table_with_shortcut("vn", "Việt Nam");
+        assert_shortcut_match(
+            &table,
+            "vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::All,
+        );
+    }
+
+    #[test]
+    fn issue86_smart_case_vn_uppercase() {
+        let table = table_with_shortcut("vn", "Việt Nam");
+        assert_shortcut_match(
+            &table,
+            "VN",
+            Some(' '),
+            true,
+            "VIỆT NAM ",
+            2,
+            InputMethod::All,
+        );
+    }
+
+    #[test]
+    fn issue86_smart_case_vn_titlecase() {
+        let table = table_with_shortcut("vn", "Việt Nam");
+        assert_shortcut_match(
+            &table,
+            "Vn",
+            Some(' '),
+            true,
+            "Việt Nam ",
+            2,
+            InputMethod::All,
+        );
+    }
+
+    #[test]
+    fn issue86_smart_case_immediate_shortcut() {
+        let table = table_with_immediate("dc", "được");
+
+        let result = table.try_match("dc", None, false);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().output, "được");
+
+        let result = table.try_match("DC", None, false);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().output, "ĐƯỢC");
+
+        let result = table.try_match("Dc", None, false);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().output, "Được");
+    }
+
+    #[test]
+    fn issue86_smart_case_lookup_case_insensitive() {
+        let table = table_with_shortcut("ko", "không");
+        assert!(table.lookup("ko").is_some());
+        assert!(table.lookup("Ko").is_some());
+        assert!(table.lookup("KO").is_some());
+        assert!(table.lookup("kO").is_some());
+    }
+
+    #[test]
+    fn issue86_smart_case_mixed_case_fallback() {
+        let table = table_with_shortcut("ko", "không");
+        assert_shortcut_match(&table, "kO", Some(' '), true, "không ", 2, InputMethod::All);
+    }
+
+    #[test]
+    fn issue86_smart_case_hcm() {
+        let table = table_with_shortcut("hcm", "Hồ Chí Minh");
+        assert_shortcut_match(
+            &table,
+            "hcm",
+            Some(' '),
+            true,
+            "Hồ Chí Minh ",
+            3,
+            InputMethod::All,
+        );
+        assert_shortcut_match(
+            &table,
+            "HCM",
+            Some(' '),
+            true,
+            "HỒ CHÍ MINH ",
+            3,
+            InputMethod::All,
+        );
+        assert_shortcut_match(
+            &table,
+            "Hcm",
+            Some(' '),
+            true,
+            "Hồ Chí Minh ",
+            3,
+            InputMethod::All,
+        );
+    }
+
+    #[test]
+    fn test_shortcut_packs() {
+        let mut table = ShortcutTable::new();
+        table.set_pack_enabled(ShortcutPack::Arrows, true);
+        assert!(table.pack_enabled(ShortcutPack::Arrows));
+        let (_, s) = table.lookup("->").unwrap();
+        assert_eq!(s.replacement, "→");
+        assert_eq!(s.condition, TriggerCondition::Immediate);
+        assert_eq!(s.pack_entry, Some("arrows.right"));
+        assert!(table.lookup("!=").is_none());
+
+        table.set_pack_enabled(ShortcutPack::Arrows, false);
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn test_user_shortcut_beats_pack() {
+        let mut table = ShortcutTable::new();
+        table.add(Shortcut::new("ko", "không có"));
+        table.set_pack_enabled(ShortcutPack::Vietnamese, true);
+        assert_eq!(table.lookup("ko").unwrap().1.replacement, "không có");
+
+        // Pack entry comes back when the user shortcut goes away
+        table.remove("ko");
+        assert_eq!(table.lookup("ko").unwrap().1.replacement, "không");
+
+        // Clearing user shortcuts keeps packs
+        table.add(Shortcut::new("abc", "xyz"));
+        table.clear();
+        assert!(table.lookup("abc").is_none());
+        assert!(table.lookup("ko").is_some());
+    }
+
+    #[test]
+    fn test_pack_entry_edits() {
+        let mut table = ShortcutTable::new();
+        assert!(table.edit_pack_entry("currency.vnd", "dong", "₫"));
+        assert!(table.remove_pack_entry("arrows.left"));
+        assert!(!table.edit_pack_entry("currency.nope", "x", "y"));
+        // Edits made while disabled apply once the pack is enabled
+        assert!(table.is_empty());
+
+        table.set_pack_enabled(ShortcutPack::Currency, true);
+        table.set_pack_enabled(ShortcutPack::Arrows, true);
+        assert!(table.lookup("vnd").is_none());
+        assert_eq!(
+            table.lookup("dong").unwrap().1.pack_entry,
+            Some("currency.vnd")
+        );
+        assert!(table.lookup("<-").is_none());
+        assert!(table
+            .pack_shortcuts(ShortcutPack::Arrows)
+            .iter()
+            .all(|s| s.pack_entry != Some("arrows.left")));
+
+        assert!(table.reset_pack_entry("currency.vnd"));
+        assert!(table.reset_pack_entry("arrows.left"));
+        assert!(!table.reset_pack_entry("arrows.left"));
+        assert!(table.lookup("vnd").is_some());
+        assert!(table.lookup("dong").is_none());
+        assert!(table.lookup("<-").is_some());
+    }
+
+    #[test]
+    fn test_pack_entry_conditions() {
+        let mut table = ShortcutTable::new();
+        table.set_pack_enabled(ShortcutPack::Math, true);
+        let condition =
+            |table: &ShortcutTable, trigger: &str| table.lookup(trigger).unwrap().1.condition;
+        assert_eq!(condition(&table, "!="), TriggerCondition::Immediate);
+        // Digits go to the word buffer, so "^2" waits for the boundary
+        assert_eq!(condition(&table, "^2"), TriggerCondition::OnWordBoundary);
+
+        assert!(table.edit_pack_entry("math.squared", "^^2", "²"));
+        assert_eq!(condition(&table, "^^2"), TriggerCondition::OnWordBoundary);
+        assert!(table.edit_pack_entry("math.approx", "~~", "≈"));
+        assert_eq!(condition(&table, "~~"), TriggerCondition::Immediate);
+        assert!(table.edit_pack_entry("math.approx", "xap", "≈"));
+        assert_eq!(condition(&table, "xap"), TriggerCondition::OnWordBoundary);
+    }
+
+    #[test]
+    fn test_with_all_defaults_leaves_packs_off() {
+        let table = ShortcutTable::with_all_defaults();
+        for pack in ShortcutPack::ALL {
+            assert!(!table.pack_enabled(pack));
+        }
+        assert_eq!(table.lookup("hcm").unwrap().1.replacement, "Hồ Chí Minh");
+        assert!(table.lookup("->").is_none());
+    }
+
+    #[test]
+    fn test_template_shortcuts() {
+        let mut table = ShortcutTable::new();
+        table.set_clock(Clock::Fixed(DateTime {
+            year: 2025,
+            month: 1,
+            day: 29,
+            hour: 9,
+            minute: 30,
+            second: 0,
+        }));
+        assert!(table.add(Shortcut::new("ngay", "{weekday_vi}, {date}")));
+        assert!(table.add(Shortcut::new("so", "#{counter}")));
+        assert!(!table.add(Shortcut::new("bad", "{date:qq}")));
+        assert!(!table.add(Shortcut::new("bad2", "{cursor}{cursor}")));
+        assert!(table.lookup("bad").is_none());
+        // Braces that don't start a placeholder are literal
+        assert!(table.add(Shortcut::new("obj", "{}")));
+        assert!(table.add(Shortcut::new("json", "{\"a\": 1}")));
+
+        assert_shortcut_match(
+            &table,
+            "ngay",
+            Some(' '),
+            true,
+            "Thứ Tư, 29/01/2025 ",
+            4,
+            InputMethod::All,
+        );
+        for expected in ["#1 ", "#2 ", "#3 "] {
+            assert_shortcut_match(&table, "so", Some(' '), true, expected, 2, InputMethod::All);
+        }
+    }
+
+    #[test]
+    fn test_usage_tracking() {
+        let mut table = ShortcutTable::new();
+        let now = DateTime {
+            year: 2025,
+            month: 1,
+            day: 29,
+            hour: 9,
+            minute: 30,
+            second: 0,
+        };
+        table.set_clock(Clock::Fixed(now));
+        table.add(Shortcut::new("vn", "Việt Nam"));
+        assert_eq!(table.usage("vn"), ShortcutUsage::default());
+
+        table.try_match("VN", Some(' '), true);
+        table.try_match("vn", Some(' '), true);
+        table.try_match("vn", Some('a'), false);
+        assert_eq!(
+            table.usage("Vn"),
+            ShortcutUsage {
+                count: 2,
+                last_used: now.to_unix(0)
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_enabled() {
+        let mut table = ShortcutTable::new();
+        table.add(Shortcut::new("vn", "Việt Nam"));
+        table.set_pack_enabled(ShortcutPack::Arrows, true);
+
+        assert!(table.set_enabled("VN", false));
+        assert!(table.lookup("vn").is_none());
+        assert!(!table.get("vn").unwrap().enabled);
+        assert!(table.set_enabled("vn", true));
+        assert!(table.lookup("vn").is_some());
+        assert!(!table.set_enabled("nope", false));
+
+        // Pack entries stay disabled when packs are re-applied
+        assert!(table.set_enabled("->", false));
+        table.set_pack_enabled(ShortcutPack::Math, true);
+        assert!(table.lookup("->").is_none());
+        assert!(table.reset_pack_entry("arrows.right"));
+        assert!(table.lookup("->").is_some());
+    }
+
+    #[test]
+    fn test_replace_all_is_atomic() {
+        let mut table = ShortcutTable::new();
+        table.add(Shortcut::new("vn", "Việt Nam"));
+        table.set_pack_enabled(ShortcutPack::Arrows, true);
+
+        let bad = vec![
+            Shortcut::new("hn", "Hà Nội"),
+            Shortcut::new("x", "{date:qq}"),
+        ];
+        assert!(!table.replace_all(bad));
+        assert!(table.get("vn").is_some());
+        assert!(table.get("hn").is_none());
+
+        assert!(table.replace_all(vec![Shortcut::new("hn", "Hà Nội")]));
+        let triggers: Vec<&str> = table.entries().iter().map(|s| s.trigger.as_str()).collect();
+        assert_eq!(triggers, ["->", "<-", "=>", "hn"]);
+    }
+
+    #[test]
+    fn test_option_ids() {
+        for method in [InputMethod::All, InputMethod::Telex, InputMethod::Vni] {
+            assert_eq!(InputMethod::from_id(method as u8), Some(method));
+        }
+        assert_eq!(
+            TriggerCondition::from_id(1),
+            Some(TriggerCondition::OnWordBoundary)
+        );
+        assert_eq!(CaseMode::from_id(0), Some(CaseMode::Exact));
+        assert_eq!(CaseMode::from_id(2), None);
+    }
+
+    #[test]
+    fn test_pattern_step_budget_is_shared() {
+        // Exponential backtracking; "[bc]" leaves no literal suffix to reject early
+        let mut table = ShortcutTable::new();
+        for i in 0..10 {
+            let pattern = format!("(?:a|a)*(a*)*[bc]{}", "?".repeat(i % 2));
+            table.add(Shortcut::pattern(&format!("x{}|{}", i, pattern), "y").unwrap());
+        }
+        let word = format!("{}d", "a".repeat(60));
+        let mut steps = 0;
+        assert!(table
+            .lookup_pattern(&word, InputMethod::All, &mut steps)
+            .is_none());
+        // Ten patterns together stop at one word's budget
+        assert_eq!(steps, pattern::MAX_STEPS);
+    }
+}
@@ -0,0 +1,585 @@
+//! Pattern Triggers - shortcuts whose trigger is a small regular expression
+//!
+//! Some expansions are patterns, not fixed strings: `(\d+)tr` → `$1 triệu`
+//! turns "50tr" into "50 triệu". The whole typed word must match; letters
+//! match case-insensitively. Supported syntax:
+//!
+//! - Literal chars; `\` escapes a special char (`\.`, `\(`, `\$`)
+//! - `.` any char, `\d` digit, `\w` letter or digit
+//! - `[abc]`, `[a-z0-9]`, `[^0-9]` classes (`\d`, `\w` allowed inside)
+//! - `(...)` capturing group (at most 9), `(?:...)` non-capturing, `|`
+//! - `?`, `*`, `+`, `{n}`, `{n,}`, `{n,m}` (bounds up to `MAX_REPEAT`)
+//!
+//! Replacements use `$1`..`$9` for captured text (as typed), `$0` for the
+//! whole word and `$$` for a dollar sign. Templates (`{date}`, `{cursor}`)
+//! work as in fixed shortcuts.
+//!
+//! Matching backtracks with a step budget (`MAX_STEPS`) shared by every
+//! pattern tried on a word, so pathological patterns give up (no match)
+//! instead of stalling a keystroke, however many of them there are.
+
+/// Longest accepted pattern, in chars
+pub const MAX_PATTERN_LEN: usize = 64;
+
+/// Largest `{n,m}` bound
+pub const MAX_REPEAT: u32 = 32;
+
+/// Matching steps allowed per word, across all patterns, before giving up
+pub const MAX_STEPS: usize = 10_000;
+
+const MAX_GROUPS: usize = 9;
+
+/// Compiled pattern trigger
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    root: Node,
+    groups: usize,
+    /// Literal text every match starts / ends with (quick reject)
+    prefix: Vec<char>,
+    suffix: Vec<char>,
+}
+
+/// Captured spans (char offsets) of a match, index 0 is the whole word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures {
+    spans: [Option<(usize, usize)>; MAX_GROUPS + 1],
+    text: Vec<char>,
+}
+
+impl Captures {
+    /// Text of group `i` as typed, None if the group did not take part
+    pub fn get(&self, i: usize) -> Option<String> {
+        let (start, end) = (*self.spans.get(i)?)?;
+        Some(self.text[start..end].iter().collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    /// Lowercase literal
+    Char(char),
+    Any,
+    Class(Class),
+    Group {
+        alternatives: Vec<Vec<Node>>,
+        capture: Option<usize>,
+    },
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        /// None = unbounded
+        max: Option<u32>,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Class {
+    items: Vec<ClassItem>,
+    negated: bool,
+}
+
+#[derive(Debug, Clone)]
+enum ClassItem {
+    Range(char, char),
+    Digit,
+    Word,
+}
+
+impl Class {
+    fn matches(&self, c: char) -> bool {
+        let hit = self.items.iter().any(|item| match *item {
+            ClassItem::Range(lo, hi) => (lo..=hi).contains(&c),
+            ClassItem::Digit => c.is_ascii_digit(),
+            ClassItem::Word => c.is_alphanumeric(),
+        });
+        hit != self.negated
+    }
+}
+
+impl Pattern {
+    /// Compile a pattern, None if it is malformed or too long
+    pub fn parse(source: &str) -> Option<Self> {
+        let chars: Vec<char> = source.chars().collect();
+        if chars.is_empty() || chars.len() > MAX_PATTERN_LEN {
+            return None;
+        }
+        let mut parser = Parser {
+            chars,
+            pos: 0,
+            groups: 0,
+        };
+        let alternatives = parser.alternatives()?;
+        if parser.pos != parser.chars.len() {
+            return None;
+        }
+        let (prefix, suffix) = match alternatives.as_slice() {
+            [only] => (literal_run(only.iter()), {
+                let mut s = literal_run(only.iter().rev());
+                s.reverse();
+                s
+            }),
+            _ => (vec![], vec![]),
+        };
+        Some(Self {
+            root: Node::Group {
+                alternatives,
+                capture: None,
+            },
+            groups: parser.groups,
+            prefix,
+            suffix,
+        })
+    }
+
+    /// Number of capturing groups
+    pub fn groups(&self) -> usize {
+        self.groups
+    }
+
+    /// Match the whole word; None if it doesn't match or the step budget runs out
+    pub fn captures(&self, word: &str) -> Option<Captures> {
+        self.captures_within(word, &mut 0)
+    }
+
+    /// Like `captures`, spending from a budget shared with other patterns
+    ///
+    /// `steps` counts steps already used and grows by the steps this match
+    /// takes; it never exceeds `MAX_STEPS`.
+    pub fn captures_within(&self, word: &str, steps: &mut usize) -> Option<Captures> {
+        let text: Vec<char> = word.chars().collect();
+        let lower: Vec<char> = text.iter().map(|&c| lowercase(c)).collect();
+        if !lower.starts_with(&self.prefix) || !lower.ends_with(&self.suffix) {
+            return None;
+        }
+        let mut m = Matcher {
+            text: &lower,
+            steps: *steps,
+            spans: [None; MAX_GROUPS + 1],
+        };
+        let end = lower.len();
+        let matched = m.node(&self.root, 0, &mut |_, pos| pos == end);
+        *steps = m.steps;
+        if !matched {
+            return None;
+        }
+        let mut spans = m.spans;
+        spans[0] = Some((0, end));
+        Some(Captures { spans, text })
+    }
+
+    /// Check that a replacement only refers to existing groups
+    pub fn check_replacement(&self, replacement: &str) -> bool {
+        let mut chars = replacement.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '$' {
+                continue;
+            }
+            match chars.peek().and_then(|c| c.to_digit(10)) {
+                Some(i) if i as usize > self.groups => return false,
+                Some(_) => {
+                    chars.next();
+                }
+                None => {
+                    chars.next_if_eq(&'$');
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Replace `$0`..`$9` with captured text and `$$` with `$`
+pub fn substitute(replacement: &str, captures: &Captures) -> String {
+    let mut out = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            out.push(c);
+            continue;
+        }
+        match chars.peek().copied() {
+            Some('$') => {
+                chars.next();
+                out.push('$');
+            }
+            Some(d) if d.is_ascii_digit() => {
+                chars.next();
+                let i = d as usize - '0' as usize;
+                out.push_str(&captures.get(i).unwrap_or_default());
+            }
+            _ => out.push('$'),
+        }
+    }
+    out
+}
+
+/// Single-char lowercase, keeping offsets aligned with the typed text
+fn lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+/// Leading literal chars of a sequence
+fn literal_run<'a>(nodes: impl Iterator<Item = &'a Node>) -> Vec<char> {
+    nodes
+        .map_while(|n| match n {
+            Node::Char(c) => Some(*c),
+            _ => None,
+        })
+        .collect()
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    groups: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let hit = self.peek() == Some(c);
+        if hit {
+            self.pos += 1;
+        }
+        hit
+    }
+
+    fn alternatives(&mut self) -> Option<Vec<Vec<Node>>> {
+        let mut alternatives = vec![self.sequence()?];
+        while self.eat('|') {
+            alternatives.push(self.sequence()?);
+        }
+        Some(alternatives)
+    }
+
+    fn sequence(&mut self) -> Option<Vec<Node>> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            nodes.push(self.quantified(atom)?);
+        }
+        Some(nodes)
+    }
+
+    fn atom(&mut self) -> Option<Node> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(match c {
+            '.' => Node::Any,
+            '(' => {
+                let capture = if self.eat('?') {
+                    if !self.eat(':') {
+                        return None;
+                    }
+                    None
+                } else {
+                    self.groups += 1;
+                    if self.groups > MAX_GROUPS {
+                        return None;
+                    }
+                    Some(self.groups)
+                };
+                let alternatives = self.alternatives()?;
+                if !self.eat(')') {
+                    return None;
+                }
+                Node::Group {
+                    alternatives,
+                    capture,
+                }
+            }
+            '[' => Node::Class(self.class()?),
+            '\\' => match self.escape()? {
+                ClassItem::Range(c, _) => Node::Char(c),
+                item => Node::Class(Class {
+                    items: vec![item],
+                    negated: false,
+                }),
+            },
+            '?' | '*' | '+' | '{' | ')' | ']' | '}' => return None,
+            c => Node::Char(lowercase(c)),
+        })
+    }
+
+    /// Char after `\`: `d`, `w` or an escaped literal
+    fn escape(&mut self) -> Option<ClassItem> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(match c {
+            'd' => ClassItem::Digit,
+            'w' => ClassItem::Word,
+            c if c.is_alphanumeric() => return None,
+            c => ClassItem::Range(c, c),
+        })
+    }
+
+    fn class(&mut self) -> Option<Class> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+        loop {
+            let c = self.peek()?;
+            self.pos += 1;
+            let item = match c {
+                ']' if !items.is_empty() => break,
+                '\\' => self.escape()?,
+                c => ClassItem::Range(lowercase(c), lowercase(c)),
+            };
+            // Range "a-z" (a trailing '-' is literal)
+            if let ClassItem::Range(lo, _) = item {
+                if self.peek() == Some('-') && self.chars.get(self.pos + 1) != Some(&']') {
+                    self.pos += 1;
+                    let hi = lowercase(self.peek()?);
+                    self.pos += 1;
+                    if hi < lo {
+                        return None;
+                    }
+                    items.push(ClassItem::Range(lo, hi));
+                    continue;
+                }
+            }
+            items.push(item);
+        }
+        Some(Class { items, negated })
+    }
+
+    fn quantified(&mut self, node: Node) -> Option<Node> {
+        let (min, max) = match self.peek() {
+            Some('?') => (0, Some(1)),
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('{') => {
+                self.pos += 1;
+                let min = self.number()?;
+                let max = if self.eat(',') {
+                    match self.peek() {
+                        Some('}') => None,
+                        _ => Some(self.number()?),
+                    }
+                } else {
+                    Some(min)
+                };
+                if !self.eat('}') || max.is_some_and(|max| max < min) {
+                    return None;
+                }
+                return Some(Node::Repeat {
+                    node: Box::new(node),
+                    min,
+                    max,
+                });
+            }
+            _ => return Some(node),
+        };
+        self.pos += 1;
+        Some(Node::Repeat {
+            node: Box::new(node),
+            min,
+            max,
+        })
+    }
+
+    fn number(&mut self) -> Option<u32> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let n: u32 = self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()?;
+        (n <= MAX_REPEAT).then_some(n)
+    }
+}
+
+type Next<'k, 'a> = &'k mut dyn FnMut(&mut Matcher<'a>, usize) -> bool;
+
+/// Backtracking matcher; each step spends budget, running out fails the match
+struct Matcher<'a> {
+    text: &'a [char],
+    steps: usize,
+    spans: [Option<(usize, usize)>; MAX_GROUPS + 1],
+}
+
+impl<'a> Matcher<'a> {
+    fn tick(&mut self) -> bool {
+        if self.steps == MAX_STEPS {
+            return false;
+        }
+        self.steps += 1;
+        true
+    }
+
+    /// Match `node` at `pos`, then continue with `next` from where it ended
+    fn node(&mut self, node: &Node, pos: usize, next: Next<'_, 'a>) -> bool {
+        if !self.tick() {
+            return false;
+        }
+        let single = |c: Option<&char>| match (node, c) {
+            (Node::Char(want), Some(c)) => want == c,
+            (Node::Any, Some(_)) => true,
+            (Node::Class(class), Some(&c)) => class.matches(c),
+            _ => false,
+        };
+        match node {
+            Node::Char(_) | Node::Any | Node::Class(_) => {
+                single(self.text.get(pos)) && next(self, pos + 1)
+            }
+            Node::Group {
+                alternatives,
+                capture,
+            } => alternatives.iter().any(|alternative| {
+                self.sequence(alternative, pos, &mut |m, end| match *capture {
+                    Some(i) => {
+                        let saved = m.spans[i];
+                        m.spans[i] = Some((pos, end));
+                        next(m, end) || {
+                            m.spans[i] = saved;
+                            false
+                        }
+                    }
+                    None => next(m, end),
+                })
+            }),
+            Node::Repeat { node, min, max } => self.repeat(node, *min, *max, 0, pos, next),
+        }
+    }
+
+    fn sequence(&mut self, nodes: &[Node], pos: usize, next: Next<'_, 'a>) -> bool {
+        match nodes.split_first() {
+            None => next(self, pos),
+            Some((first, rest)) => self.node(first, pos, &mut |m, p| m.sequence(rest, p, next)),
+        }
+    }
+
+    /// Greedy repeat: try one more iteration first, then stop if allowed
+    fn repeat(
+        &mut self,
+        node: &Node,
+        min: u32,
+        max: Option<u32>,
+        count: u32,
+        pos: usize,
+        next: Next<'_, 'a>,
+    ) -> bool {
+        if !self.tick() {
+            return false;
+        }
+        let more = max.is_none_or(|max| count < max);
+        // Empty iterations only count toward the minimum (no endless loops)
+        if more
+            && self.node(node, pos, &mut |m, p| {
+                (p != pos || count < min) && m.repeat(node, min, max, count + 1, p, next)
+            })
+        {
+            return true;
+        }
+        count >= min && next(self, pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(pattern: &str, replacement: &str, word: &str) -> Option<String> {
+        let p = Pattern::parse(pattern).unwrap();
+        p.captures(word).map(|c| substitute(replacement, &c))
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            expand(r"(\d+)tr", "$1 triệu", "50tr").as_deref(),
+            Some("50 triệu")
+        );
+        assert_eq!(
+            expand(r"(\d+)k", "$1.000 đ", "120K").as_deref(),
+            Some("120.000 đ")
+        );
+        assert_eq!(expand(r"(\d+)k", "$1.000 đ", "k"), None);
+        assert_eq!(expand(r"(\d+)k", "$1.000 đ", "12kk"), None);
+        assert_eq!(
+            expand(r"sdt(\d{4})(\d{3})(\d{3})", "$1 $2 $3", "sdt0912345678").as_deref(),
+            Some("0912 345 678")
+        );
+        assert_eq!(
+            expand(r"sdt(\d{4})(\d{3})(\d{3})", "$1", "sdt091234567"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_syntax() {
+        assert_eq!(expand("colou?r", "$0", "COLOR").as_deref(), Some("COLOR"));
+        assert_eq!(expand("(?:ab)+c", "x", "ababc").as_deref(), Some("x"));
+        assert_eq!(expand("a|bc", "$0", "bc").as_deref(), Some("bc"));
+        assert_eq!(expand("(a|b)(x)?", "[$1$2]", "b").as_deref(), Some("[b]"));
+        assert_eq!(expand("[^0-9]+", "$0", "ab").as_deref(), Some("ab"));
+        assert_eq!(expand("[^0-9]+", "$0", "a1"), None);
+        assert_eq!(
+            expand(r"[\d.]+\$", "$$$0", "1.5$").as_deref(),
+            Some("$1.5$")
+        );
+        assert_eq!(expand(r"\w{2,3}", "$0", "đi").as_deref(), Some("đi"));
+        assert_eq!(expand(r"a.c", "$0", "a-c").as_deref(), Some("a-c"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        for bad in [
+            "",
+            "(",
+            "a)",
+            "[",
+            "[z-a]",
+            "*a",
+            "a{2",
+            "a{3,1}",
+            "a{99}",
+            r"\q",
+            "(?x)",
+            "(a)(a)(a)(a)(a)(a)(a)(a)(a)(a)",
+        ] {
+            assert!(Pattern::parse(bad).is_none(), "{}", bad);
+        }
+        assert!(Pattern::parse(&"a".repeat(MAX_PATTERN_LEN + 1)).is_none());
+
+        let p = Pattern::parse(r"(\d+)k").unwrap();
+        assert!(p.check_replacement("$1.000 $$ $0"));
+        assert!(!p.check_replacement("$2"));
+    }
+
+    #[test]
+    fn test_step_budget() {
+        // Exponential backtracking on a non-match gives up instead of hanging
+        let p = Pattern::parse("(a*)*[b]").unwrap();
+        let word = "a".repeat(40);
+        assert!(p.captures(&word).is_none());
+        let mut m = Matcher {
+            text: &word.chars().collect::<Vec<_>>(),
+            steps: 0,
+            spans: [None; MAX_GROUPS + 1],
+        };
+        assert!(!m.node(&p.root, 0, &mut |_, pos| pos == 40));
+        assert_eq!(m.steps, MAX_STEPS);
+        let p = Pattern::parse("(?:a|aa)+b").unwrap();
+        assert!(p.captures(&format!("{}b", "a".repeat(20))).is_some());
+
+        // A spent budget fails at once, even for an easy match
+        let mut steps = MAX_STEPS;
+        assert!(p.captures_within("aab", &mut steps).is_none());
+        let mut steps = 0;
+        assert!(p.captures_within("aab", &mut steps).is_some());
+        assert!(steps > 0 && steps < MAX_STEPS);
+    }
+}
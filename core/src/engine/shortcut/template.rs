@@ -0,0 +1,427 @@
+//! Shortcut templates - placeholders evaluated at expansion time
+//!
+//! A replacement like "Hà Nội, {date:'ngày' d 'tháng' M 'năm' yyyy}" is
+//! rendered each time the shortcut fires:
+//!
+//! - `{date}` / `{date:FMT}`: date, default `dd/MM/yyyy`
+//! - `{time}` / `{time:FMT}`: time, default `HH:mm`
+//! - `{weekday_vi}`: Vietnamese weekday ("Thứ Hai" ... "Chủ Nhật")
+//! - `{year}`: four-digit year
+//! - `{counter}`: 1, 2, 3, ... per shortcut
+//! - `{cursor}`: where the caret lands after expansion (at most once)
+//! - `{{` and `}}`: literal braces
+//!
+//! Any other brace that doesn't start one of these placeholders ("{}",
+//! `{"a": 1}`, "{nope}") is copied as is.
+//!
+//! Format tokens: `yyyy yy M MM d dd H HH m mm s ss`; text in single
+//! quotes and any other non-letter character is copied as is.
+//!
+//! Templates are parsed when the shortcut is added so malformed
+//! placeholders (bad format, format on a placeholder without one, second
+//! `{cursor}`) are rejected up front.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Vietnam (UTC+07:00), used until the platform sets the local offset
+pub const DEFAULT_UTC_OFFSET_MINUTES: i32 = 7 * 60;
+
+const WEEKDAYS_VI: [&str; 7] = [
+    "Thứ Hai",
+    "Thứ Ba",
+    "Thứ Tư",
+    "Thứ Năm",
+    "Thứ Sáu",
+    "Thứ Bảy",
+    "Chủ Nhật",
+];
+
+/// Local calendar date and time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DateTime {
+    /// Date and time for Unix seconds shifted by a UTC offset
+    pub fn from_unix(secs: i64, utc_offset_minutes: i32) -> Self {
+        let secs = secs + i64::from(utc_offset_minutes) * 60;
+        let (days, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+        // Civil-from-days (proleptic Gregorian), 400-year eras from 0000-03-01
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self {
+            year,
+            month,
+            day,
+            hour: (rem / 3600) as u8,
+            minute: (rem / 60 % 60) as u8,
+            second: (rem % 60) as u8,
+        }
+    }
+
+    /// Unix seconds for this date and time read at a UTC offset
+    pub fn to_unix(&self, utc_offset_minutes: i32) -> i64 {
+        // Days-from-civil, inverse of `from_unix`
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let mp = (i64::from(self.month) + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146_097 + doe - 719_468;
+        let secs =
+            i64::from(self.hour) * 3600 + i64::from(self.minute) * 60 + i64::from(self.second);
+        days * 86_400 + secs - i64::from(utc_offset_minutes) * 60
+    }
+
+    /// Day of the week, 0 = Monday ... 6 = Sunday
+    pub fn weekday(&self) -> usize {
+        // Sakamoto's method (0 = Sunday), shifted to start on Monday
+        const T: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let y = if self.month < 3 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let sunday_based =
+            (y + y / 4 - y / 100 + y / 400 + T[self.month as usize - 1] + i32::from(self.day)) % 7;
+        ((sunday_based + 6) % 7) as usize
+    }
+}
+
+/// Time source for date and time placeholders
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Clock {
+    /// System time at a UTC offset in minutes
+    System { utc_offset_minutes: i32 },
+    /// Always the same time (tests)
+    Fixed(DateTime),
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Clock::System {
+            utc_offset_minutes: DEFAULT_UTC_OFFSET_MINUTES,
+        }
+    }
+}
+
+impl Clock {
+    pub fn now(&self) -> DateTime {
+        match *self {
+            Clock::System { utc_offset_minutes } => {
+                DateTime::from_unix(self.unix_now(), utc_offset_minutes)
+            }
+            Clock::Fixed(time) => time,
+        }
+    }
+
+    /// Current Unix time in seconds (a fixed time is read as UTC)
+    pub fn unix_now(&self) -> i64 {
+        match *self {
+            Clock::System { .. } => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs() as i64),
+            Clock::Fixed(time) => time.to_unix(0),
+        }
+    }
+}
+
+/// One piece of a date/time format
+#[derive(Debug, Clone, PartialEq)]
+enum Field {
+    Text(String),
+    Year4,
+    Year2,
+    // Numeric fields: true if zero-padded ("09" vs "9")
+    Month(bool),
+    Day(bool),
+    Hour(bool),
+    Minute(bool),
+    Second(bool),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    DateTime(Vec<Field>),
+    WeekdayVi,
+    Counter,
+    Cursor,
+}
+
+/// Parsed replacement text
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    /// Parse a replacement, None if it has a malformed placeholder
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                literal.push(c);
+                rest = &rest[2..];
+                continue;
+            }
+            rest = &rest[c.len_utf8()..];
+            // "{name}" or "{name:format}" for a known name; any other brace
+            // is literal text
+            let body = (c == '{')
+                .then(|| rest.split_once('}'))
+                .flatten()
+                .filter(|(body, _)| !body.contains('{'))
+                .filter(|(body, _)| is_placeholder_name(body.split(':').next().unwrap_or("")));
+            match body {
+                Some((body, after)) => {
+                    if !literal.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut literal)));
+                    }
+                    segments.push(parse_placeholder(body)?);
+                    rest = after;
+                }
+                None => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Text(literal));
+        }
+        if segments.iter().filter(|s| **s == Segment::Cursor).count() > 1 {
+            return None;
+        }
+        Some(Self { segments })
+    }
+
+    /// Render with the current time and this expansion's counter value
+    ///
+    /// Returns the text and the char offset of `{cursor}`, if any.
+    pub fn render(&self, now: &DateTime, counter: u32) -> (String, Option<usize>) {
+        let mut out = String::new();
+        let mut cursor = None;
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => out.push_str(text),
+                Segment::DateTime(fields) => {
+                    for field in fields {
+                        format_field(&mut out, field, now);
+                    }
+                }
+                Segment::WeekdayVi => out.push_str(WEEKDAYS_VI[now.weekday()]),
+                Segment::Counter => out.push_str(&counter.to_string()),
+                Segment::Cursor => cursor = Some(out.chars().count()),
+            }
+        }
+        (out, cursor)
+    }
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    matches!(
+        name,
+        "date" | "time" | "year" | "weekday_vi" | "counter" | "cursor"
+    )
+}
+
+fn parse_placeholder(body: &str) -> Option<Segment> {
+    let (name, format) = match body.split_once(':') {
+        Some((name, format)) => (name, Some(format)),
+        None => (body, None),
+    };
+    match (name, format) {
+        ("date", None) => parse_format("dd/MM/yyyy"),
+        ("time", None) => parse_format("HH:mm"),
+        ("date" | "time", Some(format)) => parse_format(format),
+        ("year", None) => Some(Segment::DateTime(vec![Field::Year4])),
+        ("weekday_vi", None) => Some(Segment::WeekdayVi),
+        ("counter", None) => Some(Segment::Counter),
+        ("cursor", None) => Some(Segment::Cursor),
+        _ => None,
+    }
+}
+
+/// Parse `dd/MM/yyyy`-style formats; None on unknown letter runs
+fn parse_format(format: &str) -> Option<Segment> {
+    let chars: Vec<char> = format.chars().collect();
+    let mut fields = Vec::new();
+    let mut text = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\'' {
+            let end = chars[i + 1..].iter().position(|&q| q == '\'')? + i + 1;
+            text.extend(&chars[i + 1..end]);
+            i = end + 1;
+            continue;
+        }
+        if !c.is_alphabetic() {
+            text.push(c);
+            i += 1;
+            continue;
+        }
+        let run = chars[i..].iter().take_while(|&&r| r == c).count();
+        let field = match (c, run) {
+            ('y', 4) => Field::Year4,
+            ('y', 2) => Field::Year2,
+            ('M', 1 | 2) => Field::Month(run == 2),
+            ('d', 1 | 2) => Field::Day(run == 2),
+            ('H', 1 | 2) => Field::Hour(run == 2),
+            ('m', 1 | 2) => Field::Minute(run == 2),
+            ('s', 1 | 2) => Field::Second(run == 2),
+            _ => return None,
+        };
+        if !text.is_empty() {
+            fields.push(Field::Text(std::mem::take(&mut text)));
+        }
+        fields.push(field);
+        i += run;
+    }
+    if !text.is_empty() {
+        fields.push(Field::Text(text));
+    }
+    Some(Segment::DateTime(fields))
+}
+
+fn format_field(out: &mut String, field: &Field, now: &DateTime) {
+    let (value, padded) = match *field {
+        Field::Text(ref text) => {
+            out.push_str(text);
+            return;
+        }
+        Field::Year4 => {
+            out.push_str(&format!("{:04}", now.year));
+            return;
+        }
+        Field::Year2 => (now.year.rem_euclid(100) as u8, true),
+        Field::Month(padded) => (now.month, padded),
+        Field::Day(padded) => (now.day, padded),
+        Field::Hour(padded) => (now.hour, padded),
+        Field::Minute(padded) => (now.minute, padded),
+        Field::Second(padded) => (now.second, padded),
+    };
+    if padded {
+        out.push_str(&format!("{:02}", value));
+    } else {
+        out.push_str(&value.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Monday 2024-09-02 08:05:09 (Vietnam's National Day)
+    const NOW: DateTime = DateTime {
+        year: 2024,
+        month: 9,
+        day: 2,
+        hour: 8,
+        minute: 5,
+        second: 9,
+    };
+
+    fn render(text: &str) -> String {
+        Template::parse(text).unwrap().render(&NOW, 1).0
+    }
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(render("{date}"), "02/09/2024");
+        assert_eq!(render("{date:d/M/yy}"), "2/9/24");
+        assert_eq!(render("{time}"), "08:05");
+        assert_eq!(render("{time:H'h'mm:ss}"), "8h05:09");
+        assert_eq!(render("{weekday_vi}, {year}"), "Thứ Hai, 2024");
+        assert_eq!(render("#{counter}"), "#1");
+        assert_eq!(
+            render("{date:'ngày' d 'tháng' M 'năm' yyyy}"),
+            "ngày 2 tháng 9 năm 2024"
+        );
+        assert_eq!(render("{{date}} {{}}"), "{date} {}");
+    }
+
+    #[test]
+    fn test_other_braces_are_literal() {
+        assert_eq!(render("{}"), "{}");
+        assert_eq!(render("{\"a\": 1}"), "{\"a\": 1}");
+        assert_eq!(render("fn main() {cursor}"), "fn main() ");
+        assert_eq!(render("{nope} {date"), "{nope} {date");
+        assert_eq!(render("a}b {a{year}"), "a}b {a2024");
+        assert_eq!(render("{ {time} }"), "{ 08:05 }");
+    }
+
+    #[test]
+    fn test_malformed() {
+        for text in [
+            "{date:xx}",
+            "{date:yyy}",
+            "{date:'open}",
+            "{year:yy}",
+            "{counter:2}",
+            "{cursor}{cursor}",
+        ] {
+            assert_eq!(Template::parse(text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_cursor() {
+        let template = Template::parse("<p>{cursor}</p>").unwrap();
+        assert_eq!(template.render(&NOW, 1), ("<p></p>".to_string(), Some(3)));
+        let template = Template::parse("“{cursor}”").unwrap();
+        assert_eq!(template.render(&NOW, 1), ("“”".to_string(), Some(1)));
+        assert_eq!(Template::parse("x").unwrap().render(&NOW, 1).1, None);
+    }
+
+    #[test]
+    fn test_from_unix() {
+        // 2024-09-02T01:05:09Z is 08:05:09 in Vietnam
+        assert_eq!(DateTime::from_unix(1_725_239_109, 7 * 60), NOW);
+        assert_eq!(
+            DateTime::from_unix(0, 0),
+            DateTime {
+                year: 1970,
+                month: 1,
+                day: 1,
+                hour: 0,
+                minute: 0,
+                second: 0
+            }
+        );
+        // Leap day, negative offset crossing midnight
+        let t = DateTime::from_unix(951_782_400, -60);
+        assert_eq!((t.year, t.month, t.day, t.hour), (2000, 2, 28, 23));
+    }
+
+    #[test]
+    fn test_to_unix_roundtrip() {
+        assert_eq!(NOW.to_unix(7 * 60), 1_725_239_109);
+        for secs in [0, 951_782_400, 4_102_444_799, -86_401] {
+            assert_eq!(DateTime::from_unix(secs, -60).to_unix(-60), secs);
+        }
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(NOW.weekday(), 0);
+        let sunday = DateTime { day: 8, ..NOW };
+        assert_eq!(WEEKDAYS_VI[sunday.weekday()], "Chủ Nhật");
+        let new_year = DateTime::from_unix(0, 0);
+        assert_eq!(WEEKDAYS_VI[new_year.weekday()], "Thứ Năm");
+    }
+}
@@ -0,0 +1,228 @@
+//! Trigger Trie - case-insensitive prefix tree of shortcut triggers
+//!
+//! Lookups walk one node per character, so their cost depends on the
+//! trigger length, not on how many shortcuts exist. A `TriggerCursor`
+//! follows the trie one keystroke at a time: frontends and the engine can
+//! keep it across keys and stop checking once no trigger starts with what
+//! was typed.
+//!
+//! Triggers are stored lowercase; characters are lowercased as they are
+//! stepped, so "VN" and "Vn" reach the node of "vn".
+
+/// Prefix tree of lowercase triggers
+#[derive(Debug, Clone, Default)]
+pub struct TriggerTrie {
+    root: Node,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Children sorted by char (binary search; most nodes have few)
+    children: Vec<(char, Node)>,
+    /// Trigger ending at this node
+    trigger: Option<String>,
+}
+
+impl Node {
+    fn child(&self, c: char) -> Option<&Node> {
+        self.children
+            .binary_search_by_key(&c, |(k, _)| *k)
+            .ok()
+            .map(|i| &self.children[i].1)
+    }
+
+    fn child_or_insert(&mut self, c: char) -> &mut Node {
+        let i = match self.children.binary_search_by_key(&c, |(k, _)| *k) {
+            Ok(i) => i,
+            Err(i) => {
+                self.children.insert(i, (c, Node::default()));
+                i
+            }
+        };
+        &mut self.children[i].1
+    }
+
+    /// Remove `chars` below this node, pruning empty branches.
+    /// Returns whether a trigger was removed.
+    fn remove(&mut self, chars: &[char]) -> bool {
+        let Some((&c, rest)) = chars.split_first() else {
+            return self.trigger.take().is_some();
+        };
+        let Ok(i) = self.children.binary_search_by_key(&c, |(k, _)| *k) else {
+            return false;
+        };
+        let removed = self.children[i].1.remove(rest);
+        let child = &self.children[i].1;
+        if child.trigger.is_none() && child.children.is_empty() {
+            self.children.remove(i);
+        }
+        removed
+    }
+
+    /// Triggers at and below this node, in char order
+    fn collect<'a>(&'a self, out: &mut Vec<&'a str>) {
+        if let Some(ref trigger) = self.trigger {
+            out.push(trigger);
+        }
+        for (_, child) in &self.children {
+            child.collect(out);
+        }
+    }
+}
+
+/// Position in the trie after some typed characters
+#[derive(Debug, Clone, Copy)]
+pub struct TriggerCursor<'a> {
+    node: &'a Node,
+}
+
+impl<'a> TriggerCursor<'a> {
+    /// Follow one typed character (case-insensitive).
+    /// None if no trigger continues with it.
+    pub fn step(self, c: char) -> Option<Self> {
+        let mut node = self.node;
+        for lower in c.to_lowercase() {
+            node = node.child(lower)?;
+        }
+        Some(Self { node })
+    }
+
+    /// Follow several characters
+    pub fn step_str(self, s: &str) -> Option<Self> {
+        s.chars().try_fold(self, Self::step)
+    }
+
+    /// Trigger typed so far, if it is a complete trigger
+    pub fn trigger(&self) -> Option<&'a str> {
+        self.node.trigger.as_deref()
+    }
+
+    /// Whether a longer trigger starts with what was typed so far
+    pub fn has_longer(&self) -> bool {
+        !self.node.children.is_empty()
+    }
+}
+
+impl TriggerTrie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a trigger (lowercased). Returns false if it was already there.
+    pub fn insert(&mut self, trigger: &str) -> bool {
+        let trigger = trigger.to_lowercase();
+        let mut node = &mut self.root;
+        for c in trigger.chars() {
+            node = node.child_or_insert(c);
+        }
+        if node.trigger.is_some() {
+            return false;
+        }
+        node.trigger = Some(trigger);
+        self.len += 1;
+        true
+    }
+
+    /// Remove a trigger (case-insensitive). Returns false if it was not there.
+    pub fn remove(&mut self, trigger: &str) -> bool {
+        let chars: Vec<char> = trigger.to_lowercase().chars().collect();
+        let removed = self.root.remove(&chars);
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Start of incremental matching (nothing typed yet)
+    pub fn cursor(&self) -> TriggerCursor<'_> {
+        TriggerCursor { node: &self.root }
+    }
+
+    /// Stored trigger equal to `text` (case-insensitive)
+    pub fn get(&self, text: &str) -> Option<&str> {
+        self.cursor().step_str(text)?.trigger()
+    }
+
+    /// Triggers starting with `prefix` (case-insensitive), in char order
+    pub fn with_prefix(&self, prefix: &str) -> Vec<&str> {
+        let mut out = Vec::new();
+        if let Some(cursor) = self.cursor().step_str(prefix) {
+            cursor.node.collect(&mut out);
+        }
+        out
+    }
+
+    /// Number of triggers
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trie(triggers: &[&str]) -> TriggerTrie {
+        let mut t = TriggerTrie::new();
+        for trigger in triggers {
+            t.insert(trigger);
+        }
+        t
+    }
+
+    #[test]
+    fn test_exact_case_insensitive() {
+        let t = trie(&["vn", "vnd", "->", "đc"]);
+        assert_eq!(t.get("VN"), Some("vn"));
+        assert_eq!(t.get("Vnd"), Some("vnd"));
+        assert_eq!(t.get("->"), Some("->"));
+        assert_eq!(t.get("ĐC"), Some("đc"));
+        assert_eq!(t.get("v"), None);
+        assert_eq!(t.get("vnx"), None);
+        assert_eq!(t.len(), 4);
+    }
+
+    #[test]
+    fn test_incremental_cursor() {
+        let t = trie(&["vn", "vnd"]);
+        let c = t.cursor().step('v').unwrap();
+        assert_eq!(c.trigger(), None);
+        let c = c.step('N').unwrap();
+        assert_eq!(c.trigger(), Some("vn"));
+        assert!(c.has_longer());
+        let c = c.step('d').unwrap();
+        assert_eq!(c.trigger(), Some("vnd"));
+        assert!(!c.has_longer());
+        assert!(c.step('x').is_none());
+    }
+
+    #[test]
+    fn test_prefix_query() {
+        let t = trie(&["hn", "hcm", "hnx", "vn"]);
+        assert_eq!(t.with_prefix("H"), ["hcm", "hn", "hnx"]);
+        assert_eq!(t.with_prefix("hn"), ["hn", "hnx"]);
+        assert!(t.with_prefix("x").is_empty());
+        assert_eq!(t.with_prefix("").len(), 4);
+    }
+
+    #[test]
+    fn test_remove_prunes() {
+        let mut t = trie(&["vn", "vnd"]);
+        assert!(!t.insert("VN"));
+        assert!(t.remove("vnd"));
+        assert!(!t.remove("vnd"));
+        assert!(!t.cursor().step_str("vn").unwrap().has_longer());
+        assert!(t.remove("Vn"));
+        assert!(t.is_empty());
+        assert!(!t.cursor().has_longer());
+    }
+}
@@ -0,0 +1,207 @@
+//! User Dictionary - runtime additions to English auto-restore
+//!
+//! The bundled English word list (`data::english_dict`) is fixed at build
+//! time. Users extend it with their own jargon and brand names ("kubectl",
+//! "terraform", "nestjs") and remove words that cause false restores.
+//! Entries here take priority over the bundled list.
+//!
+//! File format (one entry per line, case-insensitive):
+//!
+//! ```text
+//! # comment
+//! terraform
+//! -mason
+//! ```
+//!
+//! A leading `-` removes a bundled word.
+
+use crate::data::english_dict;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// User additions and removals for the English dictionary
+#[derive(Debug, Clone, Default)]
+pub struct UserDictionary {
+    /// Words treated as English (lowercase)
+    added: BTreeSet<String>,
+    /// Bundled words no longer treated as English (lowercase)
+    removed: BTreeSet<String>,
+}
+
+impl UserDictionary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat `word` as English. Returns false if the word is empty or not ASCII.
+    pub fn add(&mut self, word: &str) -> bool {
+        let Some(word) = normalize(word) else {
+            return false;
+        };
+        self.removed.remove(&word);
+        self.added.insert(word);
+        true
+    }
+
+    /// Stop treating `word` as English (user or bundled word)
+    pub fn remove(&mut self, word: &str) -> bool {
+        let Some(word) = normalize(word) else {
+            return false;
+        };
+        self.added.remove(&word);
+        self.removed.insert(word);
+        true
+    }
+
+    /// Drop any user entry for `word`, going back to the bundled list
+    pub fn reset(&mut self, word: &str) {
+        if let Some(word) = normalize(word) {
+            self.added.remove(&word);
+            self.removed.remove(&word);
+        }
+    }
+
+    /// Remove all user entries
+    pub fn clear(&mut self) {
+        self.added.clear();
+        self.removed.clear();
+    }
+
+    /// User entry for a word: Some(true) added, Some(false) removed, None if unset
+    pub fn lookup(&self, word: &str) -> Option<bool> {
+        let word = word.to_lowercase();
+        if self.added.contains(&word) {
+            Some(true)
+        } else if self.removed.contains(&word) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Check if a word is English: user entries first, then the bundled list
+    pub fn is_english_word(&self, word: &str) -> bool {
+        self.lookup(word)
+            .unwrap_or_else(|| english_dict::is_english_word(word))
+    }
+
+    /// Added words, sorted
+    pub fn added(&self) -> impl Iterator<Item = &str> {
+        self.added.iter().map(String::as_str)
+    }
+
+    /// Removed words, sorted
+    pub fn removed(&self) -> impl Iterator<Item = &str> {
+        self.removed.iter().map(String::as_str)
+    }
+
+    /// Parse entries from text (see module docs), adding to current ones
+    ///
+    /// Returns the number of entries read.
+    pub fn load_str(&mut self, text: &str) -> usize {
+        let mut count = 0;
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let ok = match line.strip_prefix('-') {
+                Some(word) => self.remove(word),
+                None => self.add(line),
+            };
+            if ok {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Serialize entries (see module docs)
+    pub fn to_text(&self) -> String {
+        let mut lines: Vec<String> = self.added.iter().cloned().collect();
+        lines.extend(self.removed.iter().map(|w| format!("-{}", w)));
+        let mut text = lines.join("\n");
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Load entries from a file, adding to current ones
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> io::Result<usize> {
+        let text = fs::read_to_string(path)?;
+        Ok(self.load_str(&text))
+    }
+
+    /// Write entries to a file
+    pub fn save_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+}
+
+/// Lowercase ASCII word, None if empty or not a single ASCII word
+fn normalize(word: &str) -> Option<String> {
+    let word = word.trim();
+    let valid = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '\'' || c == '-');
+    valid.then(|| word.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_remove_priority() {
+        let mut d = UserDictionary::new();
+        assert!(!d.is_english_word("kubectl"));
+        assert!(d.add("Kubectl"));
+        assert!(d.is_english_word("KUBECTL"));
+
+        assert!(d.is_english_word("mason"));
+        d.remove("mason");
+        assert!(!d.is_english_word("mason"));
+        d.reset("mason");
+        assert!(d.is_english_word("mason"));
+    }
+
+    #[test]
+    fn test_rejects_non_words() {
+        let mut d = UserDictionary::new();
+        assert!(!d.add(""));
+        assert!(!d.add("hai từ"));
+        assert!(!d.add("việt"));
+        assert_eq!(d.added().count(), 0);
+    }
+
+    #[test]
+    fn test_text_roundtrip() {
+        let mut d = UserDictionary::new();
+        let n = d.load_str("# jargon\nterraform\n\n  NestJS \n-mason\nbad word\n");
+        assert_eq!(n, 3);
+        assert_eq!(d.to_text(), "nestjs\nterraform\n-mason\n");
+
+        let mut e = UserDictionary::new();
+        e.load_str(&d.to_text());
+        assert_eq!(e.to_text(), d.to_text());
+        e.clear();
+        assert_eq!(e.to_text(), "");
+    }
+
+    #[test]
+    fn test_file_roundtrip() {
+        let path = std::env::temp_dir().join(format!("gonhanh_dict_{}.txt", std::process::id()));
+        let mut d = UserDictionary::new();
+        d.add("kubectl");
+        d.save_file(&path).unwrap();
+
+        let mut e = UserDictionary::new();
+        assert_eq!(e.load_file(&path).unwrap(), 1);
+        assert!(e.is_english_word("kubectl"));
+        fs::remove_file(&path).unwrap();
+        assert!(e.load_file(&path).is_err());
+    }
+}
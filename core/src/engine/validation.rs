@@ -4,19 +4,26 @@
 //! Uses valid patterns from docs/vietnamese-language-system.md Section 7.6.1
 
 use super::syllable::{parse, Syllable};
-use crate::data::chars::tone;
+use crate::data::chars::{self, mark, tone};
 use crate::data::constants;
 use crate::data::keys;
 
 /// Validation result
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Discriminants are stable: they are the codes returned over FFI.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
 pub enum ValidationResult {
-    Valid,
-    InvalidInitial,
-    InvalidFinal,
-    InvalidSpelling,
-    InvalidVowelPattern,
-    NoVowel,
+    Valid = 0,
+    InvalidInitial = 1,
+    InvalidFinal = 2,
+    InvalidSpelling = 3,
+    InvalidVowelPattern = 4,
+    NoVowel = 5,
+    /// Character is not a Vietnamese letter (digit, symbol, foreign letter)
+    InvalidChar = 6,
+    /// Tone mark not allowed (two marks, or stop final without sắc/nặng)
+    InvalidTone = 7,
 }
 
 impl ValidationResult {
@@ -489,6 +496,204 @@ pub fn is_foreign_word_pattern(
     false
 }
 
+// =============================================================================
+// UNICODE TEXT VALIDATION
+// =============================================================================
+
+/// Validation failure for Unicode text, with the offending span
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyllableError {
+    /// Failing rule
+    pub result: ValidationResult,
+    /// Human-readable reason (e.g. "c before e/i/y")
+    pub reason: &'static str,
+    /// Byte offset where the offending part starts
+    pub start: usize,
+    /// Byte offset where the offending part ends (exclusive)
+    pub end: usize,
+}
+
+/// Stop finals (c, ch, p, t, k) only combine with sắc or nặng
+const STOP_FINALS: &[u16] = &[keys::C, keys::P, keys::T, keys::K];
+
+/// Long "oo" before ng/c in loanwords: boong, xoong, soóc
+///
+/// Never typed through the engine (Telex "oo" is ô), so not in the whitelist.
+fn is_long_o(snap: &BufferSnapshot, syllable: &Syllable) -> bool {
+    let vowel: Vec<u16> = syllable.vowel.iter().map(|&i| snap.keys[i]).collect();
+    let final_c: Vec<u16> = syllable.final_c.iter().map(|&i| snap.keys[i]).collect();
+    vowel == [keys::O, keys::O]
+        && syllable.vowel.iter().all(|&i| snap.tones[i] == tone::NONE)
+        && (final_c == [keys::N, keys::G] || final_c == [keys::C])
+}
+
+/// Validate a complete Vietnamese syllable written in Unicode ("nghiêng", "Đắk")
+///
+/// Runs the same rules as the engine plus checks that only make sense for
+/// finished text: iê/uê/yê must carry the circumflex, at most one tone mark,
+/// and stop finals (c, ch, p, t) take only sắc or nặng.
+/// Case is ignored. Spans are byte offsets into `s`.
+pub fn validate_syllable(s: &str) -> Result<(), SyllableError> {
+    let offsets: Vec<(usize, char)> = s.char_indices().collect();
+    let span = |indices: &[usize], result: ValidationResult, reason: &'static str| {
+        let first = indices.iter().copied().min().unwrap_or(0);
+        let last = indices.iter().copied().max().unwrap_or(0);
+        let (start, c) = offsets.get(first).copied().unwrap_or((0, '\0'));
+        let end = offsets
+            .get(last)
+            .map_or(start + c.len_utf8(), |&(i, c)| i + c.len_utf8());
+        SyllableError {
+            result,
+            reason,
+            start,
+            end: if offsets.is_empty() { 0 } else { end },
+        }
+    };
+
+    if offsets.is_empty() {
+        return Err(span(&[], ValidationResult::NoVowel, "empty syllable"));
+    }
+
+    let mut parsed = Vec::with_capacity(offsets.len());
+    for (i, &(_, c)) in offsets.iter().enumerate() {
+        match chars::parse_char(c) {
+            Some(p) => parsed.push(p),
+            None => {
+                return Err(span(
+                    &[i],
+                    ValidationResult::InvalidChar,
+                    "not a Vietnamese letter",
+                ))
+            }
+        }
+    }
+
+    let snap = BufferSnapshot {
+        keys: parsed.iter().map(|p| p.key).collect(),
+        tones: parsed.iter().map(|p| p.tone).collect(),
+        has_tone_info: true,
+        allow_foreign_consonants: false,
+    };
+    let syllable = parse(&snap.keys);
+    let all: Vec<usize> = (0..offsets.len()).collect();
+    let nucleus: Vec<usize> = syllable
+        .glide
+        .iter()
+        .chain(&syllable.vowel)
+        .copied()
+        .collect();
+
+    for rule in RULES {
+        let Some(result) = rule(&snap, &syllable) else {
+            continue;
+        };
+        return Err(match result {
+            ValidationResult::InvalidInitial => {
+                span(&syllable.initial, result, "invalid initial consonant")
+            }
+            ValidationResult::InvalidFinal => {
+                // Chars the parser could not place (e.g. consonant between vowels)
+                let unparsed: Vec<usize> = all
+                    .iter()
+                    .copied()
+                    .filter(|i| {
+                        !syllable.initial.contains(i)
+                            && syllable.glide != Some(*i)
+                            && !syllable.vowel.contains(i)
+                            && !syllable.final_c.contains(i)
+                    })
+                    .collect();
+                if unparsed.is_empty() {
+                    span(&syllable.final_c, result, "invalid final consonant")
+                } else {
+                    span(&unparsed, result, "unexpected letters")
+                }
+            }
+            ValidationResult::InvalidSpelling => {
+                let initial: Vec<u16> = syllable.initial.iter().map(|&i| snap.keys[i]).collect();
+                let reason = constants::SPELLING_RULES
+                    .iter()
+                    .find(|(consonant, _, _)| *consonant == initial.as_slice())
+                    .map_or("spelling rule", |&(_, _, msg)| msg);
+                let mut indices = syllable.initial.clone();
+                indices.push(nucleus[0]);
+                span(&indices, result, reason)
+            }
+            ValidationResult::InvalidVowelPattern => {
+                if is_long_o(&snap, &syllable) {
+                    continue;
+                }
+                span(&nucleus, result, "invalid vowel combination")
+            }
+            _ => span(&all, result, "no vowel"),
+        });
+    }
+
+    // iê, uê, yê are never written without the circumflex in finished text
+    if syllable.vowel.len() >= 2 {
+        let (v1, v2) = (syllable.vowel[0], syllable.vowel[1]);
+        let pair = [snap.keys[v1], snap.keys[v2]];
+        if constants::V2_CIRCUMFLEX_REQUIRED.contains(&pair) && snap.tones[v2] != tone::CIRCUMFLEX {
+            return Err(span(
+                &[v1, v2],
+                ValidationResult::InvalidVowelPattern,
+                "iê/uê/yê requires circumflex",
+            ));
+        }
+    }
+
+    let marked: Vec<usize> = all
+        .iter()
+        .copied()
+        .filter(|&i| parsed[i].mark != mark::NONE)
+        .collect();
+    if marked.len() > 1 {
+        return Err(span(
+            &marked[1..],
+            ValidationResult::InvalidTone,
+            "more than one tone mark",
+        ));
+    }
+
+    if let Some(&f) = syllable.final_c.first() {
+        if STOP_FINALS.contains(&snap.keys[f]) {
+            let tone_mark = marked.first().map_or(mark::NONE, |&i| parsed[i].mark);
+            if tone_mark != mark::SAC && tone_mark != mark::NANG {
+                let at = if marked.is_empty() {
+                    &syllable.final_c
+                } else {
+                    &marked
+                };
+                return Err(span(
+                    at,
+                    ValidationResult::InvalidTone,
+                    "stop final (c, ch, p, t) needs sắc or nặng",
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate a word of one or more syllables separated by spaces or hyphens
+///
+/// Returns the first failing syllable; spans are byte offsets into `s`.
+pub fn validate_word(s: &str) -> Result<(), SyllableError> {
+    let mut start = 0;
+    for part in s.split([' ', '-']) {
+        if !part.is_empty() {
+            validate_syllable(part).map_err(|mut e| {
+                e.start += start;
+                e.end += start;
+                e
+            })?;
+        }
+        start += part.len() + 1;
+    }
+    Ok(())
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -634,4 +839,72 @@ mod tests {
             "'ăi' should be invalid"
         );
     }
+
+    fn check(s: &str) -> Option<(ValidationResult, &str)> {
+        validate_syllable(s)
+            .err()
+            .map(|e| (e.result, &s[e.start..e.end]))
+    }
+
+    #[test]
+    fn test_validate_syllable_valid() {
+        for s in [
+            "nghiêng",
+            "Việt",
+            "người",
+            "Đắk",
+            "khuya",
+            "quốc",
+            "gì",
+            "hoà",
+            "THƯỜNG",
+            "ạ",
+        ] {
+            assert_eq!(validate_syllable(s), Ok(()), "'{}' should be valid", s);
+        }
+    }
+
+    #[test]
+    fn test_validate_syllable_reports_span() {
+        use ValidationResult::*;
+        assert_eq!(check("clàu"), Some((InvalidInitial, "cl")));
+        assert_eq!(check("kà"), Some((InvalidSpelling, "kà")));
+        assert_eq!(check("ghé"), None);
+        assert_eq!(check("nghà"), Some((InvalidSpelling, "nghà")));
+        assert_eq!(
+            validate_syllable("ce").unwrap_err().reason,
+            "c before e/i/y"
+        );
+        assert_eq!(check("bás"), Some((InvalidFinal, "s")));
+        assert_eq!(check("bcd"), Some((NoVowel, "bcd")));
+        assert_eq!(check("tiêu2"), Some((InvalidChar, "2")));
+        assert_eq!(check("bèa"), Some((InvalidVowelPattern, "èa")));
+        assert_eq!(check(""), Some((NoVowel, "")));
+    }
+
+    #[test]
+    fn test_validate_syllable_tones() {
+        use ValidationResult::*;
+        // Two tone marks
+        assert_eq!(check("bóà"), Some((InvalidTone, "à")));
+        // Stop finals only take sắc or nặng
+        assert_eq!(check("hàt"), Some((InvalidTone, "à")));
+        assert_eq!(check("mat"), Some((InvalidTone, "t")));
+        assert_eq!(check("sách"), None);
+        assert_eq!(check("học"), None);
+        // iê needs circumflex in finished text
+        assert_eq!(check("tiep"), Some((InvalidVowelPattern, "ie")));
+        assert_eq!(check("tiếp"), None);
+    }
+
+    #[test]
+    fn test_validate_word_offsets() {
+        assert_eq!(validate_word("Việt Nam"), Ok(()));
+        assert_eq!(validate_word("cà-phê"), Ok(()));
+        assert_eq!(validate_word("xin chào kem"), Ok(()));
+        let s = "tiếng Vieät";
+        let e = validate_word(s).unwrap_err();
+        assert_eq!(&s[e.start..e.end], "ä");
+        assert_eq!(e.result, ValidationResult::InvalidChar);
+    }
 }
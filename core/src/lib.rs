@@ -21,11 +21,20 @@
 //! ```
 
 pub mod data;
+pub mod document;
 pub mod engine;
 pub mod input;
+pub mod spellcheck;
+pub mod suggest;
 pub mod updater;
 pub mod utils;
 
+use data::language_pack::LanguagePack;
+use data::shortcut_pack::ShortcutPack;
+use engine::identifier::{IdentifierKind, RawPrefix};
+use engine::shortcut::format::{escape_line, Conflict, ImportOptions, ShortcutFormat};
+use engine::shortcut::template::Clock;
+use engine::shortcut::{CaseMode, InputMethod, Shortcut, ShortcutUsage, TriggerCondition};
 use engine::{Engine, Result};
 use std::sync::Mutex;
 
@@ -68,8 +77,16 @@ pub extern "C" fn ime_init() {
 /// # Result struct
 /// * `action`: 0=None (pass through), 1=Send (replace text), 2=Restore
 /// * `backspace`: number of characters to delete
-/// * `chars`: UTF-32 codepoints to insert
+/// * `chars`: UTF-32 codepoints to insert; with capability 0x02 (see
+///   `ime_frontend_caps`) a result that is exactly `\n` or `\t` should be
+///   sent as an Enter or Tab key press
 /// * `count`: number of valid chars
+/// * `flags`: 0x01 = key consumed (don't pass it through),
+///   0x02 = candidate list changed (re-query with `ime_candidate_list`),
+///   0x04 = more output follows (fetch it with `ime_next_output`, only
+///   with capability 0x02)
+/// * `caret_left`: chars to move the caret left after applying the result
+///   (only with capability 0x01)
 ///
 /// # Note
 /// For VNI mode with Shift+number keys (to type @, #, $ etc.),
@@ -219,6 +236,123 @@ pub extern "C" fn ime_english_auto_restore(enabled: bool) {
     }
 }
 
+/// Enable/disable a language pack for auto-restore.
+///
+/// Packs: 0 = English (default on), 1 = French, 2 = German, 3 = Spanish,
+/// 4 = Latin (incl. scientific names). Enabled packs' words are restored
+/// like English ones (e.g., "adresse" instead of "adrese") when English
+/// auto-restore is on; words that came out as Vietnamese words stay.
+/// Unknown ids are ignored. No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_language_pack(pack: u8, enabled: bool) {
+    let Some(pack) = LanguagePack::from_id(pack) else {
+        return;
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_language_pack(pack, enabled);
+    }
+}
+
+/// Enable/disable raw typing for an identifier kind.
+///
+/// Kinds: 0 = camelCase, 1 = snake_case, 2 = paths, 3 = URLs, 4 = emails.
+/// Once the current token (text since the last whitespace) has an enabled
+/// shape, the rest of it is typed without Vietnamese transforms
+/// (e.g., "getUserName", "user_id", "/usr/local", "foo@bar.com").
+/// All kinds are disabled by default. Unknown ids are ignored.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_identifier_detection(kind: u8, enabled: bool) {
+    let Some(kind) = IdentifierKind::from_id(kind) else {
+        return;
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_identifier_detection(kind, enabled);
+    }
+}
+
+/// Enable/disable a raw-mode prefix.
+///
+/// Prefixes: 0 = `@` (mentions), 1 = `#` (hashtags/channels),
+/// 2 = `:` (emoji names), 3 = `/` (commands). A token starting with an
+/// enabled prefix is typed without Vietnamese transforms until the next
+/// whitespace (e.g., "@thanhs" stays "@thanhs"). Shortcuts whose trigger
+/// starts with the prefix ("#fne") still expand.
+/// All prefixes are disabled by default. Unknown ids are ignored.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_raw_prefix(prefix: u8, enabled: bool) {
+    let Some(prefix) = RawPrefix::from_id(prefix) else {
+        return;
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_raw_prefix(prefix, enabled);
+    }
+}
+
+/// Enable/disable emoji shortcodes.
+///
+/// When enabled, typing the closing colon of a bundled `:name:` shortcode
+/// replaces it with the emoji (":smile:" → 😄, ":tim:" → ❤️). Works with
+/// the IME disabled too. The name is typed without Vietnamese transforms.
+/// Disabled by default.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_emoji_shortcodes(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_emoji_shortcodes(enabled);
+    }
+}
+
+/// Enable/disable the n-gram English classifier for auto-restore.
+///
+/// When `enabled` is true and English auto-restore is on, words the rules
+/// keep as Vietnamese are restored if their keystrokes look English
+/// (e.g., "defend" → "defend" instead of "đền").
+/// When `enabled` is false (default), only the rules decide.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_english_classifier(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_english_classifier(enabled);
+    }
+}
+
+/// Set the English classifier threshold (default 1.0).
+///
+/// Mean log-likelihood ratio per trigram above which a word is restored.
+/// Higher values restore fewer words. No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_english_classifier_threshold(threshold: f32) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_english_classifier_threshold(threshold);
+    }
+}
+
+/// Declare what the frontend can apply beyond backspace + text.
+///
+/// `caps` is a bit mask (default 0):
+/// * 0x01: moves the caret by the result's `caret_left` (shortcut
+///   `{cursor}`); without it the caret stays after the expansion
+/// * 0x02: fetches flag 0x04 parts with `ime_next_output` and posts lone
+///   `\n`/`\t` results as Enter/Tab; without it a shortcut expansion comes
+///   in one result, line breaks included, cut at 255 chars
+///
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_frontend_caps(caps: u8) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_frontend_caps(caps);
+    }
+}
+
 /// Enable/disable auto-capitalize after sentence-ending punctuation.
 ///
 /// When `enabled` is true, automatically capitalizes the first letter
@@ -247,6 +381,21 @@ pub extern "C" fn ime_allow_foreign_consonants(enabled: bool) {
     }
 }
 
+/// Enable/disable Quick Telex consonant shortcuts (Telex only).
+///
+/// When `enabled` is true, doubled consonants expand to digraphs:
+/// cc→ch, gg→gi, kk→kh, nn→ng, pp→ph, qq→qu, tt→th.
+/// A third press reverts to the literal pair (ccc → cc).
+/// When `enabled` is false (default), doubled consonants are typed as-is.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_quick_telex(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_quick_telex(enabled);
+    }
+}
+
 /// Clear the input buffer.
 ///
 /// Call on word boundaries (space, punctuation).
@@ -324,42 +473,359 @@ pub unsafe extern "C" fn ime_free(r: *mut Result) {
 
 /// Add a shortcut to the engine.
 ///
+/// The replacement may contain template placeholders evaluated when the
+/// shortcut fires: `{date}`, `{date:dd/MM/yyyy}`, `{time}`, `{weekday_vi}`,
+/// `{year}`, `{counter}` (`{{` / `}}` for literal braces). `{cursor}` marks
+/// where the caret lands: the result's `caret_left` tells the frontend how
+/// many chars to move left after applying it.
+///
 /// # Arguments
 /// * `trigger` - C string for trigger (e.g., "vn")
 /// * `replacement` - C string for replacement (e.g., "Việt Nam")
 ///
+/// # Returns
+/// false if the replacement is a malformed template or a string is invalid.
+///
 /// # Safety
 /// Both pointers must be valid null-terminated UTF-8 strings.
 #[no_mangle]
 pub unsafe extern "C" fn ime_add_shortcut(
     trigger: *const std::os::raw::c_char,
     replacement: *const std::os::raw::c_char,
-) {
+) -> bool {
     if trigger.is_null() || replacement.is_null() {
-        return;
+        return false;
     }
 
     let trigger_str = match std::ffi::CStr::from_ptr(trigger).to_str() {
         Ok(s) => s,
-        Err(_) => return,
+        Err(_) => return false,
     };
     let replacement_str = match std::ffi::CStr::from_ptr(replacement).to_str() {
         Ok(s) => s,
-        Err(_) => return,
+        Err(_) => return false,
+    };
+
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => {
+            // Symbol triggers ("->") fire immediately, abbreviations ("vn") on word boundary
+            let shortcut = Shortcut::auto(trigger_str, replacement_str);
+            e.shortcuts_mut().add(shortcut)
+        }
+        None => false,
+    }
+}
+
+/// Add a shortcut with explicit options (replaces one with the same trigger).
+///
+/// # Arguments
+/// * `trigger`, `replacement` - C strings, as for `ime_add_shortcut`
+/// * `condition` - 0 = expand immediately, 1 = on word boundary
+/// * `case_mode` - 0 = keep replacement as written, 1 = match the trigger's case
+/// * `method` - 0 = all input methods, 1 = Telex only, 2 = VNI only
+///
+/// # Returns
+/// false for unknown option ids, a malformed template, an invalid string or
+/// if the engine is not initialized.
+///
+/// # Safety
+/// Both pointers must be valid null-terminated UTF-8 strings or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_add_shortcut_ext(
+    trigger: *const std::os::raw::c_char,
+    replacement: *const std::os::raw::c_char,
+    condition: u8,
+    case_mode: u8,
+    method: u8,
+) -> bool {
+    let (Some(condition), Some(case_mode), Some(method)) = (
+        TriggerCondition::from_id(condition),
+        CaseMode::from_id(case_mode),
+        InputMethod::from_id(method),
+    ) else {
+        return false;
+    };
+    if trigger.is_null() || replacement.is_null() {
+        return false;
+    }
+    let (Ok(trigger), Ok(replacement)) = (
+        std::ffi::CStr::from_ptr(trigger).to_str(),
+        std::ffi::CStr::from_ptr(replacement).to_str(),
+    ) else {
+        return false;
+    };
+    let mut shortcut = Shortcut::new(trigger, replacement).for_method(method);
+    shortcut.condition = condition;
+    shortcut.case_mode = case_mode;
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e.shortcuts_mut().add(shortcut),
+        None => false,
+    }
+}
+
+/// Add a shortcut whose trigger is a pattern, e.g. `(\d+)k` → `$1.000 đ`
+/// (replaces one with the same trigger). See `engine::shortcut::pattern`
+/// for the syntax.
+///
+/// # Arguments
+/// * `pattern`, `replacement` - C strings; `$1`..`$9` in the replacement
+///   insert captured text
+/// * `condition`, `case_mode`, `method` - as for `ime_add_shortcut_ext`
+///
+/// # Returns
+/// false for a malformed pattern, a replacement naming a missing group,
+/// unknown option ids, a malformed template, an invalid string or if the
+/// engine is not initialized.
+///
+/// # Safety
+/// Both pointers must be valid null-terminated UTF-8 strings or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_add_pattern_shortcut(
+    pattern: *const std::os::raw::c_char,
+    replacement: *const std::os::raw::c_char,
+    condition: u8,
+    case_mode: u8,
+    method: u8,
+) -> bool {
+    let (Some(condition), Some(case_mode), Some(method)) = (
+        TriggerCondition::from_id(condition),
+        CaseMode::from_id(case_mode),
+        InputMethod::from_id(method),
+    ) else {
+        return false;
+    };
+    if pattern.is_null() || replacement.is_null() {
+        return false;
+    }
+    let (Ok(pattern), Ok(replacement)) = (
+        std::ffi::CStr::from_ptr(pattern).to_str(),
+        std::ffi::CStr::from_ptr(replacement).to_str(),
+    ) else {
+        return false;
+    };
+    let Some(shortcut) = Shortcut::pattern(pattern, replacement) else {
+        return false;
+    };
+    let mut shortcut = shortcut.for_method(method);
+    shortcut.condition = condition;
+    shortcut.case_mode = case_mode;
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e.shortcuts_mut().add(shortcut),
+        None => false,
+    }
+}
+
+/// One shortcut as a tab-separated line for the listing FFI
+fn shortcut_line(s: &Shortcut, usage: ShortcutUsage) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        escape_line(&s.trigger),
+        escape_line(&s.replacement),
+        s.condition as u8,
+        s.case_mode as u8,
+        s.input_method as u8,
+        u8::from(s.enabled),
+        usage.count,
+        usage.last_used,
+        s.pack_entry.unwrap_or_default(),
+        u8::from(s.pattern.is_some())
+    )
+}
+
+/// List all shortcuts in effect (user and bundled), sorted by trigger.
+///
+/// One line per shortcut, fields separated by tabs:
+/// `trigger` and `replacement` (line breaks, tabs and backslashes escaped
+/// as `\n`, `\t`, `\\`), condition id, case mode id, method id (ids as for
+/// `ime_add_shortcut_ext`), enabled 0|1, use count, last use in Unix seconds
+/// (0 = never), the bundled pack entry id (empty for user shortcuts), and
+/// pattern 0|1 (1 = `trigger` is a pattern, see `ime_add_pattern_shortcut`).
+/// Use counts start at 0 when the engine starts.
+///
+/// # Returns
+/// Number of codepoints written to `out` as UTF-32.
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_shortcuts(out: *mut u32, max_len: i64) -> i64 {
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let table = e.shortcuts();
+            let lines: Vec<String> = table
+                .entries()
+                .into_iter()
+                .map(|s| shortcut_line(s, table.usage(&s.trigger)))
+                .collect();
+            write_lines(lines.iter().map(String::as_str), out, max_len)
+        }
+        None => 0,
+    }
+}
+
+/// Read back one shortcut (case-insensitive trigger), in the `ime_shortcuts` line format.
+///
+/// # Returns
+/// Number of codepoints written to `out` as UTF-32 (0 if there is no such shortcut).
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `trigger` must be a valid null-terminated UTF-8 string or null; `out`
+/// must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_get_shortcut(
+    trigger: *const std::os::raw::c_char,
+    out: *mut u32,
+    max_len: i64,
+) -> i64 {
+    if trigger.is_null() || out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let Ok(trigger) = std::ffi::CStr::from_ptr(trigger).to_str() else {
+        return 0;
+    };
+    let guard = lock_engine();
+    let Some(ref e) = *guard else {
+        return 0;
+    };
+    let table = e.shortcuts();
+    match table.get(trigger) {
+        Some(s) => write_lines(
+            [shortcut_line(s, table.usage(trigger)).as_str()],
+            out,
+            max_len,
+        ),
+        None => 0,
+    }
+}
+
+/// Enable or disable a shortcut by trigger without removing it.
+///
+/// Works for bundled pack entries too (stored as an edit of the entry).
+///
+/// # Returns
+/// false if no shortcut has this trigger or engine not initialized.
+///
+/// # Safety
+/// `trigger` must be a valid null-terminated UTF-8 string or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_enable_shortcut(
+    trigger: *const std::os::raw::c_char,
+    enabled: bool,
+) -> bool {
+    if trigger.is_null() {
+        return false;
+    }
+    let Ok(trigger) = std::ffi::CStr::from_ptr(trigger).to_str() else {
+        return false;
+    };
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e.shortcuts_mut().set_enabled(trigger, enabled),
+        None => false,
+    }
+}
+
+/// List likely mistakes in the shortcuts in effect, as "<trigger>\t<kind>" lines.
+///
+/// Triggers are escaped as in `ime_shortcuts`.
+/// Kinds: "vietnamese_word" (typing the word expands it), "english_word",
+/// "prefix_of:<triggers>" / "suffix_of:<triggers>" (comma-separated longer
+/// triggers that start / end with this immediate one),
+/// "case_duplicate:<triggers>",
+/// "trigger_too_long:<chars>", "replacement_too_long:<chars>".
+/// A trigger may have several lines.
+///
+/// # Returns
+/// Number of codepoints written to `out` as UTF-32.
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_shortcut_warnings(out: *mut u32, max_len: i64) -> i64 {
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let lines: Vec<String> = e
+                .shortcuts()
+                .analyze()
+                .iter()
+                .map(|w| {
+                    format!(
+                        "{}\t{}",
+                        escape_line(&w.trigger),
+                        escape_line(&w.kind.name())
+                    )
+                })
+                .collect();
+            write_lines(lines.iter().map(String::as_str), out, max_len)
+        }
+        None => 0,
+    }
+}
+
+/// Replace all user shortcuts at once.
+///
+/// `json` uses the shortcut JSON format (see `engine::shortcut::format`):
+/// an array of `{"trigger", "replacement", "condition", "case",
+/// "input_method", "enabled"}` objects, or `{"shortcuts": [...]}`.
+/// Enabled packs, pack edits and use counts are kept.
+///
+/// # Returns
+/// false, leaving the table unchanged, if any entry is invalid or the
+/// engine is not initialized.
+///
+/// # Safety
+/// `json` must be a valid null-terminated UTF-8 string or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_replace_shortcuts(json: *const std::os::raw::c_char) -> bool {
+    if json.is_null() {
+        return false;
+    }
+    let Ok(json) = std::ffi::CStr::from_ptr(json).to_str() else {
+        return false;
     };
+    let import =
+        engine::shortcut::format::parse(json, ShortcutFormat::Json, &ImportOptions::default());
+    if !import.errors.is_empty() {
+        return false;
+    }
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e
+            .shortcuts_mut()
+            .replace_all(import.shortcuts.into_iter().map(|(_, s)| s).collect()),
+        None => false,
+    }
+}
 
+/// Set the local UTC offset (minutes) for `{date}` / `{time}` in shortcuts.
+///
+/// Default: +420 (Vietnam, UTC+07:00). Platforms should pass the system
+/// time zone's current offset and update it when the time zone changes.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_utc_offset(minutes: i32) {
     let mut guard = lock_engine();
     if let Some(ref mut e) = *guard {
-        // Auto-detect shortcut type:
-        // - If trigger contains only non-letter chars (like "->", "=>"), use immediate trigger
-        // - Otherwise use word boundary trigger (traditional abbreviations like "vn" → "Việt Nam")
-        let is_symbol_trigger = trigger_str.chars().all(|c| !c.is_alphabetic());
-        let shortcut = if is_symbol_trigger {
-            engine::shortcut::Shortcut::immediate(trigger_str, replacement_str)
-        } else {
-            engine::shortcut::Shortcut::new(trigger_str, replacement_str)
-        };
-        e.shortcuts_mut().add(shortcut);
+        e.shortcuts_mut().set_clock(Clock::System {
+            utc_offset_minutes: minutes,
+        });
     }
 }
 
@@ -387,7 +853,9 @@ pub unsafe extern "C" fn ime_remove_shortcut(trigger: *const std::os::raw::c_cha
     }
 }
 
-/// Clear all shortcuts from the engine.
+/// Clear all user shortcuts from the engine.
+///
+/// Enabled shortcut packs and edits of pack entries are kept.
 #[no_mangle]
 pub extern "C" fn ime_clear_shortcuts() {
     let mut guard = lock_engine();
@@ -396,66 +864,1085 @@ pub extern "C" fn ime_clear_shortcuts() {
     }
 }
 
-// ============================================================
-// Word Restore FFI
-// ============================================================
-
-/// Restore buffer from a Vietnamese word string.
-///
-/// Used when native app detects cursor at word boundary and user
-/// wants to continue editing (e.g., backspace into previous word).
-/// Parses Vietnamese characters back to buffer components.
-///
-/// # Arguments
-/// * `word` - C string containing the Vietnamese word to restore
+/// Enable/disable a bundled shortcut pack.
 ///
-/// # Safety
-/// Pointer must be a valid null-terminated UTF-8 string.
+/// Packs: 0 = arrows (-> → →), 1 = math (!= → ≠), 2 = currency (vnd → ₫),
+/// 3 = typography (-- → –, ... → …), 4 = Vietnamese abbreviations
+/// (ko → không, hcm → Hồ Chí Minh). User shortcuts with the same trigger
+/// take priority. All packs are disabled by default. Unknown ids are ignored.
+/// No-op if engine not initialized.
 #[no_mangle]
-pub unsafe extern "C" fn ime_restore_word(word: *const std::os::raw::c_char) {
-    if word.is_null() {
+pub extern "C" fn ime_shortcut_pack(pack: u8, enabled: bool) {
+    let Some(pack) = ShortcutPack::from_id(pack) else {
         return;
-    }
-    let word_str = match std::ffi::CStr::from_ptr(word).to_str() {
-        Ok(s) => s,
-        Err(_) => return,
     };
     let mut guard = lock_engine();
     if let Some(ref mut e) = *guard {
-        e.restore_word(word_str);
+        e.shortcuts_mut().set_pack_enabled(pack, enabled);
     }
 }
 
-// ============================================================
-// Tests
-// ============================================================
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::data::keys;
-    use serial_test::serial;
-    use std::ffi::CString;
-
-    #[test]
-    #[serial]
-    fn test_ffi_flow() {
-        ime_init();
-        ime_method(0); // Telex
-
-        // Type 'a' + 's' -> á
-        let r1 = ime_key(keys::A, false, false);
-        assert!(!r1.is_null());
-        unsafe { ime_free(r1) };
-
-        let r2 = ime_key(keys::S, false, false);
-        assert!(!r2.is_null());
-        unsafe {
-            assert_eq!((*r2).chars[0], 'á' as u32);
-            ime_free(r2);
+/// List shortcut packs as "<name>\t<enabled 0|1>" lines, one per pack id.
+///
+/// # Returns
+/// Number of codepoints written to `out` as UTF-32.
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_shortcut_packs(out: *mut u32, max_len: i64) -> i64 {
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let lines: Vec<String> = ShortcutPack::ALL
+                .iter()
+                .map(|&p| format!("{}\t{}", p.name(), u8::from(e.shortcuts().pack_enabled(p))))
+                .collect();
+            write_lines(lines.iter().map(String::as_str), out, max_len)
         }
-
-        ime_clear();
+        None => 0,
+    }
+}
+
+/// List a pack's entries as "<entry id>\t<trigger>\t<replacement>" lines.
+///
+/// Entries reflect user edits; removed entries are left out. Trigger and
+/// replacement are escaped as in `ime_shortcuts`.
+///
+/// # Returns
+/// Number of codepoints written to `out` as UTF-32 (0 for unknown packs).
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_shortcut_pack_entries(pack: u8, out: *mut u32, max_len: i64) -> i64 {
+    let Some(pack) = ShortcutPack::from_id(pack) else {
+        return 0;
+    };
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let lines: Vec<String> = e
+                .shortcuts()
+                .pack_shortcuts(pack)
+                .iter()
+                .map(|s| {
+                    let id = s.pack_entry.unwrap_or_default();
+                    format!(
+                        "{}\t{}\t{}",
+                        id,
+                        escape_line(&s.trigger),
+                        escape_line(&s.replacement)
+                    )
+                })
+                .collect();
+            write_lines(lines.iter().map(String::as_str), out, max_len)
+        }
+        None => 0,
+    }
+}
+
+/// Edit a bundled pack entry (e.g., "currency.vnd" → trigger "dong").
+///
+/// Edits are keyed by entry id: store them on the platform side and replay
+/// them after `ime_init` so they survive upgrades of the bundled packs.
+///
+/// # Returns
+/// false if the entry id is unknown or the engine is not initialized.
+///
+/// # Safety
+/// All pointers must be valid null-terminated UTF-8 strings.
+#[no_mangle]
+pub unsafe extern "C" fn ime_edit_pack_entry(
+    id: *const std::os::raw::c_char,
+    trigger: *const std::os::raw::c_char,
+    replacement: *const std::os::raw::c_char,
+) -> bool {
+    if id.is_null() || trigger.is_null() || replacement.is_null() {
+        return false;
+    }
+    let (Ok(id), Ok(trigger), Ok(replacement)) = (
+        std::ffi::CStr::from_ptr(id).to_str(),
+        std::ffi::CStr::from_ptr(trigger).to_str(),
+        std::ffi::CStr::from_ptr(replacement).to_str(),
+    ) else {
+        return false;
+    };
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e.shortcuts_mut().edit_pack_entry(id, trigger, replacement),
+        None => false,
+    }
+}
+
+/// Apply `f` to the shortcut table with a pack entry id argument.
+///
+/// # Safety
+/// `id` must be a valid null-terminated string or null.
+unsafe fn with_pack_entry(
+    id: *const std::os::raw::c_char,
+    f: impl FnOnce(&mut engine::shortcut::ShortcutTable, &str) -> bool,
+) -> bool {
+    if id.is_null() {
+        return false;
+    }
+    let Ok(id) = std::ffi::CStr::from_ptr(id).to_str() else {
+        return false;
+    };
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => f(e.shortcuts_mut(), id),
+        None => false,
+    }
+}
+
+/// Remove a bundled pack entry; it stays removed when the pack is re-enabled.
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_remove_pack_entry(id: *const std::os::raw::c_char) -> bool {
+    with_pack_entry(id, |t, id| t.remove_pack_entry(id))
+}
+
+/// Undo the user's edit or removal of a bundled pack entry.
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_reset_pack_entry(id: *const std::os::raw::c_char) -> bool {
+    with_pack_entry(id, |t, id| t.reset_pack_entry(id))
+}
+
+/// Import shortcuts from a UniKey, OpenKey or EVKey macro file or a JSON file.
+///
+/// # Arguments
+/// * `path` - C string (UTF-8) file path; contents UTF-8, or UTF-16 with a BOM
+/// * `format` - 0 = UniKey, 1 = OpenKey, 2 = EVKey, 3 = JSON; other values
+///   detect the format from the contents
+/// * `flags` - 0x01 = keep replacement case as written, 0x02 = expand
+///   immediately instead of on word boundary, 0x04 = overwrite user
+///   shortcuts with the same trigger (JSON entries may override 0x01/0x02)
+/// * `report` - Output buffer for UTF-32 report lines, ordered by line:
+///   "error\t<line>\t<kind>", "duplicate\t<line>\t<trigger>" (defined again
+///   in the file, last one wins), "conflict\t<line>\t<trigger>" (an existing
+///   user shortcut differs), "warning\t<line>\t<trigger>\t<kind>" (imported,
+///   but likely a mistake; kinds as for `ime_shortcut_warnings`). Triggers
+///   are escaped as in `ime_shortcuts`. May be null.
+/// * `report_max` - Maximum number of codepoints to write to `report`
+/// * `report_len` - Receives the number of codepoints written, or minus the
+///   number needed if the report doesn't fit in `report_max` (then nothing
+///   is written). May be null.
+///
+/// # Returns
+/// Number of shortcuts imported, or -1 if the file can't be read or engine not initialized.
+///
+/// # Safety
+/// `path` must be a valid null-terminated UTF-8 string or null; `report` must
+/// be null or valid for `report_max` u32 writes; `report_len` must be null or valid.
+#[no_mangle]
+pub unsafe extern "C" fn ime_import_shortcuts(
+    path: *const std::os::raw::c_char,
+    format: u8,
+    flags: u8,
+    report: *mut u32,
+    report_max: i64,
+    report_len: *mut i64,
+) -> i64 {
+    if !report_len.is_null() {
+        *report_len = 0;
+    }
+    if path.is_null() {
+        return -1;
+    }
+    let Ok(path) = std::ffi::CStr::from_ptr(path).to_str() else {
+        return -1;
+    };
+    let Ok(text) = engine::shortcut::format::read_file(path) else {
+        return -1;
+    };
+    let format = ShortcutFormat::from_id(format).unwrap_or_else(|| ShortcutFormat::detect(&text));
+    let mut guard = lock_engine();
+    let Some(ref mut e) = *guard else {
+        return -1;
+    };
+    let import = e
+        .shortcuts_mut()
+        .import(&text, format, &ImportOptions::from_flags(flags));
+
+    if !report.is_null() && report_max > 0 {
+        let mut lines: Vec<(usize, String)> = import
+            .errors
+            .iter()
+            .map(|err| {
+                (
+                    err.line,
+                    format!("error\t{}\t{}", err.line, err.kind.name()),
+                )
+            })
+            .collect();
+        lines.extend(import.conflicts.iter().map(|c| {
+            let (kind, trigger) = match c {
+                Conflict::Duplicate { trigger, .. } => ("duplicate", trigger),
+                Conflict::Existing { trigger, .. } => ("conflict", trigger),
+            };
+            (
+                c.line(),
+                format!("{}\t{}\t{}", kind, c.line(), escape_line(trigger)),
+            )
+        }));
+        lines.extend(import.warnings.iter().map(|(line, w)| {
+            let text = format!(
+                "warning\t{}\t{}\t{}",
+                line,
+                escape_line(&w.trigger),
+                escape_line(&w.kind.name())
+            );
+            (*line, text)
+        }));
+        lines.sort_by_key(|(line, _)| *line);
+        let len = write_lines(lines.iter().map(|(_, l)| l.as_str()), report, report_max);
+        if !report_len.is_null() {
+            *report_len = len;
+        }
+    }
+    import.shortcuts.len() as i64
+}
+
+/// Export user shortcuts (bundled packs excluded) to a file.
+///
+/// `format`: 0 = UniKey, 1 = OpenKey, 2 = EVKey, 3 = JSON. Macro files keep
+/// only trigger and replacement; JSON keeps every field.
+///
+/// Returns false for unknown formats, if the file can't be written or engine not initialized.
+///
+/// # Safety
+/// `path` must be a valid null-terminated UTF-8 string or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_export_shortcuts(
+    path: *const std::os::raw::c_char,
+    format: u8,
+) -> bool {
+    let Some(format) = ShortcutFormat::from_id(format) else {
+        return false;
+    };
+    if path.is_null() {
+        return false;
+    }
+    let Ok(path) = std::ffi::CStr::from_ptr(path).to_str() else {
+        return false;
+    };
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => std::fs::write(path, e.shortcuts().export(format)).is_ok(),
+        None => false,
+    }
+}
+
+// ============================================================
+// Autocorrect FFI
+// ============================================================
+
+/// Enable/disable autocorrect of common misspellings at word commit.
+///
+/// When `enabled` is true, known mistakes are fixed on Space/punctuation
+/// (e.g., "xuất xắc" → "xuất sắc"); Backspace right after the space undoes it.
+/// When `enabled` is false (default), words are committed as typed.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_autocorrect(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_autocorrect(enabled);
+    }
+}
+
+/// Add a user autocorrect entry (overrides the bundled list).
+///
+/// # Arguments
+/// * `typo` - C string for the misspelling, one or two words (e.g., "hok")
+/// * `correction` - C string for the correct text (e.g., "không")
+///
+/// # Safety
+/// Both pointers must be valid null-terminated UTF-8 strings.
+#[no_mangle]
+pub unsafe extern "C" fn ime_add_autocorrect(
+    typo: *const std::os::raw::c_char,
+    correction: *const std::os::raw::c_char,
+) {
+    if typo.is_null() || correction.is_null() {
+        return;
+    }
+
+    let typo_str = match std::ffi::CStr::from_ptr(typo).to_str() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let correction_str = match std::ffi::CStr::from_ptr(correction).to_str() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.autocorrect_mut().add(typo_str, correction_str);
+    }
+}
+
+/// Remove a user autocorrect entry.
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_remove_autocorrect(typo: *const std::os::raw::c_char) {
+    if typo.is_null() {
+        return;
+    }
+
+    let typo_str = match std::ffi::CStr::from_ptr(typo).to_str() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.autocorrect_mut().remove(typo_str);
+    }
+}
+
+/// Clear all user autocorrect entries (bundled list is kept).
+#[no_mangle]
+pub extern "C" fn ime_clear_autocorrect() {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.autocorrect_mut().clear_user();
+    }
+}
+
+// ============================================================
+// English Dictionary FFI
+// ============================================================
+
+/// Apply `f` to the user dictionary with a C string argument.
+///
+/// # Safety
+/// `word` must be a valid null-terminated string or null.
+unsafe fn with_user_dictionary(
+    word: *const std::os::raw::c_char,
+    f: impl FnOnce(&mut engine::user_dictionary::UserDictionary, &str),
+) {
+    if word.is_null() {
+        return;
+    }
+    let Ok(word) = std::ffi::CStr::from_ptr(word).to_str() else {
+        return;
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        f(e.user_dictionary_mut(), word);
+    }
+}
+
+/// Add a word to the user English dictionary.
+///
+/// The word restores to English on auto-restore even if the bundled
+/// dictionary doesn't know it (e.g., "kubectl", "terraform").
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_add_english_word(word: *const std::os::raw::c_char) {
+    with_user_dictionary(word, |d, w| {
+        d.add(w);
+    });
+}
+
+/// Remove a word from the English dictionary (user or bundled).
+///
+/// Typing it keeps Vietnamese instead of restoring to English.
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_remove_english_word(word: *const std::os::raw::c_char) {
+    with_user_dictionary(word, |d, w| {
+        d.remove(w);
+    });
+}
+
+/// Drop the user entry for a word (back to the bundled dictionary).
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_reset_english_word(word: *const std::os::raw::c_char) {
+    with_user_dictionary(word, |d, w| d.reset(w));
+}
+
+/// Clear all user English dictionary entries.
+#[no_mangle]
+pub extern "C" fn ime_clear_english_words() {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.user_dictionary_mut().clear();
+    }
+}
+
+/// Load user English dictionary entries from a file, adding to current ones.
+///
+/// One word per line; "-word" removes a bundled word; "#" starts a comment.
+///
+/// # Returns
+/// Number of entries loaded, or -1 if the file can't be read or engine not initialized.
+///
+/// # Safety
+/// `path` must be a valid null-terminated UTF-8 string or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_load_english_words(path: *const std::os::raw::c_char) -> i64 {
+    if path.is_null() {
+        return -1;
+    }
+    let Ok(path) = std::ffi::CStr::from_ptr(path).to_str() else {
+        return -1;
+    };
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e
+            .user_dictionary_mut()
+            .load_file(path)
+            .map_or(-1, |n| n as i64),
+        None => -1,
+    }
+}
+
+/// Save user English dictionary entries to a file (same format as load).
+///
+/// Returns false if the file can't be written or engine not initialized.
+///
+/// # Safety
+/// `path` must be a valid null-terminated UTF-8 string or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_save_english_words(path: *const std::os::raw::c_char) -> bool {
+    if path.is_null() {
+        return false;
+    }
+    let Ok(path) = std::ffi::CStr::from_ptr(path).to_str() else {
+        return false;
+    };
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => e.user_dictionary().save_file(path).is_ok(),
+        None => false,
+    }
+}
+
+// ============================================================
+// Blocklist FFI
+// ============================================================
+
+/// Add a blocklist entry: a word ("pnpm") or prefix ("dotf*").
+///
+/// Matching words are typed as raw ASCII without marks or tones.
+/// Returns false if the entry is invalid or engine not initialized.
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_add_blocked_word(entry: *const std::os::raw::c_char) -> bool {
+    if entry.is_null() {
+        return false;
+    }
+    let Ok(entry) = std::ffi::CStr::from_ptr(entry).to_str() else {
+        return false;
+    };
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e.blocklist_mut().add(entry),
+        None => false,
+    }
+}
+
+/// Remove a blocklist entry (same syntax as `ime_add_blocked_word`).
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_remove_blocked_word(entry: *const std::os::raw::c_char) {
+    if entry.is_null() {
+        return;
+    }
+    let Ok(entry) = std::ffi::CStr::from_ptr(entry).to_str() else {
+        return;
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.blocklist_mut().remove(entry);
+    }
+}
+
+/// Clear all blocklist entries.
+#[no_mangle]
+pub extern "C" fn ime_clear_blocked_words() {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.blocklist_mut().clear();
+    }
+}
+
+/// List blocklist entries as newline-separated UTF-32 (prefixes end with '*').
+///
+/// # Returns
+/// Number of codepoints written to `out`.
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_blocked_words(out: *mut u32, max_len: i64) -> i64 {
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let entries = e.blocklist().entries();
+            write_lines(entries.iter().map(String::as_str), out, max_len)
+        }
+        None => 0,
+    }
+}
+
+// ============================================================
+// Word Restore FFI
+// ============================================================
+
+/// Restore buffer from a Vietnamese word string.
+///
+/// Used when native app detects cursor at word boundary and user
+/// wants to continue editing (e.g., backspace into previous word).
+/// Parses Vietnamese characters back to buffer components.
+///
+/// # Arguments
+/// * `word` - C string containing the Vietnamese word to restore
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_restore_word(word: *const std::os::raw::c_char) {
+    if word.is_null() {
+        return;
+    }
+    let word_str = match std::ffi::CStr::from_ptr(word).to_str() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.restore_word(word_str);
+    }
+}
+
+// ============================================================
+// Validation FFI
+// ============================================================
+
+/// Write a validation outcome to the FFI out-params, returning its code
+///
+/// # Safety
+/// `out_start`/`out_end` must each be null or valid for writes.
+unsafe fn write_validation(
+    outcome: std::result::Result<(), engine::validation::SyllableError>,
+    out_start: *mut i64,
+    out_end: *mut i64,
+) -> u8 {
+    let (code, start, end) = match outcome {
+        Ok(()) => (engine::validation::ValidationResult::Valid, 0, 0),
+        Err(e) => (e.result, e.start, e.end),
+    };
+    if !out_start.is_null() {
+        *out_start = start as i64;
+    }
+    if !out_end.is_null() {
+        *out_end = end as i64;
+    }
+    code as u8
+}
+
+/// Validate a single Vietnamese syllable (e.g. "nghiêng").
+///
+/// Does not need `ime_init` and does not touch engine state.
+///
+/// # Arguments
+/// * `text` - C string (UTF-8) containing one syllable
+/// * `out_start`, `out_end` - Receive the byte span of the offending part
+///   (0, 0 when valid). Either may be null.
+///
+/// # Returns
+/// 0 = valid, 1 = invalid initial, 2 = invalid final, 3 = spelling (c/k, g/gh, ng/ngh),
+/// 4 = invalid vowel pattern, 5 = no vowel, 6 = invalid character, 7 = invalid tone mark.
+/// Null or non-UTF-8 input returns 6.
+///
+/// # Safety
+/// `text` must be a valid null-terminated string or null; out pointers must be
+/// valid for writes or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_validate_syllable(
+    text: *const std::os::raw::c_char,
+    out_start: *mut i64,
+    out_end: *mut i64,
+) -> u8 {
+    validate_c_str(
+        text,
+        out_start,
+        out_end,
+        engine::validation::validate_syllable,
+    )
+}
+
+/// Validate a word of syllables separated by spaces or hyphens (e.g. "cà-phê").
+///
+/// Same codes and span semantics as `ime_validate_syllable`, reporting the
+/// first invalid syllable (span relative to the whole string).
+///
+/// # Safety
+/// Same as `ime_validate_syllable`.
+#[no_mangle]
+pub unsafe extern "C" fn ime_validate_word(
+    text: *const std::os::raw::c_char,
+    out_start: *mut i64,
+    out_end: *mut i64,
+) -> u8 {
+    validate_c_str(text, out_start, out_end, engine::validation::validate_word)
+}
+
+/// Shared body of the validation FFI functions
+unsafe fn validate_c_str(
+    text: *const std::os::raw::c_char,
+    out_start: *mut i64,
+    out_end: *mut i64,
+    validate: fn(&str) -> std::result::Result<(), engine::validation::SyllableError>,
+) -> u8 {
+    let text = if text.is_null() {
+        None
+    } else {
+        std::ffi::CStr::from_ptr(text).to_str().ok()
+    };
+    match text {
+        Some(s) => write_validation(validate(s), out_start, out_end),
+        None => {
+            write_validation(Ok(()), out_start, out_end);
+            engine::validation::ValidationResult::InvalidChar as u8
+        }
+    }
+}
+
+// ============================================================
+// Suggestion FFI
+// ============================================================
+
+/// Write strings as newline-separated UTF-32 codepoints
+///
+/// Returns the number of codepoints written. If they don't fit in `max_len`
+/// nothing is written and minus the number needed is returned, so callers
+/// can retry with a larger buffer instead of reading a cut-off line.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+unsafe fn write_lines<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+    out: *mut u32,
+    max_len: i64,
+) -> i64 {
+    let joined = lines.into_iter().collect::<Vec<_>>().join("\n");
+    let utf32: Vec<u32> = joined.chars().map(|c| c as u32).collect();
+    if utf32.len() > max_len as usize {
+        return -(utf32.len() as i64);
+    }
+    std::ptr::copy_nonoverlapping(utf32.as_ptr(), out, utf32.len());
+    utf32.len() as i64
+}
+
+/// Suggest valid Vietnamese syllables near an invalid one (e.g. "ngi" → "nghi").
+///
+/// Does not need `ime_init` and does not touch engine state.
+///
+/// # Arguments
+/// * `text` - C string (UTF-8) containing one syllable
+/// * `limit` - Maximum number of suggestions
+/// * `out` - Output buffer for UTF-32 codepoints, suggestions separated by '\n',
+///   best first
+/// * `max_len` - Maximum number of codepoints to write
+///
+/// # Returns
+/// Number of codepoints written to `out` (0 if no suggestions).
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `text` must be a valid null-terminated string or null;
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_suggest(
+    text: *const std::os::raw::c_char,
+    limit: u32,
+    out: *mut u32,
+    max_len: i64,
+) -> i64 {
+    if text.is_null() || out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let Ok(text) = std::ffi::CStr::from_ptr(text).to_str() else {
+        return 0;
+    };
+    let suggestions = suggest::suggest(text, limit as usize);
+    write_lines(suggestions.iter().map(|s| s.text.as_str()), out, max_len)
+}
+
+/// Suggest valid Vietnamese syllables for the word being typed.
+///
+/// Uses the engine's raw keystrokes, input method and tone style.
+/// Same output format and return value as `ime_suggest`; 0 if engine not
+/// initialized.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_suggest_buffer(limit: u32, out: *mut u32, max_len: i64) -> i64 {
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let suggestions = e.suggestions(limit as usize);
+            write_lines(suggestions.iter().map(|s| s.text.as_str()), out, max_len)
+        }
+        None => 0,
+    }
+}
+
+// ============================================================
+// Prediction FFI
+// ============================================================
+
+/// Enable or disable word completion and next-word prediction.
+///
+/// When enabled, committed words are also learned.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_prediction(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_prediction(enabled);
+    }
+}
+
+/// Get completion/prediction candidates for the current position.
+///
+/// Completes the word being typed, or predicts the next word right after a
+/// committed word and a space.
+///
+/// # Arguments
+/// * `limit` - Maximum number of candidates
+/// * `out` - Output buffer for UTF-32 codepoints, candidates separated by '\n',
+///   best first
+/// * `max_len` - Maximum number of codepoints to write
+///
+/// # Returns
+/// Number of codepoints written (0 if none or engine not initialized).
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_candidates(limit: u32, out: *mut u32, max_len: i64) -> i64 {
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let candidates = e.candidates(limit as usize);
+            write_lines(candidates.iter().map(|c| c.text.as_str()), out, max_len)
+        }
+        None => 0,
+    }
+}
+
+/// Fetch the next part of a long or multi-line shortcut expansion.
+///
+/// Call after a result with flag 0x04 (more output) and before the next
+/// key, until a part comes without it. Only used after the frontend sets
+/// capability 0x02 with `ime_frontend_caps`. Parts have no backspace; Enter and
+/// Tab come as their own one-char parts.
+///
+/// # Returns
+/// * Pointer to `Result` (caller must free with `ime_free`); action 0 if
+///   nothing is pending
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_next_output() -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        let r = e.next_output();
+        Box::into_raw(Box::new(r))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+/// Accept candidate `index` (0-based, as listed by `ime_candidates`).
+///
+/// # Returns
+/// * Pointer to `Result` replacing the current word with the candidate and a
+///   space (caller must free with `ime_free`); action 0 if no such candidate
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_accept_candidate(index: u32) -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        let r = e.accept_candidate(index as usize);
+        Box::into_raw(Box::new(r))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+/// Export learned word counts ("<count>\t<text>" lines) for persistence.
+///
+/// # Returns
+/// Number of codepoints written to `out` as UTF-32.
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_export_learned(out: *mut u32, max_len: i64) -> i64 {
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let data = e.predictor().export_learned();
+            write_lines(std::iter::once(data.as_str()), out, max_len)
+        }
+        None => 0,
+    }
+}
+
+/// Import learned word counts previously exported with `ime_export_learned`.
+///
+/// # Returns
+/// Number of entries imported (0 if engine not initialized).
+///
+/// # Safety
+/// `data` must be a valid null-terminated string or null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_import_learned(data: *const std::os::raw::c_char) -> u32 {
+    if data.is_null() {
+        return 0;
+    }
+    let Ok(data) = std::ffi::CStr::from_ptr(data).to_str() else {
+        return 0;
+    };
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e.predictor_mut().import_learned(data) as u32,
+        None => 0,
+    }
+}
+
+// ============================================================
+// Candidate List FFI
+// ============================================================
+
+/// Candidate list state for frontends
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct CandidateListInfo {
+    /// Number of candidates (all pages); 0 when the list is closed
+    pub total: u32,
+    /// Current page (0-based)
+    pub page: u32,
+    pub page_count: u32,
+    pub page_size: u32,
+    /// Highlighted index on the current page, -1 if none
+    pub highlighted: i32,
+}
+
+/// Get candidate list state.
+///
+/// Call after a key whose result has flag 0x02 (candidates changed).
+/// Returns false (and leaves `info` untouched) if engine not initialized.
+///
+/// # Safety
+/// `info` must point to a valid `CandidateListInfo` or be null.
+#[no_mangle]
+pub unsafe extern "C" fn ime_candidate_list_info(info: *mut CandidateListInfo) -> bool {
+    if info.is_null() {
+        return false;
+    }
+    let guard = lock_engine();
+    let Some(ref e) = *guard else {
+        return false;
+    };
+    let list = e.candidate_list();
+    *info = CandidateListInfo {
+        total: list.items().len() as u32,
+        page: list.page() as u32,
+        page_count: list.page_count() as u32,
+        page_size: list.page_size() as u32,
+        highlighted: list.highlighted().map_or(-1, |i| i as i32),
+    };
+    true
+}
+
+/// Get the candidates on the current page.
+///
+/// # Arguments
+/// * `out` - Output buffer for UTF-32 codepoints, one "label\ttext" line per
+///   candidate (labels "1".."9", then "0")
+/// * `max_len` - Maximum number of codepoints to write
+///
+/// # Returns
+/// Number of codepoints written (0 if the list is closed).
+/// If the text doesn't fit in `max_len`, nothing is written and minus the
+/// number of codepoints needed is returned.
+///
+/// # Safety
+/// `out` must point to valid memory of at least `max_len * sizeof(u32)` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_candidate_list(out: *mut u32, max_len: i64) -> i64 {
+    if out.is_null() || max_len <= 0 {
+        return 0;
+    }
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) => {
+            let lines: Vec<String> = e
+                .candidate_list()
+                .page_items()
+                .map(|(label, text)| format!("{}\t{}", label, text))
+                .collect();
+            write_lines(lines.iter().map(String::as_str), out, max_len)
+        }
+        None => 0,
+    }
+}
+
+/// Select candidate `index` (0-based) on the current page.
+///
+/// # Returns
+/// * Pointer to `Result` with the text to insert (caller must free with
+///   `ime_free`); action 0 if there is no such candidate
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_select_candidate(index: u32) -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        let r = e.select_candidate(index as usize);
+        Box::into_raw(Box::new(r))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+/// Let digits, arrows and page keys select in the candidate list.
+///
+/// Prediction lists open passive so numbers and caret moves still type;
+/// call this when the user opens the list explicitly (hotkey, click).
+/// Tab does the same from the keyboard.
+///
+/// Returns false if no list is open or engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_activate_candidates() -> bool {
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e.activate_candidates(),
+        None => false,
+    }
+}
+
+/// Go to the next (`forward` = true) or previous candidate page, activating
+/// the list.
+///
+/// Returns false if there is only one page or engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_candidate_page(forward: bool) -> bool {
+    let mut guard = lock_engine();
+    match *guard {
+        Some(ref mut e) => e.turn_candidate_page(forward),
+        None => false,
+    }
+}
+
+/// Set candidates shown per page (1-10, default 9).
+///
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_candidate_page_size(size: u32) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_candidate_page_size(size as usize);
+    }
+}
+
+/// Close the candidate list (e.g. when the popup is dismissed by mouse).
+///
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_close_candidates() {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.close_candidates();
+    }
+}
+
+// ============================================================
+// Tests
+// ============================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::keys;
+    use crate::engine::shortcut::format::unescape_line;
+    use serial_test::serial;
+    use std::ffi::CString;
+
+    #[test]
+    #[serial]
+    fn test_ffi_flow() {
+        ime_init();
+        ime_method(0); // Telex
+
+        // Type 'a' + 's' -> á
+        let r1 = ime_key(keys::A, false, false);
+        assert!(!r1.is_null());
+        unsafe { ime_free(r1) };
+
+        let r2 = ime_key(keys::S, false, false);
+        assert!(!r2.is_null());
+        unsafe {
+            assert_eq!((*r2).chars[0], 'á' as u32);
+            ime_free(r2);
+        }
+
+        ime_clear();
     }
 
     #[test]
@@ -596,18 +2083,50 @@ mod tests {
             ime_add_shortcut(trigger.as_ptr(), replacement.as_ptr());
         }
 
-        // Verify shortcut was added with immediate trigger
-        let guard = lock_engine();
-        if let Some(ref e) = *guard {
-            assert_eq!(e.shortcuts().len(), 1);
-            let shortcut = e.shortcuts().lookup("->").unwrap().1;
-            assert_eq!(
-                shortcut.condition,
-                engine::shortcut::TriggerCondition::Immediate,
-                "Symbol-only trigger should be immediate"
-            );
+        // Verify shortcut was added with immediate trigger
+        let guard = lock_engine();
+        if let Some(ref e) = *guard {
+            assert_eq!(e.shortcuts().len(), 1);
+            let shortcut = e.shortcuts().lookup("->").unwrap().1;
+            assert_eq!(
+                shortcut.condition,
+                engine::shortcut::TriggerCondition::Immediate,
+                "Symbol-only trigger should be immediate"
+            );
+        }
+        drop(guard);
+
+        ime_clear_shortcuts();
+        ime_clear();
+    }
+
+    #[test]
+    #[serial]
+    fn test_shortcut_ffi_digit_symbol_trigger_immediate() {
+        // Triggers without letters stay immediate even with digits ("1/2", ":3")
+        ime_init();
+        ime_clear_shortcuts();
+
+        for (trigger, replacement) in [("1/2", "½"), (":3", "😺")] {
+            let t = CString::new(trigger).unwrap();
+            let r = CString::new(replacement).unwrap();
+            unsafe {
+                assert!(ime_add_shortcut(t.as_ptr(), r.as_ptr()));
+            }
+        }
+
+        {
+            let guard = lock_engine();
+            let e = guard.as_ref().unwrap();
+            for trigger in ["1/2", ":3"] {
+                assert_eq!(
+                    e.shortcuts().lookup(trigger).unwrap().1.condition,
+                    engine::shortcut::TriggerCondition::Immediate,
+                    "{}",
+                    trigger
+                );
+            }
         }
-        drop(guard);
 
         ime_clear_shortcuts();
         ime_clear();
@@ -743,4 +2262,859 @@ mod tests {
 
         ime_clear();
     }
+
+    #[test]
+    fn test_validate_syllable_ffi() {
+        let mut start = -1i64;
+        let mut end = -1i64;
+
+        let valid = CString::new("nghiêng").unwrap();
+        let code = unsafe { ime_validate_syllable(valid.as_ptr(), &mut start, &mut end) };
+        assert_eq!((code, start, end), (0, 0, 0));
+
+        // "kà": k before a → spelling, span covers "kà" (1 + 2 bytes)
+        let spelling = CString::new("kà").unwrap();
+        let code = unsafe { ime_validate_syllable(spelling.as_ptr(), &mut start, &mut end) };
+        assert_eq!((code, start, end), (3, 0, 3));
+
+        // Word: second syllable "cl..." has an invalid initial
+        let word = CString::new("xin clào").unwrap();
+        let code = unsafe { ime_validate_word(word.as_ptr(), &mut start, &mut end) };
+        assert_eq!((code, start, end), (1, 4, 6));
+
+        // Null input and null out-params are safe
+        let code = unsafe {
+            ime_validate_syllable(std::ptr::null(), std::ptr::null_mut(), std::ptr::null_mut())
+        };
+        assert_eq!(code, 6);
+    }
+
+    #[test]
+    #[serial]
+    fn test_suggest_ffi() {
+        let mut out = [0u32; 64];
+        let text = CString::new("ngi").unwrap();
+        let n = unsafe { ime_suggest(text.as_ptr(), 2, out.as_mut_ptr(), 64) };
+        let s: String = out[..n as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        assert!(s.starts_with("nghi\n"), "{}", s);
+        assert_eq!(s.lines().count(), 2);
+
+        // Current word: "kaf" → "kà" → "cà"
+        ime_init();
+        ime_method(0);
+        for key in [keys::K, keys::A, keys::F] {
+            let r = ime_key(key, false, false);
+            unsafe { ime_free(r) };
+        }
+        let n = unsafe { ime_suggest_buffer(1, out.as_mut_ptr(), 64) };
+        let s: String = out[..n as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        assert_eq!(s, "cà");
+        ime_clear();
+    }
+
+    #[test]
+    #[serial]
+    fn test_autocorrect_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_autocorrect(true);
+
+        let typo = CString::new("hok").unwrap();
+        let correction = CString::new("không").unwrap();
+        unsafe { ime_add_autocorrect(typo.as_ptr(), correction.as_ptr()) };
+
+        for key in [keys::H, keys::O, keys::K] {
+            let r = ime_key(key, false, false);
+            unsafe { ime_free(r) };
+        }
+        let r = ime_key(keys::SPACE, false, false);
+        unsafe {
+            let res = &*r;
+            assert_eq!(res.action, 1);
+            assert_eq!(res.backspace, 3);
+            let out: String = res.chars[..res.count as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+                .collect();
+            assert_eq!(out, "không ");
+            ime_free(r);
+        }
+
+        unsafe { ime_remove_autocorrect(typo.as_ptr()) };
+        ime_clear_autocorrect();
+        ime_autocorrect(false);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_prediction_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_prediction(true);
+
+        // "chuyee" → "chuyê"
+        for key in [keys::C, keys::H, keys::U, keys::Y, keys::E, keys::E] {
+            let r = ime_key(key, false, false);
+            unsafe { ime_free(r) };
+        }
+        let mut out = [0u32; 256];
+        let n = unsafe { ime_candidates(5, out.as_mut_ptr(), out.len() as i64) };
+        let list: String = out[..n as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        let first = list.lines().next().unwrap().to_string();
+        assert!(list.lines().any(|w| w == "chuyên"), "{}", list);
+
+        let r = ime_accept_candidate(0);
+        unsafe {
+            let res = &*r;
+            assert_eq!(res.action, 1);
+            assert_eq!(res.backspace, 5);
+            let text: String = res.chars[..res.count as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+                .collect();
+            assert_eq!(text, format!("{} ", first));
+            ime_free(r);
+        }
+
+        let n = unsafe { ime_export_learned(out.as_mut_ptr(), out.len() as i64) };
+        let learned: String = out[..n as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        assert_eq!(learned, format!("1\t{}", first));
+        let data = CString::new("2\txin chào").unwrap();
+        assert_eq!(unsafe { ime_import_learned(data.as_ptr()) }, 1);
+
+        ime_prediction(false);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_candidate_list_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_prediction(true);
+        ime_candidate_page_size(3);
+
+        // "chuyee" → "chuyê", list opens
+        let mut flags = 0;
+        for key in [keys::C, keys::H, keys::U, keys::Y, keys::E, keys::E] {
+            let r = ime_key(key, false, false);
+            unsafe {
+                flags |= (*r).flags;
+                ime_free(r);
+            }
+        }
+        assert_ne!(flags & engine::FLAG_CANDIDATES_CHANGED, 0);
+
+        let mut info = CandidateListInfo::default();
+        assert!(unsafe { ime_candidate_list_info(&mut info) });
+        assert_eq!(info.page, 0);
+        assert_eq!(info.page_size, 3);
+        assert_eq!(info.highlighted, -1);
+        assert!(info.page_count >= 2, "{:?}", info);
+
+        let mut out = [0u32; 256];
+        let n = unsafe { ime_candidate_list(out.as_mut_ptr(), out.len() as i64) };
+        let page: String = out[..n as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        let lines: Vec<&str> = page.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("1\t"));
+
+        assert!(ime_activate_candidates());
+        assert!(ime_candidate_page(true));
+        let r = ime_select_candidate(0);
+        unsafe {
+            let res = &*r;
+            assert_eq!(res.action, 1);
+            assert_eq!(res.backspace, 5);
+            ime_free(r);
+        }
+
+        ime_close_candidates();
+        assert!(unsafe { ime_candidate_list_info(&mut info) });
+        assert_eq!(info.total, 0);
+        assert!(!ime_activate_candidates());
+
+        ime_candidate_page_size(9);
+        ime_prediction(false);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_english_dictionary_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_english_auto_restore(true);
+
+        let word = CString::new("vuex").unwrap();
+        unsafe { ime_add_english_word(word.as_ptr()) };
+
+        let path =
+            std::env::temp_dir().join(format!("gonhanh_ffi_dict_{}.txt", std::process::id()));
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        assert!(unsafe { ime_save_english_words(c_path.as_ptr()) });
+        ime_clear_english_words();
+        assert_eq!(unsafe { ime_load_english_words(c_path.as_ptr()) }, 1);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(unsafe { ime_load_english_words(c_path.as_ptr()) }, -1);
+
+        // "vuex" → "vuẽ" while typing, restored on space
+        for key in [keys::V, keys::U, keys::E, keys::X] {
+            let r = ime_key(key, false, false);
+            unsafe { ime_free(r) };
+        }
+        let r = ime_key(keys::SPACE, false, false);
+        unsafe {
+            let res = &*r;
+            assert_eq!(res.action, 1);
+            let out: String = res.chars[..res.count as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+                .collect();
+            assert_eq!(out, "vuex ");
+            ime_free(r);
+        }
+
+        unsafe { ime_reset_english_word(word.as_ptr()) };
+        unsafe { ime_remove_english_word(word.as_ptr()) };
+        ime_clear_english_words();
+        ime_english_auto_restore(false);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_blocklist_ffi() {
+        ime_init();
+        ime_method(0);
+
+        let word = CString::new("pnpm").unwrap();
+        let prefix = CString::new("dotf*").unwrap();
+        let invalid = CString::new("two words").unwrap();
+        unsafe {
+            assert!(ime_add_blocked_word(word.as_ptr()));
+            assert!(ime_add_blocked_word(prefix.as_ptr()));
+            assert!(!ime_add_blocked_word(invalid.as_ptr()));
+        }
+
+        let mut out = [0u32; 64];
+        let n = unsafe { ime_blocked_words(out.as_mut_ptr(), out.len() as i64) };
+        let list: String = out[..n as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        assert_eq!(list, "pnpm\ndotf*");
+
+        unsafe { ime_remove_blocked_word(word.as_ptr()) };
+        let n = unsafe { ime_blocked_words(out.as_mut_ptr(), out.len() as i64) };
+        assert_eq!(n, 5);
+
+        ime_clear_blocked_words();
+        let n = unsafe { ime_blocked_words(out.as_mut_ptr(), out.len() as i64) };
+        assert_eq!(n, 0);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_english_classifier_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_english_auto_restore(true);
+        ime_english_classifier(true);
+
+        let type_defend = || -> String {
+            for key in [keys::D, keys::E, keys::F, keys::E, keys::N, keys::D] {
+                let r = ime_key(key, false, false);
+                unsafe { ime_free(r) };
+            }
+            let r = ime_key(keys::SPACE, false, false);
+            unsafe {
+                let res = &*r;
+                let out = res.chars[..res.count as usize]
+                    .iter()
+                    .filter_map(|&c| char::from_u32(c))
+                    .collect();
+                ime_free(r);
+                out
+            }
+        };
+        assert_eq!(type_defend(), "defend ");
+
+        ime_english_classifier_threshold(100.0);
+        assert_eq!(type_defend(), "");
+
+        ime_english_classifier_threshold(1.0);
+        ime_english_classifier(false);
+        ime_english_auto_restore(false);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_language_pack_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_english_auto_restore(true);
+
+        let type_tasse = || -> String {
+            let mut out = String::new();
+            for (key, ch) in [
+                (keys::T, 't'),
+                (keys::A, 'a'),
+                (keys::S, 's'),
+                (keys::S, 's'),
+                (keys::E, 'e'),
+                (keys::SPACE, ' '),
+            ] {
+                let r = ime_key(key, false, false);
+                unsafe {
+                    let res = &*r;
+                    if res.action == 1 {
+                        for _ in 0..res.backspace {
+                            out.pop();
+                        }
+                        let chars = &res.chars[..res.count as usize];
+                        out.extend(chars.iter().filter_map(|&c| char::from_u32(c)));
+                    } else {
+                        out.push(ch);
+                    }
+                    ime_free(r);
+                }
+            }
+            out
+        };
+
+        ime_language_pack(2, true); // German
+        assert_eq!(type_tasse(), "tasse ");
+        ime_language_pack(2, false);
+        assert_eq!(type_tasse(), "tase ");
+        ime_language_pack(99, true); // ignored
+
+        ime_english_auto_restore(false);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_identifier_detection_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_identifier_detection(1, true); // snake_case
+
+        // "a_as": 's' would add a tone without detection
+        let mut out = String::new();
+        for (key, shift, ch) in [
+            (keys::A, false, 'a'),
+            (keys::MINUS, true, '_'),
+            (keys::A, false, 'a'),
+            (keys::S, false, 's'),
+        ] {
+            let r = ime_key_ext(key, false, false, shift);
+            unsafe {
+                let res = &*r;
+                if res.action == 1 {
+                    for _ in 0..res.backspace {
+                        out.pop();
+                    }
+                    let chars = &res.chars[..res.count as usize];
+                    out.extend(chars.iter().filter_map(|&c| char::from_u32(c)));
+                } else {
+                    out.push(ch);
+                }
+                ime_free(r);
+            }
+        }
+        assert_eq!(out, "a_as");
+
+        ime_identifier_detection(1, false);
+        ime_identifier_detection(99, true); // ignored
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_raw_prefix_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_raw_prefix(0, true); // @
+
+        // "@as": 's' would add a tone without the prefix
+        let mut out = String::new();
+        for (key, shift, ch) in [
+            (keys::N2, true, '@'),
+            (keys::A, false, 'a'),
+            (keys::S, false, 's'),
+        ] {
+            let r = ime_key_ext(key, false, false, shift);
+            unsafe {
+                let res = &*r;
+                if res.action == 1 {
+                    for _ in 0..res.backspace {
+                        out.pop();
+                    }
+                    let chars = &res.chars[..res.count as usize];
+                    out.extend(chars.iter().filter_map(|&c| char::from_u32(c)));
+                } else {
+                    out.push(ch);
+                }
+                ime_free(r);
+            }
+        }
+        assert_eq!(out, "@as");
+
+        ime_raw_prefix(0, false);
+        ime_raw_prefix(99, true); // ignored
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_emoji_shortcodes_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_emoji_shortcodes(true);
+
+        let mut out = String::new();
+        for (key, shift, ch) in [
+            (keys::SEMICOLON, true, ':'),
+            (keys::T, false, 't'),
+            (keys::I, false, 'i'),
+            (keys::M, false, 'm'),
+            (keys::SEMICOLON, true, ':'),
+        ] {
+            let r = ime_key_ext(key, false, false, shift);
+            unsafe {
+                let res = &*r;
+                if res.action == 1 {
+                    for _ in 0..res.backspace {
+                        out.pop();
+                    }
+                    let chars = &res.chars[..res.count as usize];
+                    out.extend(chars.iter().filter_map(|&c| char::from_u32(c)));
+                } else {
+                    out.push(ch);
+                }
+                ime_free(r);
+            }
+        }
+        assert_eq!(out, "❤️");
+
+        ime_emoji_shortcodes(false);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_shortcut_pack_ffi() {
+        ime_init();
+        ime_clear_shortcuts();
+        ime_shortcut_pack(2, true); // currency
+        ime_shortcut_pack(99, true); // ignored
+
+        let mut buf = [0u32; 512];
+        let read = |buf: &[u32], len: i64| -> String {
+            buf[..len as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+                .collect()
+        };
+        let len = unsafe { ime_shortcut_packs(buf.as_mut_ptr(), buf.len() as i64) };
+        let packs = read(&buf, len);
+        assert_eq!(packs.lines().count(), 5);
+        assert_eq!(packs.lines().nth(2), Some("currency\t1"));
+        assert_eq!(packs.lines().next(), Some("arrows\t0"));
+
+        let id = CString::new("currency.vnd").unwrap();
+        let trigger = CString::new("dong").unwrap();
+        let replacement = CString::new("₫").unwrap();
+        unsafe {
+            assert!(ime_edit_pack_entry(
+                id.as_ptr(),
+                trigger.as_ptr(),
+                replacement.as_ptr()
+            ));
+        }
+        let len = unsafe { ime_shortcut_pack_entries(2, buf.as_mut_ptr(), buf.len() as i64) };
+        let entries = read(&buf, len);
+        assert_eq!(entries.lines().next(), Some("currency.vnd\tdong\t₫"));
+        {
+            let guard = lock_engine();
+            let e = guard.as_ref().unwrap();
+            assert!(e.shortcuts().lookup("dong").is_some());
+        }
+
+        unsafe {
+            assert!(ime_remove_pack_entry(id.as_ptr()));
+            assert!(ime_reset_pack_entry(id.as_ptr()));
+        }
+        let len = unsafe { ime_shortcut_pack_entries(2, buf.as_mut_ptr(), buf.len() as i64) };
+        assert!(read(&buf, len).starts_with("currency.vnd\tvnd\t₫"));
+
+        // Multi-line replacements stay on one line, three fields
+        let multiline = CString::new("₫\n\t(đồng)").unwrap();
+        unsafe {
+            assert!(ime_edit_pack_entry(
+                id.as_ptr(),
+                trigger.as_ptr(),
+                multiline.as_ptr()
+            ));
+        }
+        let len = unsafe { ime_shortcut_pack_entries(2, buf.as_mut_ptr(), buf.len() as i64) };
+        let entries = read(&buf, len);
+        let fields: Vec<&str> = entries.lines().next().unwrap().split('\t').collect();
+        assert_eq!(fields, ["currency.vnd", "dong", "₫\\n\\t(đồng)"]);
+        assert_eq!(unescape_line(fields[2]), "₫\n\t(đồng)");
+
+        ime_shortcut_pack(2, false);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_template_shortcut_ffi() {
+        ime_init();
+        ime_clear_shortcuts();
+        ime_utc_offset(0);
+
+        let trigger = CString::new("nam").unwrap();
+        let good = CString::new("{year}").unwrap();
+        let bad = CString::new("{year:yy}").unwrap();
+        unsafe {
+            assert!(!ime_add_shortcut(trigger.as_ptr(), bad.as_ptr()));
+            assert!(ime_add_shortcut(trigger.as_ptr(), good.as_ptr()));
+        }
+        {
+            let guard = lock_engine();
+            let e = guard.as_ref().unwrap();
+            assert_eq!(e.shortcuts().lookup("nam").unwrap().1.replacement, "{year}");
+        }
+
+        ime_clear_shortcuts();
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_next_output_ffi() {
+        ime_init();
+        ime_clear_shortcuts();
+        ime_frontend_caps(0x02);
+        let trigger = CString::new("sig").unwrap();
+        let replacement = CString::new("Thân,\nNam").unwrap();
+        unsafe {
+            assert!(ime_add_shortcut(trigger.as_ptr(), replacement.as_ptr()));
+        }
+
+        let mut parts = Vec::new();
+        for key in [keys::S, keys::I, keys::G] {
+            unsafe { ime_free(ime_key(key, false, false)) };
+        }
+        let mut r = ime_key(keys::SPACE, false, false);
+        loop {
+            unsafe {
+                let res = &*r;
+                assert_eq!(res.action, 1);
+                let chars = &res.chars[..res.count as usize];
+                parts.push(
+                    chars
+                        .iter()
+                        .filter_map(|&c| char::from_u32(c))
+                        .collect::<String>(),
+                );
+                let more = res.flags & 0x04 != 0;
+                ime_free(r);
+                if !more {
+                    break;
+                }
+            }
+            r = ime_next_output();
+        }
+        assert_eq!(parts, ["Thân,", "\n", "Nam "]);
+
+        // Nothing pending
+        let r = ime_next_output();
+        unsafe {
+            assert_eq!((*r).action, 0);
+            ime_free(r);
+        }
+
+        ime_frontend_caps(0);
+        ime_clear_shortcuts();
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_import_export_shortcuts_ffi() {
+        ime_init();
+        ime_clear_shortcuts();
+        let dir = std::env::temp_dir();
+        let macros = dir.join(format!("gonhanh_ffi_macros_{}.txt", std::process::id()));
+        let json = dir.join(format!("gonhanh_ffi_macros_{}.json", std::process::id()));
+        let c_macros = CString::new(macros.to_str().unwrap()).unwrap();
+        let c_json = CString::new(json.to_str().unwrap()).unwrap();
+
+        let trigger = CString::new("vn").unwrap();
+        let replacement = CString::new("VN").unwrap();
+        unsafe { ime_add_shortcut(trigger.as_ptr(), replacement.as_ptr()) };
+        std::fs::write(
+            &macros,
+            ";DO NOT DELETE THIS LINE*** version=1 ***\nvn:Việt Nam\nnosep\nhn:Hà Nội\n",
+        )
+        .unwrap();
+
+        let mut report = vec![0u32; 256];
+        let mut report_len = -1;
+        let n = unsafe {
+            ime_import_shortcuts(
+                c_macros.as_ptr(),
+                255,
+                0,
+                report.as_mut_ptr(),
+                report.len() as i64,
+                &mut report_len,
+            )
+        };
+        assert_eq!(n, 1);
+        let text: String = report[..report_len as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        assert_eq!(text, "conflict\t2\tvn\nerror\t3\tmissing_separator");
+
+        // Overwrite, no report buffer
+        let n = unsafe {
+            ime_import_shortcuts(
+                c_macros.as_ptr(),
+                0,
+                0x04,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(n, 2);
+
+        assert!(unsafe { ime_export_shortcuts(c_json.as_ptr(), 3) });
+        assert!(!unsafe { ime_export_shortcuts(c_json.as_ptr(), 9) });
+        ime_clear_shortcuts();
+        let n = unsafe {
+            ime_import_shortcuts(
+                c_json.as_ptr(),
+                255,
+                0,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(n, 2);
+        {
+            let guard = lock_engine();
+            let e = guard.as_ref().unwrap();
+            assert_eq!(
+                e.shortcuts().lookup("vn").unwrap().1.replacement,
+                "Việt Nam"
+            );
+        }
+
+        std::fs::remove_file(&macros).unwrap();
+        std::fs::remove_file(&json).unwrap();
+        let missing = unsafe {
+            ime_import_shortcuts(
+                c_json.as_ptr(),
+                3,
+                0,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        assert_eq!(missing, -1);
+        ime_clear_shortcuts();
+    }
+
+    #[test]
+    #[serial]
+    fn test_shortcut_management_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_clear_shortcuts();
+        let read = |out: &[u32], len: i64| -> String {
+            out[..len as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+                .collect()
+        };
+
+        let trigger = CString::new("VN").unwrap();
+        let replacement = CString::new("Việt Nam\n").unwrap();
+        unsafe {
+            assert!(!ime_add_shortcut_ext(
+                trigger.as_ptr(),
+                replacement.as_ptr(),
+                2,
+                0,
+                0
+            ));
+            assert!(ime_add_shortcut_ext(
+                trigger.as_ptr(),
+                replacement.as_ptr(),
+                1,
+                0,
+                1
+            ));
+        }
+
+        // Fire it once so the use count and time are set
+        for key in [keys::V, keys::N] {
+            let r = ime_key(key, true, false);
+            unsafe { ime_free(r) };
+        }
+        let r = ime_key(keys::SPACE, false, false);
+        unsafe { ime_free(r) };
+        ime_clear_all();
+
+        let mut out = vec![0u32; 256];
+        let len = unsafe { ime_get_shortcut(trigger.as_ptr(), out.as_mut_ptr(), 256) };
+        let line = read(&out, len);
+        let fields: Vec<&str> = line.split('\t').collect();
+        assert_eq!(&fields[..7], ["vn", "Việt Nam\\n", "1", "0", "1", "1", "1"]);
+        assert!(fields[7].parse::<i64>().unwrap() > 0);
+        assert_eq!(fields[8..], ["", "0"]);
+
+        // Disable, then bulk replace
+        unsafe {
+            assert!(ime_enable_shortcut(trigger.as_ptr(), false));
+            let missing = CString::new("nope").unwrap();
+            assert!(!ime_enable_shortcut(missing.as_ptr(), false));
+        }
+        let len = unsafe { ime_get_shortcut(trigger.as_ptr(), out.as_mut_ptr(), 256) };
+        assert_eq!(read(&out, len).split('\t').nth(5), Some("0"));
+
+        let bad = CString::new(r#"[{"trigger": "hn", "replacement": "{date:qq}"}]"#).unwrap();
+        let good = CString::new(r#"[{"trigger": "hn", "replacement": "Hà Nội", "case": "exact"}]"#)
+            .unwrap();
+        unsafe {
+            assert!(!ime_replace_shortcuts(bad.as_ptr()));
+            assert!(ime_replace_shortcuts(good.as_ptr()));
+        }
+        let len = unsafe { ime_shortcuts(out.as_mut_ptr(), 256) };
+        assert_eq!(read(&out, len), "hn\tHà Nội\t1\t0\t0\t1\t0\t0\t\t0");
+
+        // Triggers are escaped like replacements
+        let odd = CString::new("a\tb\\").unwrap();
+        unsafe { assert!(ime_add_shortcut(odd.as_ptr(), replacement.as_ptr())) };
+        let len = unsafe { ime_get_shortcut(odd.as_ptr(), out.as_mut_ptr(), 256) };
+        assert!(read(&out, len).starts_with("a\\tb\\\\\tViệt Nam\\n\t"));
+
+        // Too small a buffer: nothing written, minus the length needed
+        let needed = unsafe { ime_shortcuts(out.as_mut_ptr(), 256) };
+        out.fill(0);
+        assert_eq!(unsafe { ime_shortcuts(out.as_mut_ptr(), 10) }, -needed);
+        assert!(out.iter().all(|&c| c == 0));
+
+        ime_clear_shortcuts();
+    }
+
+    #[test]
+    #[serial]
+    fn test_shortcut_warnings_ffi() {
+        ime_init();
+        ime_clear_shortcuts();
+        for (trigger, replacement) in [("hoa", "Hòa"), ("vn", "Việt Nam"), ("vnd", "₫")] {
+            let trigger = CString::new(trigger).unwrap();
+            let replacement = CString::new(replacement).unwrap();
+            unsafe { ime_add_shortcut(trigger.as_ptr(), replacement.as_ptr()) };
+        }
+        let mut out = vec![0u32; 256];
+        let len = unsafe { ime_shortcut_warnings(out.as_mut_ptr(), 256) };
+        let text: String = out[..len as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        // "vn" only fires as a whole word, so "vnd" is no overlap
+        assert_eq!(text, "hoa\tvietnamese_word");
+        ime_clear_shortcuts();
+    }
+
+    #[test]
+    #[serial]
+    fn test_pattern_shortcut_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_clear_shortcuts();
+        let pattern = CString::new(r"(\d+)k").unwrap();
+        let replacement = CString::new("$1.000 đ").unwrap();
+        let missing_group = CString::new("$2").unwrap();
+        let bad = CString::new("(\\d+").unwrap();
+        unsafe {
+            assert!(!ime_add_pattern_shortcut(
+                bad.as_ptr(),
+                replacement.as_ptr(),
+                1,
+                1,
+                0
+            ));
+            assert!(!ime_add_pattern_shortcut(
+                pattern.as_ptr(),
+                missing_group.as_ptr(),
+                1,
+                1,
+                0
+            ));
+            assert!(ime_add_pattern_shortcut(
+                pattern.as_ptr(),
+                replacement.as_ptr(),
+                1,
+                1,
+                0
+            ));
+        }
+
+        for key in [keys::N5, keys::N0, keys::K] {
+            let r = ime_key(key, false, false);
+            unsafe { ime_free(r) };
+        }
+        let r = ime_key(keys::SPACE, false, false);
+        assert!(!r.is_null());
+        unsafe {
+            let res = &*r;
+            assert_eq!(res.action, engine::Action::Send as u8);
+            assert_eq!(res.backspace, 3);
+            let output: String = res.chars[..res.count as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+                .collect();
+            assert_eq!(output, "50.000 đ ");
+            ime_free(r);
+        }
+
+        let mut out = vec![0u32; 256];
+        let len = unsafe { ime_shortcuts(out.as_mut_ptr(), 256) };
+        let line: String = out[..len as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c))
+            .collect();
+        assert!(line.starts_with("(\\\\d+)k\t$1.000 đ\t1\t1\t0\t1\t1\t"));
+        assert!(line.ends_with("\t\t1"));
+        ime_clear_shortcuts();
+    }
 }
@@ -0,0 +1,328 @@
+//! Spell Checker - lint Vietnamese text
+//!
+//! Applies the rules the engine enforces while typing to finished text:
+//!
+//! - Invalid syllables (initial, final, vowel pattern, tone mark)
+//! - Spelling rules: k/c, gh/g, ngh/ng
+//! - Tone mark placement that disagrees with the configured style
+//!   (modern "hoà" vs traditional "hòa")
+//!
+//! URLs, emails, numbers, acronyms, non-Latin words and known English
+//! words are skipped. All offsets are byte offsets into the input.
+//!
+//! ```
+//! use gonhanh_core::spellcheck::{spellcheck, IssueKind};
+//!
+//! let issues = spellcheck("kà phê");
+//! assert_eq!(issues.len(), 1);
+//! assert_eq!(issues[0].kind, IssueKind::Spelling);
+//! assert_eq!(issues[0].word, "kà");
+//! ```
+
+use crate::data::{chars, english_dict, keys};
+use crate::engine::buffer::{Buffer, Char};
+use crate::engine::transform;
+use crate::engine::validation::{validate_syllable, ValidationResult};
+
+/// Kind of problem found
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueKind {
+    /// Not a Vietnamese syllable (the failing validation rule)
+    InvalidSyllable(ValidationResult),
+    /// Spelling rule violated: k/c, gh/g, ngh/ng
+    Spelling,
+    /// Tone mark on the wrong vowel for the configured style
+    TonePlacement,
+}
+
+/// One problem in the checked text
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// Human-readable reason (e.g. "c before e/i/y")
+    pub message: &'static str,
+    /// Byte offset where the offending part starts
+    pub start: usize,
+    /// Byte offset where the offending part ends (exclusive)
+    pub end: usize,
+    /// The whole word containing the issue
+    pub word: String,
+    /// Corrected word, when the fix is unambiguous
+    pub suggestion: Option<String>,
+}
+
+/// Check text using modern tone placement (hoà, thuý) - the engine default
+pub fn spellcheck(text: &str) -> Vec<Issue> {
+    spellcheck_with(text, true)
+}
+
+/// Check text with explicit tone placement style
+///
+/// # Arguments
+/// * `modern_tone` - true: "hoà", "thuý" (modern); false: "hòa", "thúy" (traditional)
+pub fn spellcheck_with(text: &str, modern_tone: bool) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for (chunk_start, chunk) in chunks(text) {
+        if is_link(chunk) {
+            continue;
+        }
+        for (word_start, word) in words(chunk) {
+            if let Some(issue) = check_word(word, chunk_start + word_start, modern_tone) {
+                issues.push(issue);
+            }
+        }
+    }
+    issues
+}
+
+/// Check one word (run of letters/digits) starting at byte offset `at`
+fn check_word(word: &str, at: usize, modern_tone: bool) -> Option<Issue> {
+    if should_skip(word) {
+        return None;
+    }
+
+    if let Err(e) = validate_syllable(word) {
+        // Foreign words typed in Latin script are not our business
+        if word.is_ascii() && english_dict::is_english_word(word) {
+            return None;
+        }
+        let kind = match e.result {
+            ValidationResult::InvalidSpelling => IssueKind::Spelling,
+            r => IssueKind::InvalidSyllable(r),
+        };
+        return Some(Issue {
+            kind,
+            message: e.reason,
+            start: at + e.start,
+            end: at + e.end,
+            word: word.to_string(),
+            suggestion: None,
+        });
+    }
+
+    let (pos, fixed) = misplaced_tone(word, modern_tone)?;
+    let (offset, c) = word.char_indices().nth(pos)?;
+    Some(Issue {
+        kind: IssueKind::TonePlacement,
+        message: if modern_tone {
+            "tone mark placement (modern style: hoà, thuý)"
+        } else {
+            "tone mark placement (traditional style: hòa, thúy)"
+        },
+        start: at + offset,
+        end: at + offset + c.len_utf8(),
+        word: word.to_string(),
+        suggestion: Some(fixed),
+    })
+}
+
+/// Find a tone mark on the wrong vowel
+///
+/// Returns the char index of the misplaced mark and the corrected word.
+/// Reuses the engine's placement rules by re-applying the mark to the word.
+fn misplaced_tone(word: &str, modern_tone: bool) -> Option<(usize, String)> {
+    let mut buf = Buffer::new();
+    let mut current = None;
+    for (i, c) in word.chars().enumerate() {
+        let p = chars::parse_char(c)?;
+        if p.mark != chars::mark::NONE {
+            current = Some((i, p.mark));
+        }
+        buf.push(Char {
+            key: p.key,
+            caps: p.caps,
+            tone: p.tone,
+            mark: p.mark,
+            stroke: p.stroke,
+        });
+    }
+
+    let (pos, mark) = current?;
+    let result = transform::apply_mark(&mut buf, mark, modern_tone);
+    let expected = result.earliest_position()?;
+    if expected == pos {
+        return None;
+    }
+    Some((pos, buf.to_full_string()))
+}
+
+/// Split on whitespace, yielding (byte offset, chunk)
+fn chunks(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(char::is_whitespace)
+        .scan(0, |offset, chunk| {
+            let start = *offset;
+            // Whitespace can be wider than a byte (NBSP, ideographic space)
+            let sep = text[start + chunk.len()..]
+                .chars()
+                .next()
+                .map_or(0, char::len_utf8);
+            *offset += chunk.len() + sep;
+            Some((start, chunk))
+        })
+        .filter(|(_, chunk)| !chunk.is_empty())
+}
+
+/// Split a chunk into runs of letters/digits, yielding (byte offset, word)
+fn words(chunk: &str) -> impl Iterator<Item = (usize, &str)> {
+    chunk
+        .split(|c: char| !c.is_alphanumeric())
+        .scan(0, |offset, word| {
+            let start = *offset;
+            // Separators are single chars of any width
+            let sep = chunk[start + word.len()..]
+                .chars()
+                .next()
+                .map_or(0, char::len_utf8);
+            *offset += word.len() + sep;
+            Some((start, word))
+        })
+        .filter(|(_, word)| !word.is_empty())
+}
+
+/// URLs, emails and domain-like chunks ("example.com")
+fn is_link(chunk: &str) -> bool {
+    let lower = chunk.to_ascii_lowercase();
+    lower.contains("://") || lower.starts_with("www.") || chunk.contains('@') || is_domain(chunk)
+}
+
+/// Bare domain: ASCII labels joined by dots, ending in a letters-only
+/// TLD ("example.com", "vnexpress.net/"); trailing punctuation is ignored
+fn is_domain(chunk: &str) -> bool {
+    let host = chunk
+        .trim_end_matches(|c: char| c.is_ascii_punctuation())
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    let labels: Vec<&str> = host.split('.').collect();
+    let Some(tld) = labels.last() else {
+        return false;
+    };
+    labels.len() >= 2
+        && tld.len() >= 2
+        && tld.chars().all(|c| c.is_ascii_alphabetic())
+        && labels
+            .iter()
+            .all(|l| !l.is_empty() && l.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+}
+
+/// Numbers, acronyms ("HTML") and words outside the Vietnamese alphabet
+fn should_skip(word: &str) -> bool {
+    let has_digit = word.chars().any(|c| c.is_ascii_digit());
+    let is_acronym = word.chars().count() >= 2 && word.chars().all(|c| c.is_ascii_uppercase());
+    let is_foreign_script = word.chars().any(|c| chars::parse_char(c).is_none());
+    // Single consonants are initials or abbreviations ("B", "đ")
+    let is_single_consonant = word.chars().count() == 1
+        && chars::parse_char(word.chars().next().unwrap_or(' '))
+            .is_some_and(|p| keys::is_consonant(p.key));
+    has_digit || is_acronym || is_foreign_script || is_single_consonant
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(text: &str) -> Vec<(IssueKind, &str)> {
+        spellcheck(text)
+            .into_iter()
+            .map(|i| (i.kind, &text[i.start..i.end]))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_text_has_no_issues() {
+        assert!(spellcheck("Tiếng Việt là ngôn ngữ của người Việt Nam.").is_empty());
+        assert!(spellcheck("Hoà bình, thuỷ chung; quốc gia!").is_empty());
+    }
+
+    #[test]
+    fn test_spelling_rules() {
+        assert_eq!(
+            kinds("kà phê, ngế, gé"),
+            vec![
+                (IssueKind::Spelling, "kà"),
+                (IssueKind::Spelling, "ngế"),
+                (IssueKind::Spelling, "gé"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_syllable_offsets() {
+        let text = "xin chàoo bạn";
+        let issues = spellcheck(text);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].word, "chàoo");
+        assert_eq!(
+            issues[0].kind,
+            IssueKind::InvalidSyllable(ValidationResult::InvalidVowelPattern)
+        );
+        assert_eq!(&text[issues[0].start..issues[0].end], "àoo");
+    }
+
+    #[test]
+    fn test_tone_placement_modern() {
+        let issues = spellcheck("hòa thúy");
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].kind, IssueKind::TonePlacement);
+        assert_eq!(issues[0].suggestion.as_deref(), Some("hoà"));
+        assert_eq!(issues[1].suggestion.as_deref(), Some("thuý"));
+    }
+
+    #[test]
+    fn test_tone_placement_traditional() {
+        assert!(spellcheck_with("hòa thúy", false).is_empty());
+        let issues = spellcheck_with("hoà", false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].suggestion.as_deref(), Some("hòa"));
+    }
+
+    #[test]
+    fn test_tone_placement_misplaced_any_style() {
+        // Wrong in both styles: the mark never goes on 'i' in "iê" or on 'a' in "ua"
+        for modern in [true, false] {
+            let issues = spellcheck_with("tíêng muà", modern);
+            let fixes: Vec<_> = issues.iter().map(|i| i.suggestion.as_deref()).collect();
+            assert_eq!(fixes, vec![Some("tiếng"), Some("mùa")]);
+        }
+    }
+
+    #[test]
+    fn test_skips_urls_numbers_english() {
+        let text = "xem https://example.com/kà, email ka@ce.vn, HTML 2024 ka1 the function";
+        assert!(spellcheck(text).is_empty(), "{:?}", spellcheck(text));
+    }
+
+    #[test]
+    fn test_skips_foreign_script() {
+        assert!(spellcheck("日本 naïve").is_empty());
+    }
+
+    #[test]
+    fn test_multibyte_separators() {
+        let text = "“kà” – ngà";
+        let issues = spellcheck(text);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(&text[issues[0].start..issues[0].end], "kà");
+    }
+
+    #[test]
+    fn test_multibyte_whitespace() {
+        let text = "a\u{a0}xyzq tooi\u{3000}kà";
+        let issues = spellcheck(text);
+        let words: Vec<&str> = issues.iter().map(|i| i.word.as_str()).collect();
+        assert_eq!(words, ["xyzq", "tooi", "kà"]);
+        // Each span lies inside its word, wherever the wide spaces are
+        for issue in &issues {
+            let at = text.find(&issue.word).unwrap();
+            assert!(at <= issue.start && issue.end <= at + issue.word.len());
+        }
+        assert_eq!(&text[issues[2].start..issues[2].end], "kà");
+    }
+
+    #[test]
+    fn test_skips_bare_domains() {
+        assert!(spellcheck("xem kaa.com, vnexpress.net/thoi-su.").is_empty());
+        // A sentence-ending dot is not a domain
+        assert_eq!(kinds("đi kà."), [(IssueKind::Spelling, "kà")]);
+    }
+}
@@ -0,0 +1,431 @@
+//! Syllable Suggestions - nearest valid Vietnamese syllables
+//!
+//! Given an invalid syllable ("ngí", "kà", "hcao") or raw keystrokes
+//! ("ngiaf"), returns valid syllables within a small edit distance, ranked
+//! by cost. Candidates are generated by edits on base letters and checked
+//! with the same phonotactic rules as the engine
+//! ([`validate_syllable`](crate::engine::validation::validate_syllable)),
+//! with tone marks re-placed by [`Phonology`](crate::data::vowel::Phonology).
+//!
+//! ## Edit costs
+//! - 1: vowel modifier (e → ê), d ↔ đ, initial spelling (c/k, g/gh, ng/ngh),
+//!   tone mark fix for stop finals (tiep → tiếp)
+//! - 2: insert, delete, substitute or swap adjacent letters
+//!
+//! Total cost is bounded by [`MAX_COST`]; inputs longer than
+//! [`MAX_INPUT_LEN`] letters get no suggestions.
+//!
+//! ```
+//! use gonhanh_core::suggest::suggest;
+//!
+//! let s = suggest("ngi", 3);
+//! assert_eq!(s[0].text, "nghi");
+//! ```
+
+use std::collections::HashMap;
+
+use crate::data::chars::{self, mark, tone};
+use crate::data::keys;
+use crate::document::VirtualDocument;
+use crate::engine::buffer::{Buffer, Char};
+use crate::engine::transform;
+use crate::engine::validation::{validate_syllable, ValidationResult};
+use crate::engine::Engine;
+
+/// Longest input (in letters) that gets suggestions
+pub const MAX_INPUT_LEN: usize = 8;
+
+/// Highest total edit cost of a suggestion
+pub const MAX_COST: u8 = 3;
+
+/// Letters of the Vietnamese alphabet (base keys, no f/j/w/z)
+const ALPHABET: &[u16] = &[
+    keys::A,
+    keys::B,
+    keys::C,
+    keys::D,
+    keys::E,
+    keys::G,
+    keys::H,
+    keys::I,
+    keys::K,
+    keys::L,
+    keys::M,
+    keys::N,
+    keys::O,
+    keys::P,
+    keys::Q,
+    keys::R,
+    keys::S,
+    keys::T,
+    keys::U,
+    keys::V,
+    keys::X,
+    keys::Y,
+];
+
+/// Initial consonant spelling swaps (from, to)
+///
+/// Mirrors `constants::SPELLING_RULES`: c/k, g/gh, ng/ngh are the same
+/// sound written differently depending on the following vowel.
+const SPELLING_SWAPS: &[(&[u16], &[u16])] = &[
+    (&[keys::C], &[keys::K]),
+    (&[keys::K], &[keys::C]),
+    (&[keys::G], &[keys::G, keys::H]),
+    (&[keys::G, keys::H], &[keys::G]),
+    (&[keys::N, keys::G], &[keys::N, keys::G, keys::H]),
+    (&[keys::N, keys::G, keys::H], &[keys::N, keys::G]),
+];
+
+/// One suggested syllable
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub text: String,
+    /// Edit cost from the input (0 = input is already valid)
+    pub cost: u8,
+}
+
+/// Base letter with its vowel modifier / stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Letter {
+    key: u16,
+    tone: u8,
+    stroke: bool,
+}
+
+/// Syllable as base letters plus a single tone mark
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Form {
+    letters: Vec<Letter>,
+    mark: u8,
+}
+
+/// Capitalization of the input, re-applied to suggestions
+#[derive(Clone, Copy)]
+enum Case {
+    Lower,
+    Title,
+    Upper,
+}
+
+/// Suggest valid syllables for `syllable` using modern tone placement
+pub fn suggest(syllable: &str, limit: usize) -> Vec<Suggestion> {
+    suggest_with(syllable, limit, true)
+}
+
+/// Suggest valid syllables with explicit tone placement style
+pub fn suggest_with(syllable: &str, limit: usize, modern_tone: bool) -> Vec<Suggestion> {
+    let Some((form, case)) = parse(syllable) else {
+        return Vec::new();
+    };
+    if form.letters.is_empty() || form.letters.len() > MAX_INPUT_LEN {
+        return Vec::new();
+    }
+
+    // Expand edits until the cost budget runs out, keeping the cheapest path
+    let mut costs: HashMap<Form, u8> = HashMap::new();
+    costs.insert(form.clone(), 0);
+    let mut frontier = vec![(form, 0u8)];
+    while let Some((f, cost)) = frontier.pop() {
+        let mut next = Vec::new();
+        edits(&f, MAX_COST - cost, &mut next);
+        for (g, step) in next {
+            let total = cost + step;
+            if total > MAX_COST {
+                continue;
+            }
+            if costs.get(&g).is_some_and(|&c| c <= total) {
+                continue;
+            }
+            costs.insert(g.clone(), total);
+            frontier.push((g, total));
+        }
+    }
+
+    let mut best: HashMap<String, u8> = HashMap::new();
+    for (f, cost) in costs {
+        for (text, extra) in render_valid(&f, modern_tone) {
+            let total = cost + extra;
+            if total <= MAX_COST {
+                let entry = best.entry(apply_case(&text, case)).or_insert(total);
+                *entry = (*entry).min(total);
+            }
+        }
+    }
+
+    let len = syllable.chars().count();
+    let mut out: Vec<Suggestion> = best
+        .into_iter()
+        .map(|(text, cost)| Suggestion { text, cost })
+        .collect();
+    out.sort_by(|a, b| {
+        a.cost
+            .cmp(&b.cost)
+            .then_with(|| len_diff(a, len).cmp(&len_diff(b, len)))
+            .then_with(|| a.text.cmp(&b.text))
+    });
+    out.truncate(limit);
+    out
+}
+
+/// Suggest valid syllables for raw keystrokes ("ngiaf", VNI "ngia2")
+///
+/// Keystrokes are composed first with validation disabled, so modifiers
+/// apply even where the engine would stop transforming.
+pub fn suggest_keys(raw: &str, method: u8, limit: usize) -> Vec<Suggestion> {
+    suggest(&compose(raw, method), limit)
+}
+
+/// Compose raw keystrokes into text, applying every modifier
+pub(crate) fn compose(raw: &str, method: u8) -> String {
+    let mut e = Engine::new();
+    e.set_method(method);
+    e.set_free_tone(true);
+    let mut doc = VirtualDocument::with_engine(e);
+    doc.type_str(raw);
+    doc.text()
+}
+
+fn len_diff(s: &Suggestion, len: usize) -> usize {
+    s.text.chars().count().abs_diff(len)
+}
+
+/// Parse Unicode text into a form, or None for non-Vietnamese letters
+fn parse(s: &str) -> Option<(Form, Case)> {
+    let mut letters = Vec::new();
+    let mut form_mark = mark::NONE;
+    let mut upper = 0;
+    for c in s.chars() {
+        let p = chars::parse_char(c)?;
+        if p.mark != mark::NONE {
+            form_mark = p.mark;
+        }
+        if p.caps {
+            upper += 1;
+        }
+        letters.push(Letter {
+            key: p.key,
+            tone: p.tone,
+            stroke: p.stroke,
+        });
+    }
+    let first_upper = s.chars().next().is_some_and(char::is_uppercase);
+    let case = match upper {
+        n if n > 1 && n == letters.len() => Case::Upper,
+        _ if first_upper => Case::Title,
+        _ => Case::Lower,
+    };
+    Some((
+        Form {
+            letters,
+            mark: form_mark,
+        },
+        case,
+    ))
+}
+
+fn apply_case(text: &str, case: Case) -> String {
+    match case {
+        Case::Lower => text.to_string(),
+        Case::Upper => text.to_uppercase(),
+        Case::Title => {
+            let mut chars = text.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+    }
+}
+
+/// Modifiers a base vowel can carry (none, circumflex, horn/breve)
+fn vowel_tones(key: u16) -> &'static [u8] {
+    match key {
+        keys::A | keys::O => &[tone::NONE, tone::CIRCUMFLEX, tone::HORN],
+        keys::E => &[tone::NONE, tone::CIRCUMFLEX],
+        keys::U => &[tone::NONE, tone::HORN],
+        _ => &[tone::NONE],
+    }
+}
+
+fn plain(key: u16) -> Letter {
+    Letter {
+        key,
+        tone: tone::NONE,
+        stroke: false,
+    }
+}
+
+/// All single edits of `f` costing at most `budget`
+fn edits(f: &Form, budget: u8, out: &mut Vec<(Form, u8)>) {
+    if budget == 0 {
+        return;
+    }
+    let n = f.letters.len();
+    let with = |letters: Vec<Letter>| Form {
+        letters,
+        mark: f.mark,
+    };
+
+    // Cost 1: vowel modifiers and d/đ
+    for (i, l) in f.letters.iter().enumerate() {
+        for &t in vowel_tones(l.key) {
+            if t != l.tone {
+                let mut letters = f.letters.clone();
+                letters[i].tone = t;
+                out.push((with(letters), 1));
+            }
+        }
+        if l.key == keys::D {
+            let mut letters = f.letters.clone();
+            letters[i].stroke = !l.stroke;
+            out.push((with(letters), 1));
+        }
+    }
+
+    // Cost 1: initial spelling swaps (not before 'h': "ch" → "kh" is a different sound)
+    let keys: Vec<u16> = f.letters.iter().map(|l| l.key).collect();
+    for &(from, to) in SPELLING_SWAPS {
+        if keys.starts_with(from) && keys.get(from.len()) != Some(&keys::H) {
+            let mut letters: Vec<Letter> = to.iter().map(|&k| plain(k)).collect();
+            letters.extend_from_slice(&f.letters[from.len()..]);
+            out.push((with(letters), 1));
+        }
+    }
+
+    // Cost 2: letter edits
+    if budget < 2 {
+        return;
+    }
+    for i in 0..n {
+        let mut letters = f.letters.clone();
+        letters.remove(i);
+        out.push((with(letters), 2));
+
+        for &k in ALPHABET {
+            if k != f.letters[i].key {
+                let mut letters = f.letters.clone();
+                letters[i] = plain(k);
+                out.push((with(letters), 2));
+            }
+        }
+
+        if i + 1 < n && f.letters[i] != f.letters[i + 1] {
+            let mut letters = f.letters.clone();
+            letters.swap(i, i + 1);
+            out.push((with(letters), 2));
+        }
+    }
+    for i in 0..=n {
+        for &k in ALPHABET {
+            let mut letters = f.letters.clone();
+            letters.insert(i, plain(k));
+            out.push((with(letters), 2));
+        }
+    }
+}
+
+/// Render a form and keep the valid spellings
+///
+/// Stop finals (c, ch, p, t) only take sắc or nặng: when that is the only
+/// problem, both marks are tried at extra cost 1 ("tiep" → "tiếp", "tiệp").
+/// Returns rendered texts with their extra cost.
+fn render_valid(f: &Form, modern_tone: bool) -> Vec<(String, u8)> {
+    if f.letters.is_empty() {
+        return Vec::new();
+    }
+    let text = render(f, f.mark, modern_tone);
+    match validate_syllable(&text) {
+        Ok(()) => vec![(text, 0)],
+        Err(e) if e.result == ValidationResult::InvalidTone => [mark::SAC, mark::NANG]
+            .into_iter()
+            .filter(|&m| m != f.mark)
+            .map(|m| render(f, m, modern_tone))
+            .filter(|t| validate_syllable(t).is_ok())
+            .map(|t| (t, 1))
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn render(f: &Form, m: u8, modern_tone: bool) -> String {
+    let mut buf = Buffer::new();
+    for l in &f.letters {
+        buf.push(Char {
+            key: l.key,
+            caps: false,
+            tone: l.tone,
+            mark: mark::NONE,
+            stroke: l.stroke,
+        });
+    }
+    if m != mark::NONE {
+        transform::apply_mark(&mut buf, m, modern_tone);
+    }
+    buf.to_full_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(input: &str, limit: usize) -> Vec<String> {
+        suggest(input, limit).into_iter().map(|s| s.text).collect()
+    }
+
+    #[test]
+    fn test_valid_input_first() {
+        let s = suggest("việt", 3);
+        assert_eq!(s[0].text, "việt");
+        assert_eq!(s[0].cost, 0);
+    }
+
+    #[test]
+    fn test_wrong_initial() {
+        assert_eq!(texts("ngi", 1), ["nghi"]);
+        assert_eq!(texts("kà", 1), ["cà"]);
+        assert_eq!(texts("ghà", 1), ["gà"]);
+        assert_eq!(texts("cẹ", 1), ["kẹ"]);
+    }
+
+    #[test]
+    fn test_missing_modifier_and_stop_final() {
+        let s = texts("tiep", 5);
+        assert!(s.contains(&"tiếp".to_string()), "{:?}", s);
+        assert!(s.contains(&"tiệp".to_string()), "{:?}", s);
+    }
+
+    #[test]
+    fn test_swapped_keys() {
+        assert!(texts("hcao", 10).contains(&"chao".to_string()));
+    }
+
+    #[test]
+    fn test_case_preserved() {
+        assert_eq!(texts("Kà", 1), ["Cà"]);
+        assert_eq!(texts("NGI", 1), ["NGHI"]);
+    }
+
+    #[test]
+    fn test_ranked_by_cost() {
+        let s = suggest("bn", 20);
+        assert!(!s.is_empty());
+        assert!(s.windows(2).all(|w| w[0].cost <= w[1].cost));
+        assert!(s.iter().all(|s| s.cost <= MAX_COST));
+    }
+
+    #[test]
+    fn test_bounded_input() {
+        assert!(suggest("", 5).is_empty());
+        assert!(suggest("nghiêngnghiêng", 5).is_empty());
+        assert!(suggest("日本", 5).is_empty());
+    }
+
+    #[test]
+    fn test_raw_keystrokes() {
+        // Telex: "ngiaf" composes to "ngìa", nearest valid is "nghìa"
+        assert_eq!(suggest_keys("ngiaf", 0, 1)[0].text, "nghìa");
+        // VNI
+        assert_eq!(suggest_keys("ngia2", 1, 1)[0].text, "nghìa");
+    }
+}
@@ -87,6 +87,91 @@ pub fn key_to_char_ext(key: u16, caps: bool, shift: bool) -> Option<char> {
     key_to_char(key, caps)
 }
 
+/// Convert a typed character to its key code and shift state (US layout)
+///
+/// Inverse of `key_to_char_ext` plus whitespace and unshifted punctuation.
+/// Letters never report shift - case is carried by the `caps` flag instead.
+/// Returns None for characters that have no key on a US keyboard (e.g. "ế").
+pub fn char_to_key_ext(c: char) -> Option<(u16, bool)> {
+    let key = match c.to_ascii_lowercase() {
+        'a' => keys::A,
+        'b' => keys::B,
+        'c' => keys::C,
+        'd' => keys::D,
+        'e' => keys::E,
+        'f' => keys::F,
+        'g' => keys::G,
+        'h' => keys::H,
+        'i' => keys::I,
+        'j' => keys::J,
+        'k' => keys::K,
+        'l' => keys::L,
+        'm' => keys::M,
+        'n' => keys::N,
+        'o' => keys::O,
+        'p' => keys::P,
+        'q' => keys::Q,
+        'r' => keys::R,
+        's' => keys::S,
+        't' => keys::T,
+        'u' => keys::U,
+        'v' => keys::V,
+        'w' => keys::W,
+        'x' => keys::X,
+        'y' => keys::Y,
+        'z' => keys::Z,
+        '0' => keys::N0,
+        '1' => keys::N1,
+        '2' => keys::N2,
+        '3' => keys::N3,
+        '4' => keys::N4,
+        '5' => keys::N5,
+        '6' => keys::N6,
+        '7' => keys::N7,
+        '8' => keys::N8,
+        '9' => keys::N9,
+        ' ' => keys::SPACE,
+        '\t' => keys::TAB,
+        '\n' => keys::RETURN,
+        '\x1b' => keys::ESC,
+        '.' => keys::DOT,
+        ',' => keys::COMMA,
+        ';' => keys::SEMICOLON,
+        '\'' => keys::QUOTE,
+        '-' => keys::MINUS,
+        '=' => keys::EQUAL,
+        '[' => keys::LBRACKET,
+        ']' => keys::RBRACKET,
+        '\\' => keys::BACKSLASH,
+        '/' => keys::SLASH,
+        '`' => keys::BACKQUOTE,
+        // Shifted symbols
+        '!' => return Some((keys::N1, true)),
+        '@' => return Some((keys::N2, true)),
+        '#' => return Some((keys::N3, true)),
+        '$' => return Some((keys::N4, true)),
+        '%' => return Some((keys::N5, true)),
+        '^' => return Some((keys::N6, true)),
+        '&' => return Some((keys::N7, true)),
+        '*' => return Some((keys::N8, true)),
+        '(' => return Some((keys::N9, true)),
+        ')' => return Some((keys::N0, true)),
+        '_' => return Some((keys::MINUS, true)),
+        '+' => return Some((keys::EQUAL, true)),
+        ':' => return Some((keys::SEMICOLON, true)),
+        '"' => return Some((keys::QUOTE, true)),
+        '<' => return Some((keys::COMMA, true)),
+        '>' => return Some((keys::DOT, true)),
+        '?' => return Some((keys::SLASH, true)),
+        '|' => return Some((keys::BACKSLASH, true)),
+        '{' => return Some((keys::LBRACKET, true)),
+        '}' => return Some((keys::RBRACKET, true)),
+        '~' => return Some((keys::BACKQUOTE, true)),
+        _ => return None,
+    };
+    Some((key, false))
+}
+
 /// Collect vowels from buffer with phonological info
 pub fn collect_vowels(buf: &Buffer) -> Vec<Vowel> {
     buf.iter()
@@ -146,6 +231,7 @@ mod test_utils {
     //! Used by `#[cfg(test)]` modules throughout the crate.
 
     use crate::data::keys;
+    use crate::document::VirtualDocument;
     use crate::engine::{Action, Engine};
 
     // ============================================================
@@ -234,113 +320,26 @@ mod test_utils {
     // ============================================================
 
     /// Simulate typing, returns screen output
+    ///
+    /// Drives a `VirtualDocument` so results are applied exactly like a frontend.
+    /// Test conventions: '<' is Backspace, '\x1b' is ESC.
     pub fn type_word(e: &mut Engine, input: &str) -> String {
-        let mut screen = String::new();
+        let mut doc = VirtualDocument::with_engine(std::mem::take(e));
         for c in input.chars() {
-            // Detect shifted symbols and get proper (key, shift) pair
-            // NOTE: '<' is NOT included here - it maps to DELETE in test utilities
-            let (key, shift) = match c {
-                '@' => (keys::N2, true),
-                '!' => (keys::N1, true),
-                '#' => (keys::N3, true),
-                '$' => (keys::N4, true),
-                '%' => (keys::N5, true),
-                '^' => (keys::N6, true),
-                '&' => (keys::N7, true),
-                '*' => (keys::N8, true),
-                '(' => (keys::N9, true),
-                ')' => (keys::N0, true),
-                '_' => (keys::MINUS, true),
-                '+' => (keys::EQUAL, true),
-                ':' => (keys::SEMICOLON, true),
-                '"' => (keys::QUOTE, true),
-                '>' => (keys::DOT, true),
-                '?' => (keys::SLASH, true),
-                '|' => (keys::BACKSLASH, true),
-                '{' => (keys::LBRACKET, true),
-                '}' => (keys::RBRACKET, true),
-                '~' => (keys::BACKQUOTE, true),
-                _ => (char_to_key(c), false),
-            };
-            let is_caps = c.is_uppercase();
-
-            if key == keys::DELETE {
-                let r = e.on_key_ext(key, false, false, false);
-                if r.action == Action::Send as u8 {
-                    // Restore from history - apply backspaces and replacement
-                    for _ in 0..r.backspace {
-                        screen.pop();
-                    }
-                    for i in 0..r.count as usize {
-                        if let Some(ch) = char::from_u32(r.chars[i]) {
-                            screen.push(ch);
-                        }
-                    }
-                } else {
-                    // Normal backspace - just remove last char
-                    screen.pop();
-                }
-                continue;
-            }
-
-            // ESC key: restore to raw ASCII
-            if key == keys::ESC {
-                let r = e.on_key_ext(key, false, false, false);
-                if r.action == Action::Send as u8 {
-                    for _ in 0..r.backspace {
-                        screen.pop();
-                    }
-                    for i in 0..r.count as usize {
-                        if let Some(ch) = char::from_u32(r.chars[i]) {
-                            screen.push(ch);
-                        }
-                    }
-                }
-                continue;
-            }
-
-            if key == keys::SPACE {
-                // Space can trigger shortcuts - process result
-                let r = e.on_key_ext(key, false, false, false);
-                if r.action == Action::Send as u8 {
-                    // Shortcut triggered - apply backspaces and replacement
-                    for _ in 0..r.backspace {
-                        screen.pop();
-                    }
-                    for i in 0..r.count as usize {
-                        if let Some(ch) = char::from_u32(r.chars[i]) {
-                            screen.push(ch);
-                        }
-                    }
-                } else {
-                    // No shortcut - just add space
-                    screen.push(' ');
+            match c {
+                '<' => {
+                    doc.backspace();
                 }
-                continue;
-            }
-
-            let r = e.on_key_ext(key, is_caps, false, shift);
-            if r.action == Action::Send as u8 {
-                for _ in 0..r.backspace {
-                    screen.pop();
+                '\x1b' => {
+                    doc.escape();
                 }
-                for i in 0..r.count as usize {
-                    if let Some(ch) = char::from_u32(r.chars[i]) {
-                        screen.push(ch);
-                    }
+                _ => {
+                    doc.type_char(c);
                 }
-                // For break keys (punctuation), add the character after auto-restore
-                // The restored text doesn't include the break character
-                // Use is_break_ext to handle shifted symbols like @, !, #, etc.
-                // BUT: if key_consumed flag is set (shortcut match), don't add the char
-                if keys::is_break_ext(key, shift) && !r.key_consumed() {
-                    screen.push(c);
-                }
-            } else {
-                // Pass through if not handled (mimic editor receiving char)
-                screen.push(c);
             }
         }
+        let screen = doc.text();
+        *e = doc.into_engine();
         screen
     }
 
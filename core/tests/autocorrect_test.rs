@@ -0,0 +1,124 @@
+//! Autocorrect Tests - Testing autocorrect option
+//!
+//! Common misspellings are fixed at word commit (Space, punctuation) when
+//! autocorrect is enabled. Backspace right after the space undoes the fix.
+
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with autocorrect enabled
+fn telex_autocorrect(cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        e.set_autocorrect(true);
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex Autocorrect] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+// ============================================================
+// CORRECTIONS
+// ============================================================
+
+#[test]
+fn two_word_corrections() {
+    telex_autocorrect(&[
+        ("xuaats xawcs ", "xuất sắc "),
+        ("suwax chuwax ", "sửa chữa "),
+        ("suwr lys ", "xử lý "),
+        ("chaan trongj ", "trân trọng "),
+        ("giups ddowr ", "giúp đỡ "),
+    ]);
+}
+
+#[test]
+fn single_word_corrections() {
+    telex_autocorrect(&[("nhuwngr ", "những "), ("cos nhuwngr ", "có những ")]);
+}
+
+#[test]
+fn correction_matches_case() {
+    telex_autocorrect(&[("Xuaats xawcs ", "Xuất sắc "), ("NHUWNGR ", "NHỮNG ")]);
+}
+
+#[test]
+fn correction_on_punctuation() {
+    telex_autocorrect(&[("xuaats xawcs.", "xuất sắc."), ("nhuwngr,", "những,")]);
+}
+
+#[test]
+fn no_correction_across_two_spaces() {
+    telex_autocorrect(&[("xuaats  xawcs ", "xuất  xắc ")]);
+}
+
+#[test]
+fn correct_words_unchanged() {
+    telex_autocorrect(&[("xuaats sawcs ", "xuất sắc "), ("nhuwngx ", "những ")]);
+}
+
+// ============================================================
+// UNDO
+// ============================================================
+
+#[test]
+fn backspace_after_space_undoes_correction() {
+    telex_autocorrect(&[
+        ("nhuwngr <", "nhửng"),
+        ("xuaats xawcs <", "xuất xắc"),
+        // Undone word is kept on the next commit
+        ("nhuwngr < ", "nhửng "),
+        ("xuaats xawcs < ", "xuất xắc "),
+    ]);
+}
+
+#[test]
+fn undo_only_for_latest_word() {
+    telex_autocorrect(&[("nhuwngr vaf <", "những và")]);
+}
+
+// ============================================================
+// OPTIONS
+// ============================================================
+
+#[test]
+fn disabled_by_default() {
+    let mut e = Engine::new();
+    assert_eq!(type_word(&mut e, "xuaats xawcs "), "xuất xắc ");
+}
+
+#[test]
+fn user_entries() {
+    let mut e = Engine::new();
+    e.set_autocorrect(true);
+    e.autocorrect_mut().add("hok", "không");
+    // Mapping to itself disables a bundled correction
+    e.autocorrect_mut().add("nhửng", "nhửng");
+    assert_eq!(type_word(&mut e, "hok nhuwngr "), "không nhửng ");
+}
+
+#[test]
+fn shortcut_takes_priority() {
+    let mut e = Engine::new();
+    e.set_autocorrect(true);
+    e.shortcuts_mut()
+        .add(gonhanh_core::engine::shortcut::Shortcut::new(
+            "nhửng",
+            "nhửng nhửng",
+        ));
+    assert_eq!(type_word(&mut e, "nhuwngr "), "nhửng nhửng ");
+}
+
+#[test]
+fn phrase_too_long_to_erase_is_skipped() {
+    // 255 + 1 + 2 chars: more than one result can backspace
+    let long = "b".repeat(255);
+    let mut e = Engine::new();
+    e.set_autocorrect(true);
+    e.autocorrect_mut().add(&format!("{} đi", long), "x");
+    let input = format!("{} ddi ", long);
+    assert_eq!(type_word(&mut e, &input), format!("{} đi ", long));
+}
@@ -0,0 +1,109 @@
+//! Blocklist Tests - words typed as raw ASCII
+//!
+//! Once the typed keys match a blocklisted prefix, the word is put back as
+//! raw ASCII and the rest of it is typed without transforms. Whole-word
+//! entries are restored when the word ends.
+
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run tests with blocklist entries (Telex unless `vni`)
+fn blocked(entries: &[&str], vni: bool, cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        if vni {
+            e.set_method(1);
+        }
+        for entry in entries {
+            e.blocklist_mut().add(entry);
+        }
+        let result = type_word(&mut e, input);
+        assert_eq!(result, *expected, "[Blocklist] '{}' → '{}'", input, result);
+    }
+}
+
+#[test]
+fn without_blocklist_words_are_mangled() {
+    blocked(
+        &[],
+        false,
+        &[("dotfiles ", "dòtiles "), ("vscode ", "vscode ")],
+    );
+}
+
+#[test]
+fn whole_word_restored_when_complete() {
+    blocked(
+        &["dotfiles", "pnpm"],
+        false,
+        &[
+            ("dotfiles ", "dotfiles "),
+            ("pnpm ", "pnpm "),
+            ("Dotfiles ", "Dotfiles "),
+        ],
+    );
+}
+
+#[test]
+fn prefix_stops_transforms_early() {
+    blocked(
+        &["dotf*"],
+        false,
+        &[
+            ("dotf", "dotf"),
+            ("dotfiles ", "dotfiles "),
+            // Mark keys after the match stay letters
+            ("dotfsss ", "dotfsss "),
+        ],
+    );
+}
+
+#[test]
+fn other_words_still_transform() {
+    blocked(
+        &["dotf*", "pnpm"],
+        false,
+        &[
+            ("dos ", "dó "),
+            ("vieetj ", "việt "),
+            ("dotfiles vieetj ", "dotfiles việt "),
+        ],
+    );
+}
+
+#[test]
+fn backspace_in_blocked_word() {
+    blocked(
+        &["dotf*"],
+        false,
+        &[("dotfi<<s", "dots"), ("dotf<<<<vieetj", "việt")],
+    );
+}
+
+#[test]
+fn works_with_vni() {
+    blocked(&["v8js"], true, &[("v8js ", "v8js ")]);
+}
+
+#[test]
+fn works_with_auto_restore() {
+    let mut e = Engine::new();
+    e.set_english_auto_restore(true);
+    e.blocklist_mut().add("dotf*");
+    assert_eq!(type_word(&mut e, "dotfiles "), "dotfiles ");
+}
+
+#[test]
+fn whole_word_entry_does_not_stick_to_longer_words() {
+    blocked(
+        &["go", "dd"],
+        false,
+        &[
+            ("go ", "go "),
+            ("gocs ", "góc "),
+            ("gos ", "gó "),
+            ("dd ", "dd "),
+            ("ddi ", "đi "),
+        ],
+    );
+}
@@ -0,0 +1,170 @@
+//! Candidate List Tests - selection keys while a list is open
+//!
+//! Prediction publishes its candidates to a passive list: keys type as
+//! usual until Tab activates it. Then digits select on the current page,
+//! Up/Down move the highlight and Enter accepts it.
+
+use gonhanh_core::data::keys;
+use gonhanh_core::document::VirtualDocument;
+use gonhanh_core::engine::Engine;
+
+/// Document with prediction enabled and `page_size` candidates per page
+fn doc(page_size: usize) -> VirtualDocument {
+    let mut e = Engine::new();
+    e.set_prediction(true);
+    e.set_candidate_page_size(page_size);
+    VirtualDocument::with_engine(e)
+}
+
+fn page(doc: &VirtualDocument) -> Vec<(String, String)> {
+    doc.engine()
+        .candidate_list()
+        .page_items()
+        .map(|(label, text)| (label.to_string(), text.to_string()))
+        .collect()
+}
+
+#[test]
+fn list_follows_typing() {
+    let mut d = doc(9);
+    d.type_str("chuyee");
+    assert!(d.engine().candidate_list().is_open());
+    d.type_str("r");
+    assert_eq!(page(&d), vec![("1".to_string(), "chuyển".to_string())]);
+}
+
+#[test]
+fn numbers_after_predicted_word_are_typed() {
+    let mut d = doc(9);
+    d.type_str("nawm ");
+    assert!(d.engine().candidate_list().is_open());
+    d.type_str("2024 ");
+    assert_eq!(d.text(), "năm 2024 ");
+}
+
+#[test]
+fn passive_list_leaves_caret_keys_alone() {
+    let mut d = doc(2);
+    d.type_str("chuyee");
+    for key in [keys::DOWN, keys::UP, keys::PAGE_DOWN, keys::PAGE_UP] {
+        assert!(!d.key(key, false, false).key_consumed);
+    }
+    assert_eq!(d.engine().candidate_list().highlighted(), None);
+}
+
+#[test]
+fn tab_activates_and_highlights_first() {
+    let mut d = doc(3);
+    d.type_str("chuyee");
+    let first = page(&d)[0].1.clone();
+    assert!(d.key(keys::TAB, false, false).key_consumed);
+    assert_eq!(d.engine().candidate_list().highlighted(), Some(0));
+    d.key(keys::TAB, false, false);
+    assert_eq!(d.text(), format!("{} ", first));
+}
+
+#[test]
+fn digit_selects_on_current_page() {
+    let mut d = doc(3);
+    d.type_str("chuyee");
+    assert!(d.engine_mut().activate_candidates());
+    let second = page(&d)[1].1.clone();
+    let edit = d.type_char('2').clone();
+    assert!(edit.key_consumed);
+    assert_eq!(d.text(), format!("{} ", second));
+}
+
+#[test]
+fn digit_without_label_is_typed() {
+    let mut d = doc(9);
+    d.type_str("chuyeer");
+    d.engine_mut().activate_candidates();
+    d.type_char('5');
+    assert_eq!(d.text(), "chuyể5");
+}
+
+#[test]
+fn arrows_highlight_and_enter_accepts() {
+    let mut d = doc(3);
+    d.type_str("chuyee");
+    let items = d.engine().candidate_list().items().to_vec();
+
+    // Tab highlights the first item; Down past the first page moves to page 2
+    d.key(keys::TAB, false, false);
+    for _ in 0..3 {
+        assert!(d.key(keys::DOWN, false, false).key_consumed);
+    }
+    let list = d.engine().candidate_list();
+    assert_eq!(list.page(), 1);
+    assert_eq!(list.highlighted(), Some(0));
+
+    d.key(keys::RETURN, false, false);
+    assert_eq!(d.text(), format!("{} ", items[3]));
+}
+
+#[test]
+fn enter_without_highlight_passes_through() {
+    let mut d = doc(9);
+    d.type_str("chuyee");
+    assert!(!d.key(keys::RETURN, false, false).key_consumed);
+    assert!(!d.engine().candidate_list().is_open());
+}
+
+#[test]
+fn page_keys_turn_pages() {
+    let mut d = doc(2);
+    d.type_str("chuyee");
+    let items = d.engine().candidate_list().items().to_vec();
+    d.engine_mut().activate_candidates();
+    d.key(keys::PAGE_DOWN, false, false);
+    assert_eq!(page(&d)[0].1, items[2]);
+    d.key(keys::PAGE_UP, false, false);
+    assert_eq!(page(&d)[0].1, items[0]);
+    assert_eq!(d.text(), "chuyê");
+}
+
+#[test]
+fn esc_closes_highlighted_list_only() {
+    let mut d = doc(9);
+    d.type_str("chuyee");
+    d.key(keys::TAB, false, false);
+    assert!(d.escape().key_consumed);
+    assert!(!d.engine().candidate_list().is_open());
+    assert_eq!(d.text(), "chuyê");
+}
+
+#[test]
+fn vni_digits_stay_tone_keys_and_numbers() {
+    let mut e = Engine::new();
+    e.set_method(1);
+    e.set_prediction(true);
+    let mut d = VirtualDocument::with_engine(e);
+    d.type_str("chuye6n");
+    assert_eq!(d.text(), "chuyên");
+
+    // Digits after the committed word are typed, not selections
+    d.type_str(" 12 ");
+    assert_eq!(d.text(), "chuyên 12 ");
+
+    // Once activated, digits select predictions
+    d.type_str("chuye6n ");
+    let first = page(&d)[0].1.clone();
+    d.key(keys::TAB, false, false);
+    d.type_char('1');
+    assert_eq!(d.text(), format!("chuyên 12 chuyên {} ", first));
+}
+
+#[test]
+fn custom_list_replaces_current_word() {
+    let mut e = Engine::new();
+    e.show_candidates(vec!["😀".to_string()]);
+    let mut d = VirtualDocument::with_engine(e);
+    // Any non-selection key closes a custom list
+    d.type_str("a");
+    assert!(!d.engine().candidate_list().is_open());
+
+    d.engine_mut()
+        .show_candidates(vec!["😀".to_string(), "😃".to_string()]);
+    d.type_char('2');
+    assert_eq!(d.text(), "😃");
+}
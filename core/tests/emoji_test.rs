@@ -0,0 +1,81 @@
+//! Emoji Shortcode Tests - ":smile:" → 😄 on the closing colon
+//!
+//! Works with the IME on or off; Vietnamese aliases are typed without marks.
+
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with emoji shortcodes enabled
+fn telex_emoji(enabled: bool, cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        e.set_emoji_shortcodes(true);
+        e.set_enabled(enabled);
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex Emoji] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut e = Engine::new();
+    assert_eq!(type_word(&mut e, ":smile: "), ":smile: ");
+}
+
+#[test]
+fn common_shortcodes() {
+    telex_emoji(
+        true,
+        &[
+            (":smile:", "😄"),
+            (":+1:", "👍"),
+            (":heart: ", "❤️ "),
+            (":fire::100:", "🔥💯"),
+        ],
+    );
+}
+
+#[test]
+fn vietnamese_aliases() {
+    telex_emoji(
+        true,
+        &[
+            (":tim:", "❤️"),
+            (":cuoi:", "😄"),
+            (":cam_on:", "🙏"),
+            (":vn:", "🇻🇳"),
+        ],
+    );
+}
+
+#[test]
+fn ime_disabled() {
+    telex_emoji(false, &[(":smile:", "😄"), ("ok :+1: ", "ok 👍 ")]);
+}
+
+#[test]
+fn vietnamese_around_shortcode() {
+    telex_emoji(
+        true,
+        &[
+            ("camr own :cam_on: nhes ", "cảm ơn 🙏 nhé "),
+            (":tim: vieetj ", "❤️ việt "),
+        ],
+    );
+}
+
+#[test]
+fn unknown_names_unchanged() {
+    telex_emoji(
+        true,
+        &[
+            (":khongco: ", ":khongco: "),
+            ("10:30: ", "10:30: "),
+            (":a b: ", ":a b: "),
+        ],
+    );
+}
@@ -180,3 +180,40 @@ fn english_100k_failures() {
         MIN_PASS_RATE
     );
 }
+
+#[test]
+fn english_100k_with_classifier() {
+    let content =
+        fs::read_to_string("tests/data/english_100k.txt").expect("Failed to read english_100k.txt");
+
+    let words: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|w| !w.is_empty() && w.chars().all(|c| c.is_ascii_alphabetic()))
+        .collect();
+
+    let mut engine = Engine::new();
+    engine.set_method(0); // Telex
+    engine.set_english_auto_restore(true);
+    engine.set_english_classifier(true);
+
+    let failures = words
+        .iter()
+        .filter(|word| type_word_with_space(&mut engine, word) != format!("{} ", word))
+        .count();
+
+    let pass_rate = (words.len() - failures) as f64 / words.len() as f64 * 100.0;
+    println!("\n=== ENGLISH 100K WITH CLASSIFIER ===\n");
+    println!("Failed words: {}", failures);
+    println!("Success rate: {:.2}%", pass_rate);
+
+    // The classifier is trained on this list; every 10th word held out
+    // (train-ngram-classifier.js --holdout) passes at 98.36%
+    const MIN_PASS_RATE: f64 = 98.4;
+    assert!(
+        pass_rate >= MIN_PASS_RATE,
+        "English 100k pass rate with classifier {:.2}% is below threshold {:.1}%",
+        pass_rate,
+        MIN_PASS_RATE
+    );
+}
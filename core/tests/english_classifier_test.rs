@@ -0,0 +1,77 @@
+//! English Classifier Tests - n-gram fallback for auto-restore
+//!
+//! When the dictionary and phonology rules keep a transformed word, the
+//! classifier restores it if the keystrokes look far more English than
+//! Vietnamese Telex.
+
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with auto-restore and the classifier
+fn telex_classifier(enabled: bool, threshold: Option<f32>, cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        e.set_english_auto_restore(true);
+        e.set_english_classifier(enabled);
+        if let Some(t) = threshold {
+            e.set_english_classifier_threshold(t);
+        }
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex Classifier] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("defend ", "defend "),
+    ("peruse ", "peruse "),
+    ("doorway ", "doorway "),
+    ("honors ", "honors "),
+    ("basics ", "basics "),
+    ("mosses ", "mosses "),
+];
+
+#[test]
+fn disabled_keeps_rule_result() {
+    telex_classifier(false, None, &[("honors ", "hốn "), ("defend ", "đền ")]);
+}
+
+#[test]
+fn restores_english_words() {
+    telex_classifier(true, None, ENGLISH);
+}
+
+#[test]
+fn keeps_vietnamese_words() {
+    telex_classifier(
+        true,
+        None,
+        &[
+            ("vieejt ", "việt "),
+            ("nguwowfi ", "người "),
+            ("thuongw ", "thương "),
+            ("dduowcj ", "được "),
+            ("khoong ", "không "),
+            ("truwowngf ", "trường "),
+            ("des ", "dé "),
+        ],
+    );
+}
+
+#[test]
+fn threshold_is_configurable() {
+    telex_classifier(true, Some(100.0), &[("defend ", "đền ")]);
+    telex_classifier(true, Some(-100.0), &[("vieejt ", "vieejt ")]);
+}
+
+#[test]
+fn removed_words_are_not_restored() {
+    let mut e = Engine::new();
+    e.set_english_auto_restore(true);
+    e.set_english_classifier(true);
+    e.user_dictionary_mut().remove("defend");
+    assert_eq!(type_word(&mut e, "defend "), "đền ");
+}
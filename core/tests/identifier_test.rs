@@ -0,0 +1,105 @@
+//! Identifier Tests - code-like tokens typed without transforms
+//!
+//! camelCase, snake_case, paths, URLs and emails keep their raw keys once
+//! the token shape is recognized; Vietnamese before and after still works.
+
+use gonhanh_core::engine::identifier::IdentifierKind;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with the given identifier kinds enabled
+fn telex_identifiers(kinds: &[IdentifierKind], cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        for &kind in kinds {
+            e.set_identifier_detection(kind, true);
+        }
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex Identifier] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+#[test]
+fn disabled_by_default() {
+    telex_identifiers(&[], &[("user_ids ", "uẻ_ids "), ("isValid ", "íValid ")]);
+}
+
+#[test]
+fn camel_case() {
+    telex_identifiers(
+        &[IdentifierKind::CamelCase],
+        &[
+            ("getUserName ", "getUserName "),
+            ("isValid ", "isValid "),
+            ("toString ", "toString "),
+            ("Vieejt Nam ", "Việt Nam "),
+        ],
+    );
+}
+
+#[test]
+fn snake_case() {
+    telex_identifiers(
+        &[IdentifierKind::SnakeCase],
+        &[
+            ("user_ids ", "user_ids "),
+            ("MAX_SIZE ", "MAX_SIZE "),
+            ("_nghieeng_ ", "_nghiêng_ "),
+        ],
+    );
+}
+
+#[test]
+fn paths() {
+    telex_identifiers(
+        &[IdentifierKind::Path],
+        &[
+            ("/usr/local/bin ", "/usr/local/bin "),
+            ("~/dotfiles ", "~/dotfiles "),
+            ("src/engine/mod.rs ", "src/engine/mod.rs "),
+            ("vaf/hoawcj ", "và/hoặc "),
+        ],
+    );
+}
+
+#[test]
+fn urls_and_emails() {
+    telex_identifiers(
+        &[IdentifierKind::Url, IdentifierKind::Email],
+        &[
+            ("https://forum.dev/users ", "https://forum.dev/users "),
+            ("www.hoosting.com ", "www.hoosting.com "),
+            ("foo@bars.com ", "foo@bars.com "),
+        ],
+    );
+}
+
+#[test]
+fn vietnamese_resumes_after_token() {
+    telex_identifiers(
+        &[IdentifierKind::SnakeCase, IdentifierKind::Email],
+        &[
+            ("user_ids laf ", "user_ids là "),
+            ("foo@bars.com ddaay ", "foo@bars.com đây "),
+        ],
+    );
+}
+
+#[test]
+fn categories_are_independent() {
+    telex_identifiers(&[IdentifierKind::CamelCase], &[("user_ids ", "uẻ_ids ")]);
+}
+
+#[test]
+fn no_shortcut_inside_identifier() {
+    let mut e = Engine::new();
+    e.set_identifier_detection(IdentifierKind::SnakeCase, true);
+    e.shortcuts_mut()
+        .add(gonhanh_core::engine::shortcut::Shortcut::new("ko", "không"));
+    assert_eq!(type_word(&mut e, "user_ko "), "user_ko ");
+    assert_eq!(type_word(&mut e, "ko "), "không ");
+}
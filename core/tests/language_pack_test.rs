@@ -0,0 +1,94 @@
+//! Language Pack Tests - foreign word lists for auto-restore
+//!
+//! French, German, Spanish and Latin words are restored like English ones
+//! once their pack is enabled, unless Telex produced a Vietnamese word.
+
+use gonhanh_core::data::language_pack::LanguagePack;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with auto-restore and the given extra packs
+fn telex_packs(packs: &[LanguagePack], cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        e.set_english_auto_restore(true);
+        for &pack in packs {
+            e.set_language_pack(pack, true);
+        }
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex Packs] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+#[test]
+fn packs_disabled_by_default() {
+    telex_packs(
+        &[],
+        &[
+            ("tasse ", "tase "),
+            ("heure ", "hểu "),
+            ("coffea ", "cofea "),
+        ],
+    );
+}
+
+#[test]
+fn french() {
+    telex_packs(
+        &[LanguagePack::French],
+        &[
+            ("adresse ", "adresse "),
+            ("essayer ", "essayer "),
+            ("heure ", "heure "),
+            ("penser ", "penser "),
+            ("arriver ", "arriver "),
+        ],
+    );
+}
+
+#[test]
+fn german_spanish_latin() {
+    telex_packs(
+        &[LanguagePack::German],
+        &[("tasse ", "tasse "), ("muessen ", "muessen ")],
+    );
+    telex_packs(
+        &[LanguagePack::Spanish],
+        &[("perro ", "perro "), ("correr ", "correr ")],
+    );
+    telex_packs(
+        &[LanguagePack::Latin],
+        &[("coffea ", "coffea "), ("rosa ", "rosa ")],
+    );
+}
+
+#[test]
+fn packs_are_independent() {
+    telex_packs(
+        &[LanguagePack::French],
+        &[("tasse ", "tase "), ("perro ", "pero ")],
+    );
+}
+
+#[test]
+fn vietnamese_words_win() {
+    telex_packs(
+        &[LanguagePack::French, LanguagePack::Spanish],
+        &[("cas ", "cá "), ("nos ", "nó "), ("mais ", "mái ")],
+    );
+}
+
+#[test]
+fn english_pack_can_be_disabled() {
+    let mut e = Engine::new();
+    e.set_english_auto_restore(true);
+    assert!(e.language_pack_enabled(LanguagePack::English));
+    assert_eq!(type_word(&mut e, "issue "), "issue ");
+    e.set_language_pack(LanguagePack::English, false);
+    assert!(!e.language_pack_enabled(LanguagePack::English));
+    assert_eq!(type_word(&mut e, "issue "), "isue ");
+}
@@ -0,0 +1,140 @@
+//! Prediction Tests - word completion and next-word candidates
+//!
+//! Candidates come from the bundled frequency list plus words learned while
+//! typing. Accepting a candidate replaces the current word and adds a space.
+
+use gonhanh_core::document::VirtualDocument;
+use gonhanh_core::engine::Engine;
+
+/// Document with a Telex engine and prediction enabled
+fn doc() -> VirtualDocument {
+    let mut e = Engine::new();
+    e.set_prediction(true);
+    VirtualDocument::with_engine(e)
+}
+
+fn candidates(doc: &VirtualDocument, limit: usize) -> Vec<String> {
+    doc.engine()
+        .candidates(limit)
+        .into_iter()
+        .map(|c| c.text)
+        .collect()
+}
+
+// ============================================================
+// COMPLETION
+// ============================================================
+
+#[test]
+fn completes_partial_word() {
+    let mut d = doc();
+    d.type_str("chuyee");
+    let list = candidates(&d, 10);
+    for w in ["chuyên", "chuyển", "chuyện"] {
+        assert!(list.contains(&w.to_string()), "missing {} in {:?}", w, list);
+    }
+}
+
+#[test]
+fn typed_mark_narrows_candidates() {
+    let mut d = doc();
+    d.type_str("chuyeer");
+    assert_eq!(candidates(&d, 10), vec!["chuyển"]);
+}
+
+#[test]
+fn completion_matches_case() {
+    let mut d = doc();
+    d.type_str("Chuyeer");
+    assert_eq!(candidates(&d, 10), vec!["Chuyển"]);
+}
+
+#[test]
+fn accept_replaces_word_and_adds_space() {
+    let mut d = doc();
+    d.type_str("xin chuyeer");
+    let edit = d.accept_candidate(0).clone();
+    assert_eq!(edit.removed, "chuyể");
+    assert_eq!(edit.inserted, "chuyển ");
+    assert_eq!(d.text(), "xin chuyển ");
+}
+
+#[test]
+fn accept_out_of_range_is_noop() {
+    let mut d = doc();
+    d.type_str("chuyeer");
+    assert!(d.accept_candidate(5).is_noop());
+    assert_eq!(d.text(), "chuyể");
+}
+
+#[test]
+fn backspace_after_accept_restores_word() {
+    let mut d = doc();
+    d.type_str("chuyeer");
+    d.accept_candidate(0);
+    d.backspace();
+    d.type_char('n');
+    assert_eq!(d.text(), "chuyểnn");
+}
+
+#[test]
+fn disabled_has_no_candidates() {
+    let mut d = VirtualDocument::new();
+    d.type_str("chuyee");
+    assert!(candidates(&d, 10).is_empty());
+    assert!(d.accept_candidate(0).is_noop());
+}
+
+// ============================================================
+// NEXT-WORD PREDICTION
+// ============================================================
+
+#[test]
+fn predicts_after_committed_word() {
+    let mut d = doc();
+    d.type_str("chuyeen ");
+    assert!(candidates(&d, 10).contains(&"môn".to_string()));
+
+    // Only right after the word (one space)
+    d.type_str(" ");
+    assert!(candidates(&d, 10).is_empty());
+}
+
+#[test]
+fn accept_prediction_inserts_without_backspace() {
+    let mut d = doc();
+    d.type_str("chuyeen ");
+    let list = candidates(&d, 10);
+    let i = list.iter().position(|w| w == "môn").unwrap();
+    d.accept_candidate(i);
+    assert_eq!(d.text(), "chuyên môn ");
+}
+
+// ============================================================
+// LEARNING
+// ============================================================
+
+#[test]
+fn learns_typed_words() {
+    let mut d = doc();
+    for _ in 0..20 {
+        d.type_str("xin chuyeenj ");
+    }
+    d.type_str("xin chuyee");
+    assert_eq!(candidates(&d, 1), vec!["chuyện"]);
+
+    d.type_str(" ");
+    d.type_str("xin ");
+    assert_eq!(candidates(&d, 1), vec!["chuyện"]);
+}
+
+#[test]
+fn english_words_not_learned() {
+    let mut e = Engine::new();
+    e.set_prediction(true);
+    e.set_english_auto_restore(true);
+    let mut d = VirtualDocument::with_engine(e);
+    d.type_str("text ");
+    assert_eq!(d.text(), "text ");
+    assert_eq!(d.engine().predictor().learned_len(), 0);
+}
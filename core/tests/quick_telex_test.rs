@@ -0,0 +1,169 @@
+//! Quick Telex Tests - Testing quick_telex option
+//!
+//! Doubled consonants expand to digraphs (cc→ch, gg→gi, kk→kh, nn→ng,
+//! pp→ph, qq→qu, tt→th) when the quick_telex option is enabled.
+
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with Quick Telex enabled
+fn telex_quick(cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        e.set_quick_telex(true);
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex QuickTelex] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+/// Helper to run telex tests with Quick Telex DISABLED (default)
+fn telex_no_quick(cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex NoQuickTelex] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+/// Helper to run telex tests with Quick Telex and English auto-restore enabled
+fn telex_quick_auto_restore(cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        e.set_quick_telex(true);
+        e.set_english_auto_restore(true);
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex QuickTelex AutoRestore] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+// ============================================================
+// INITIAL CONSONANTS
+// ============================================================
+
+#[test]
+fn initial_digraphs() {
+    telex_quick(&[
+        ("cca", "cha"),
+        ("ggaf", "già"),
+        ("kkoong", "không"),
+        ("nnay", "ngay"),
+        ("ppos", "phó"),
+        ("qqa", "qua"),
+        ("ttuw", "thư"),
+    ]);
+}
+
+#[test]
+fn initial_digraphs_uppercase() {
+    telex_quick(&[("CCa", "CHa"), ("Nnay", "Ngay"), ("TTUW", "THƯ")]);
+}
+
+#[test]
+fn initial_digraphs_full_words() {
+    telex_quick(&[
+        ("ccaof", "chào"),
+        ("nnuwowif", "người"),
+        ("ttuwowngf", "thường"),
+        ("ppaanf", "phần"),
+    ]);
+}
+
+// ============================================================
+// FINAL CONSONANTS
+// ============================================================
+
+#[test]
+fn final_digraphs() {
+    telex_quick(&[
+        ("bann", "bang"),
+        ("sacc", "sach"),
+        ("tinn", "ting"),
+        ("hoafnn", "hoàng"),
+        ("vieetjnn", "việtnn"),
+    ]);
+}
+
+#[test]
+fn final_invalid_not_expanded() {
+    // "tt" final → "th" is not a Vietnamese final
+    telex_quick(&[("matt", "matt"), ("capp", "capp"), ("bakk", "bakk")]);
+}
+
+// ============================================================
+// REVERT
+// ============================================================
+
+#[test]
+fn third_press_reverts() {
+    telex_quick(&[
+        ("ccc", "cc"),
+        ("nnn", "nn"),
+        ("cccc", "ccc"),
+        ("ccca", "cca"),
+        ("bannn", "bann"),
+    ]);
+}
+
+#[test]
+fn backspace_after_expansion() {
+    telex_quick(&[("cc<a", "ca"), ("bann<g", "bang")]);
+}
+
+// ============================================================
+// DISABLED (DEFAULT)
+// ============================================================
+
+#[test]
+fn disabled_by_default() {
+    telex_no_quick(&[("cca", "cca"), ("nnay", "nnay"), ("bann", "bann")]);
+}
+
+// ============================================================
+// AUTO-RESTORE & ESC
+// ============================================================
+
+#[test]
+fn auto_restore_english_doubled_consonants() {
+    telex_quick_auto_restore(&[
+        ("running ", "running "),
+        ("dinner ", "dinner "),
+        ("bann ", "bang "),
+        ("nnay ", "ngay "),
+    ]);
+}
+
+#[test]
+fn esc_restores_raw_keys() {
+    let mut e = Engine::new();
+    e.set_quick_telex(true);
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "cca\x1b"), "cca");
+}
+
+#[test]
+fn esc_after_revert_keeps_literal_pair() {
+    let mut e = Engine::new();
+    e.set_quick_telex(true);
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "ccca\x1b"), "cca");
+}
+
+#[test]
+fn vni_unaffected() {
+    let mut e = Engine::new();
+    e.set_method(1);
+    e.set_quick_telex(true);
+    assert_eq!(type_word(&mut e, "cca"), "cca");
+}
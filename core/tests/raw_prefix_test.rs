@@ -0,0 +1,75 @@
+//! Raw Prefix Tests - @mentions, #channels, :emoji_names: and /commands
+//!
+//! Tokens starting with an enabled prefix are typed raw until the next
+//! whitespace; Vietnamese works again after it.
+
+use gonhanh_core::engine::identifier::RawPrefix;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with the given raw prefixes enabled
+fn telex_raw(prefixes: &[RawPrefix], cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        for &prefix in prefixes {
+            e.set_raw_prefix(prefix, true);
+        }
+        let result = type_word(&mut e, input);
+        assert_eq!(result, *expected, "[Telex Raw] '{}' → '{}'", input, result);
+    }
+}
+
+#[test]
+fn disabled_by_default() {
+    telex_raw(&[], &[("@thanhs ", "@thánh "), ("/ask ", "/ák ")]);
+}
+
+#[test]
+fn all_prefixes() {
+    telex_raw(
+        &RawPrefix::ALL,
+        &[
+            ("@thanhs ", "@thanhs "),
+            ("#dev-ops ", "#dev-ops "),
+            (":smile_cat: ", ":smile_cat: "),
+            ("/ask ", "/ask "),
+            ("@first.lasts ", "@first.lasts "),
+        ],
+    );
+}
+
+#[test]
+fn vietnamese_after_token() {
+    telex_raw(
+        &RawPrefix::ALL,
+        &[
+            ("@thanhs ddi awn ", "@thanhs đi ăn "),
+            ("/remind mai hopj ", "/remind mai họp "),
+            ("chaof @ans ", "chào @ans "),
+        ],
+    );
+}
+
+#[test]
+fn prefixes_are_independent() {
+    telex_raw(
+        &[RawPrefix::Mention],
+        &[("@thanhs ", "@thanhs "), ("#thanhs ", "#thánh ")],
+    );
+}
+
+#[test]
+fn prefixed_shortcuts_still_expand() {
+    let mut e = Engine::new();
+    e.set_raw_prefix(RawPrefix::Hashtag, true);
+    e.shortcuts_mut()
+        .add(Shortcut::new("#fne", "for next episode"));
+    assert_eq!(type_word(&mut e, "#fne "), "for next episode ");
+    assert_eq!(type_word(&mut e, "#fnes "), "#fnes ");
+}
+
+#[test]
+fn backspace_over_prefix() {
+    telex_raw(&[RawPrefix::Mention], &[("@<thanhs ", "thánh ")]);
+}
@@ -0,0 +1,94 @@
+//! Large Shortcut Table Tests - 10k imported macros
+//!
+//! Benchmark: cargo test --release --test shortcut_bench_test -- --ignored --nocapture
+
+use gonhanh_core::engine::shortcut::{Shortcut, ShortcutTable};
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+use std::hint::black_box;
+use std::time::Instant;
+
+const COUNT: usize = 10_000;
+
+/// Letters Telex leaves alone (no vowels, tone or mark keys)
+const LETTERS: &[u8] = b"bcghklmnpqtv";
+
+/// Distinct 4-letter trigger for `i` ("bbbb", "bbbc", ...)
+fn trigger(mut i: usize) -> String {
+    let mut chars = [b'b'; 4];
+    for c in chars.iter_mut().rev() {
+        *c = LETTERS[i % LETTERS.len()];
+        i /= LETTERS.len();
+    }
+    String::from_utf8(chars.to_vec()).unwrap()
+}
+
+fn table() -> ShortcutTable {
+    let mut table = ShortcutTable::new();
+    for i in 0..COUNT {
+        table.add(Shortcut::new(&trigger(i), &format!("macro {}", i)));
+    }
+    table
+}
+
+fn engine() -> Engine {
+    let mut e = Engine::new();
+    *e.shortcuts_mut() = table();
+    e
+}
+
+#[test]
+fn ten_thousand_shortcuts_expand() {
+    let mut e = engine();
+    assert_eq!(e.shortcuts().len(), COUNT);
+    for i in [0, 1, 4_999, COUNT - 1] {
+        let input = format!("{} ", trigger(i));
+        assert_eq!(type_word(&mut e, &input), format!("macro {} ", i));
+    }
+    assert_eq!(type_word(&mut e, "vieejt "), "việt ");
+    assert_eq!(type_word(&mut e, "Bbbc "), "Macro 1 ");
+}
+
+#[test]
+fn prefix_and_incremental_queries() {
+    let table = table();
+    // 12 letters: every 3-letter prefix is shared by 12 triggers
+    assert_eq!(table.triggers_with_prefix("BBB").len(), LETTERS.len());
+    assert_eq!(table.triggers_with_prefix("bbb")[..2], ["bbbb", "bbbc"]);
+
+    let cursor = table.cursor().step('b').unwrap().step('B').unwrap();
+    assert!(cursor.trigger().is_none());
+    let cursor = cursor.step('b').unwrap().step('c').unwrap();
+    assert_eq!(cursor.trigger(), Some("bbbc"));
+    assert!(!cursor.has_longer());
+    assert!(table.cursor().step('a').is_none());
+}
+
+#[test]
+#[ignore]
+fn bench_10k_shortcuts() {
+    let start = Instant::now();
+    let table = table();
+    println!("build {} shortcuts: {:?}", COUNT, start.elapsed());
+
+    let triggers: Vec<String> = (0..COUNT).map(trigger).collect();
+    let start = Instant::now();
+    for t in &triggers {
+        black_box(table.lookup(black_box(t)));
+    }
+    let per_hit = start.elapsed() / COUNT as u32;
+    let start = Instant::now();
+    for t in &triggers {
+        black_box(table.lookup(black_box(&t[..3])));
+    }
+    let per_miss = start.elapsed() / COUNT as u32;
+    println!("lookup: {:?}/hit, {:?}/miss", per_hit, per_miss);
+
+    let text = "Hoom nay tooi ddi hocj bbbc vaf vieets code, bbbd vaf tvtv nhes. ".repeat(20);
+    for (name, mut e) in [("empty table", Engine::new()), ("10k table", engine())] {
+        let start = Instant::now();
+        black_box(type_word(&mut e, &text));
+        let per_key = start.elapsed() / text.len() as u32;
+        println!("typing with {}: {:?}/key", name, per_key);
+    }
+}
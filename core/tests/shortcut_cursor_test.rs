@@ -0,0 +1,127 @@
+//! Shortcut Cursor Tests - `{cursor}` places the caret inside an expansion
+//!
+//! The engine reports `caret_left` to frontends that declare
+//! `CAP_CARET_MOVE`; they move the caret after applying backspaces, output
+//! and any passed-through trigger key.
+
+use gonhanh_core::data::keys;
+use gonhanh_core::document::VirtualDocument;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::{Engine, CAP_CARET_MOVE, FLAG_KEY_CONSUMED};
+
+/// Type `input` into a document; return the text with '|' at the caret
+fn type_with_caret(shortcuts: &[Shortcut], enabled: bool, input: &str) -> String {
+    let mut e = Engine::new();
+    e.set_frontend_caps(CAP_CARET_MOVE);
+    e.set_enabled(enabled);
+    for shortcut in shortcuts {
+        assert!(e.shortcuts_mut().add(shortcut.clone()));
+    }
+    let mut doc = VirtualDocument::with_engine(e);
+    doc.type_str(input);
+    let mut text: Vec<char> = doc.text().chars().collect();
+    text.insert(doc.cursor(), '|');
+    text.into_iter().collect()
+}
+
+/// Run cases with the IME both enabled and disabled
+fn check(shortcuts: &[Shortcut], cases: &[(&str, &str)]) {
+    for enabled in [true, false] {
+        for (input, expected) in cases {
+            let result = type_with_caret(shortcuts, enabled, input);
+            assert_eq!(
+                result, *expected,
+                "[Cursor enabled={}] '{}' → '{}'",
+                enabled, input, result
+            );
+        }
+    }
+}
+
+#[test]
+fn word_trigger_on_space() {
+    check(
+        &[Shortcut::new("html", "<p>{cursor}</p>")],
+        &[("html ", "<p>|</p> "), ("a html ", "a <p>|</p> ")],
+    );
+}
+
+#[test]
+fn word_trigger_on_enter() {
+    // The trigger key goes into the output so it doesn't land at the caret
+    check(
+        &[Shortcut::new("html", "<p>{cursor}</p>")],
+        &[("html\n", "<p>|</p>\n")],
+    );
+}
+
+#[test]
+fn word_trigger_on_punctuation() {
+    // Word shortcuts only fire on punctuation while the IME is enabled
+    let shortcuts = [Shortcut::new("html", "<p>{cursor}</p>")];
+    assert_eq!(type_with_caret(&shortcuts, true, "html,"), "<p>|</p>,");
+}
+
+#[test]
+fn immediate_symbol_trigger() {
+    check(
+        &[Shortcut::immediate("\"\"", "“{cursor}”")],
+        &[("\"\"", "“|”"), ("a \"\"", "a “|”")],
+    );
+}
+
+#[test]
+fn typing_at_caret_after_expansion() {
+    let shortcuts = [Shortcut::immediate("\"\"", "“{cursor}”")];
+    assert_eq!(
+        type_with_caret(&shortcuts, true, "\"\"xin chaof"),
+        "“xin chào|”"
+    );
+}
+
+#[test]
+fn marker_at_end_or_missing_keeps_caret() {
+    check(
+        &[
+            Shortcut::new("vn", "Việt Nam{cursor}"),
+            Shortcut::new("hn", "Hà Nội"),
+        ],
+        &[("vn ", "Việt Nam |"), ("hn ", "Hà Nội |")],
+    );
+}
+
+#[test]
+fn result_accounting() {
+    let mut e = Engine::new();
+    e.set_frontend_caps(CAP_CARET_MOVE);
+    e.shortcuts_mut()
+        .add(Shortcut::new("html", "<p>{cursor}</p>"));
+    for key in [keys::H, keys::T, keys::M, keys::L] {
+        e.on_key_ext(key, false, false, false);
+    }
+    let r = e.on_key_ext(keys::DOT, false, false, false);
+    assert_eq!(r.backspace, 4);
+    assert_eq!(r.count, 8); // "<p></p>."
+    assert_eq!(r.caret_left, 5);
+    assert_ne!(r.flags & FLAG_KEY_CONSUMED, 0);
+}
+
+#[test]
+fn caret_stays_without_capability() {
+    // Frontends that don't move the caret get the text without the marker
+    let mut e = Engine::new();
+    e.shortcuts_mut()
+        .add(Shortcut::new("html", "<p>{cursor}</p>"));
+    let mut doc = VirtualDocument::with_engine(e);
+    doc.type_str("html ");
+    assert_eq!(doc.text(), "<p></p> ");
+    assert_eq!(doc.cursor(), 8);
+
+    let mut e = doc.into_engine();
+    for key in [keys::H, keys::T, keys::M, keys::L] {
+        e.on_key_ext(key, false, false, false);
+    }
+    let r = e.on_key_ext(keys::DOT, false, false, false);
+    assert_eq!((r.backspace, r.count, r.caret_left), (4, 7, 0));
+    assert_eq!(r.flags & FLAG_KEY_CONSUMED, 0);
+}
@@ -0,0 +1,141 @@
+//! Shortcut Import Tests - UniKey/OpenKey/EVKey macro files and JSON
+//! imported into the engine and typed through it
+
+use gonhanh_core::engine::shortcut::format::{Conflict, ImportOptions, ShortcutFormat};
+use gonhanh_core::engine::shortcut::{InputMethod, Shortcut};
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+const UNIKEY: &str = ";DO NOT DELETE THIS LINE*** version=1 ***\n\
+                      vn:Việt Nam\n\
+                      hn:Hà Nội\n\
+                      sig:Thân,\\nNam\n";
+
+/// Helper: import `text` into a fresh engine, then check typed telex cases
+fn telex_imported(
+    text: &str,
+    format: ShortcutFormat,
+    options: ImportOptions,
+    cases: &[(&str, &str)],
+) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        let import = e.shortcuts_mut().import(text, format, &options);
+        assert!(import.errors.is_empty(), "{:?}", import.errors);
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex Import] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+#[test]
+fn unikey_macros() {
+    telex_imported(
+        UNIKEY,
+        ShortcutFormat::UniKey,
+        ImportOptions::default(),
+        &[
+            ("vn ", "Việt Nam "),
+            ("VN ", "VIỆT NAM "),
+            ("hn.", "Hà Nội."),
+            ("sig ", "Thân,\nNam "),
+        ],
+    );
+}
+
+#[test]
+fn case_sensitive_and_immediate_options() {
+    let openkey = "vn:Việt Nam\n->:→\n";
+    let case_sensitive = ImportOptions {
+        case_sensitive: true,
+        ..Default::default()
+    };
+    telex_imported(
+        openkey,
+        ShortcutFormat::OpenKey,
+        case_sensitive,
+        &[("VN ", "Việt Nam "), ("Vn ", "Việt Nam ")],
+    );
+    let immediate = ImportOptions {
+        immediate: true,
+        ..Default::default()
+    };
+    telex_imported(
+        openkey,
+        ShortcutFormat::OpenKey,
+        immediate,
+        &[("a->b", "a→b")],
+    );
+}
+
+#[test]
+fn json_fields() {
+    let json = r#"[
+        {"trigger": "tl", "replacement": "Telex", "input_method": "telex"},
+        {"trigger": "vni", "replacement": "VNI only", "input_method": "vni"},
+        {"trigger": "tat", "replacement": "never", "enabled": false}
+    ]"#;
+    telex_imported(
+        json,
+        ShortcutFormat::Json,
+        ImportOptions::default(),
+        &[("tl ", "Telex "), ("vni ", "vni "), ("tat ", "tat ")],
+    );
+}
+
+#[test]
+fn existing_user_shortcuts_win_unless_overwrite() {
+    for (overwrite, expected) in [(false, "VN "), (true, "Việt Nam ")] {
+        let mut e = Engine::new();
+        e.shortcuts_mut().add(Shortcut::new("vn", "VN"));
+        e.shortcuts_mut().add(Shortcut::new("hn", "Hà Nội"));
+        let options = ImportOptions {
+            overwrite,
+            ..Default::default()
+        };
+        let import = e
+            .shortcuts_mut()
+            .import(UNIKEY, ShortcutFormat::EVKey, &options);
+        assert_eq!(
+            import.conflicts,
+            [Conflict::Existing {
+                line: 2,
+                trigger: "vn".into(),
+                existing: "VN".into()
+            }]
+        );
+        assert_eq!(import.shortcuts.len(), if overwrite { 3 } else { 2 });
+        assert_eq!(type_word(&mut e, "vn "), expected);
+    }
+}
+
+#[test]
+fn export_then_import_types_the_same() {
+    let mut e = Engine::new();
+    e.shortcuts_mut().add(Shortcut::new("vn", "Việt Nam"));
+    e.shortcuts_mut()
+        .add(Shortcut::immediate("->", "→").for_method(InputMethod::Telex));
+    e.shortcuts_mut().add(Shortcut::new("sig", "Thân,\n\tNam"));
+
+    for format in ShortcutFormat::ALL {
+        let text = e.shortcuts().export(format);
+        let mut fresh = Engine::new();
+        let import = fresh.shortcuts_mut().import(
+            &text,
+            ShortcutFormat::detect(&text),
+            &ImportOptions::default(),
+        );
+        assert!(import.errors.is_empty(), "{:?}", import.errors);
+        assert_eq!(type_word(&mut fresh, "vn "), "Việt Nam ");
+        assert_eq!(type_word(&mut fresh, "sig "), "Thân,\n\tNam ");
+        let arrow = if format == ShortcutFormat::Json {
+            "a→"
+        } else {
+            "a->"
+        };
+        assert_eq!(type_word(&mut fresh, "a->"), arrow, "{:?}", format);
+    }
+}
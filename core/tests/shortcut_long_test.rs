@@ -0,0 +1,110 @@
+//! Long Shortcut Tests - expansions over one result (255 chars) and with
+//! line breaks or tabs are sent in parts fetched with `next_output` to
+//! frontends that declare `CAP_MORE_OUTPUT`; others get one result
+
+use gonhanh_core::data::keys;
+use gonhanh_core::document::VirtualDocument;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::{Engine, Result, CAP_CARET_MOVE, CAP_MORE_OUTPUT};
+use gonhanh_core::utils::type_word;
+
+fn engine_with(trigger: &str, replacement: &str) -> Engine {
+    let mut e = Engine::new();
+    e.set_frontend_caps(CAP_CARET_MOVE | CAP_MORE_OUTPUT);
+    assert!(e.shortcuts_mut().add(Shortcut::new(trigger, replacement)));
+    e
+}
+
+fn chars(r: &Result) -> String {
+    r.chars[..r.count as usize]
+        .iter()
+        .filter_map(|&c| char::from_u32(c))
+        .collect()
+}
+
+/// Type "sig" + Space and collect every part of the expansion
+fn expansion_parts(e: &mut Engine) -> Vec<Result> {
+    for key in [keys::S, keys::I, keys::G] {
+        e.on_key(key, false, false);
+    }
+    let mut parts = vec![e.on_key(keys::SPACE, false, false)];
+    while parts.last().unwrap().more_output() {
+        parts.push(e.next_output());
+    }
+    parts
+}
+
+#[test]
+fn long_signature_is_not_truncated() {
+    let signature = "Gõ Nhanh - bộ gõ tiếng Việt. ".repeat(30);
+    assert!(signature.chars().count() > 800);
+    let mut e = engine_with("sig", &signature);
+
+    let parts = expansion_parts(&mut e);
+    assert_eq!(parts.len(), 4);
+    assert_eq!(parts[0].backspace, 3);
+    assert!(parts[1..].iter().all(|p| p.backspace == 0));
+    assert!(parts.iter().all(|p| p.count as usize <= 255));
+    let text: String = parts.iter().map(chars).collect();
+    assert_eq!(text, format!("{} ", signature));
+}
+
+#[test]
+fn line_breaks_and_tabs_are_separate_parts() {
+    let mut e = engine_with("sig", "Trân trọng,\r\n\tNam");
+    let parts: Vec<String> = expansion_parts(&mut e).iter().map(chars).collect();
+    assert_eq!(parts, ["Trân trọng,", "\n", "\t", "Nam "]);
+}
+
+#[test]
+fn typed_into_document() {
+    let mut e = engine_with("sig", "Trân trọng,\nNam");
+    assert_eq!(type_word(&mut e, "sig chaof"), "Trân trọng,\nNam chào");
+}
+
+#[test]
+fn punctuation_trigger_goes_after_all_parts() {
+    let mut e = engine_with("sig", "Thân,\nNam");
+    assert_eq!(type_word(&mut e, "sig."), "Thân,\nNam.");
+}
+
+#[test]
+fn cursor_after_last_part() {
+    let body = "x".repeat(300);
+    let mut doc = VirtualDocument::with_engine(engine_with(
+        "tpl",
+        &format!("<div>\n{{cursor}}{}\n</div>", body),
+    ));
+    doc.type_str("tpl ");
+    assert_eq!(doc.text(), format!("<div>\n{}\n</div> ", body));
+    assert_eq!(doc.cursor(), 6);
+}
+
+#[test]
+fn key_before_fetch_drops_rest() {
+    let mut e = engine_with("sig", "a\nb");
+    for key in [keys::S, keys::I, keys::G] {
+        e.on_key(key, false, false);
+    }
+    assert!(e.on_key(keys::SPACE, false, false).more_output());
+    e.on_key(keys::A, false, false);
+    assert_eq!(e.next_output().action, 0);
+}
+
+#[test]
+fn one_result_without_capability() {
+    let mut e = engine_with("sig", "Trân trọng,\n\tNam");
+    e.set_frontend_caps(0);
+    let parts = expansion_parts(&mut e);
+    assert_eq!(parts.len(), 1);
+    assert_eq!(chars(&parts[0]), "Trân trọng,\n\tNam ");
+    assert_eq!(type_word(&mut e, " sig chaof"), " Trân trọng,\n\tNam chào");
+
+    // Cut to one result, as before streaming
+    let mut e = engine_with("sig", &"x".repeat(300));
+    e.set_frontend_caps(0);
+    let parts = expansion_parts(&mut e);
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].count, 255);
+    assert!(!parts[0].more_output());
+}
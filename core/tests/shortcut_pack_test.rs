@@ -0,0 +1,91 @@
+//! Shortcut Pack Tests - bundled arrows, math, currency, typography and
+//! Vietnamese abbreviation shortcuts typed through the engine
+
+use gonhanh_core::data::shortcut_pack::ShortcutPack;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with the given shortcut packs enabled
+fn telex_packs(packs: &[ShortcutPack], cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        for &pack in packs {
+            e.shortcuts_mut().set_pack_enabled(pack, true);
+        }
+        let result = type_word(&mut e, input);
+        assert_eq!(result, *expected, "[Telex Pack] '{}' → '{}'", input, result);
+    }
+}
+
+#[test]
+fn disabled_by_default() {
+    telex_packs(&[], &[("a -> b ", "a -> b "), ("ko ", "ko ")]);
+}
+
+#[test]
+fn arrows_and_math() {
+    telex_packs(
+        &[ShortcutPack::Arrows, ShortcutPack::Math],
+        &[
+            ("a -> b ", "a → b "),
+            ("x=>y ", "x⇒y "),
+            ("a != b ", "a ≠ b "),
+            ("a >= b ", "a ≥ b "),
+            ("+-5 ", "±5 "),
+            ("x^2 ", "x² "),
+            ("m^3 ", "m³ "),
+            ("2^n ", "2ⁿ "),
+            ("a^2+b^2 ", "a²+b² "),
+        ],
+    );
+}
+
+#[test]
+fn currency_and_typography() {
+    telex_packs(
+        &[ShortcutPack::Currency, ShortcutPack::Typography],
+        &[
+            ("100 vnd ", "100 ₫ "),
+            ("5 gbp ", "5 £ "),
+            ("a -- b ", "a – b "),
+            ("vaay... ", "vây… "),
+        ],
+    );
+}
+
+#[test]
+fn vietnamese_abbreviations() {
+    telex_packs(
+        &[ShortcutPack::Vietnamese],
+        &[
+            ("ko bieets ", "không biết "),
+            ("Ko dc ", "Không được "),
+            ("ddi vs mn ", "đi với mọi người "),
+            ("HCM ", "HỒ CHÍ MINH "),
+        ],
+    );
+}
+
+#[test]
+fn only_enabled_packs_apply() {
+    telex_packs(
+        &[ShortcutPack::Typography],
+        &[("a -> b -- c ", "a -> b – c ")],
+    );
+}
+
+#[test]
+fn user_edits_survive_pack_toggle() {
+    let mut e = Engine::new();
+    e.shortcuts_mut()
+        .edit_pack_entry("vietnamese.ko", "kh", "không");
+    e.shortcuts_mut().add(Shortcut::new("dc", "địa chỉ"));
+    e.shortcuts_mut()
+        .set_pack_enabled(ShortcutPack::Vietnamese, true);
+    e.shortcuts_mut()
+        .set_pack_enabled(ShortcutPack::Vietnamese, false);
+    e.shortcuts_mut()
+        .set_pack_enabled(ShortcutPack::Vietnamese, true);
+    assert_eq!(type_word(&mut e, "kh ko dc "), "không ko địa chỉ ");
+}
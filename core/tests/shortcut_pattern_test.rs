@@ -0,0 +1,144 @@
+//! Pattern Shortcut Tests - triggers like `(\d+)k` with `$1` substitution,
+//! typed through the engine
+
+use gonhanh_core::engine::shortcut::format::{ImportOptions, ShortcutFormat};
+use gonhanh_core::engine::shortcut::{Shortcut, ShortcutTable, TriggerCondition};
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+fn engine_with(patterns: &[(&str, &str)]) -> Engine {
+    let mut e = Engine::new();
+    for &(pattern, replacement) in patterns {
+        let shortcut = Shortcut::pattern(pattern, replacement).unwrap();
+        assert!(e.shortcuts_mut().add(shortcut));
+    }
+    e
+}
+
+fn money_engine() -> Engine {
+    engine_with(&[
+        (r"(\d+)tr", "$1 triệu"),
+        (r"(\d+)k", "$1.000 đ"),
+        (r"sdt(\d{4})(\d{3})(\d{3})", "$1 $2 $3"),
+    ])
+}
+
+#[test]
+fn amounts_and_phone_numbers_expand() {
+    for (input, expected) in [
+        ("50tr ", "50 triệu "),
+        ("120k ", "120.000 đ "),
+        ("120K ", "120.000 đ "),
+        ("sdt0912345678 ", "0912 345 678 "),
+        ("giá 50tr ", "giá 50 triệu "),
+    ] {
+        let mut e = money_engine();
+        assert_eq!(type_word(&mut e, input), expected, "{}", input);
+    }
+}
+
+#[test]
+fn non_matching_words_are_left_alone() {
+    for input in ["k ", "50 ", "50ktr ", "sdt091234567 ", "tr "] {
+        let mut e = money_engine();
+        assert_eq!(type_word(&mut e, input), input, "{}", input);
+    }
+}
+
+#[test]
+fn fixed_triggers_win_and_keep_number_rule() {
+    let mut e = money_engine();
+    e.shortcuts_mut().add(Shortcut::new("k", "không"));
+    // Fixed "k" still expands alone, and still never after digits
+    assert_eq!(type_word(&mut e, "k "), "không ");
+    let mut e = money_engine();
+    e.shortcuts_mut().add(Shortcut::new("k", "không"));
+    assert_eq!(type_word(&mut e, "149k "), "149.000 đ ");
+
+    // A fixed trigger equal to the word is found before any pattern
+    let mut e = engine_with(&[(r"\w+", "x")]);
+    e.shortcuts_mut().add(Shortcut::new("vn", "Việt Nam"));
+    assert_eq!(type_word(&mut e, "vn "), "Việt Nam ");
+}
+
+#[test]
+fn immediate_pattern_fires_without_boundary() {
+    let mut shortcut = Shortcut::pattern(r"=(\d)", "⁽$1⁾").unwrap();
+    shortcut.condition = TriggerCondition::Immediate;
+    let mut table = ShortcutTable::new();
+    assert!(table.add(shortcut));
+    let m = table.try_match("=2", None, false).unwrap();
+    assert_eq!((m.backspace_count, m.output.as_str()), (2, "⁽2⁾"));
+    assert!(!m.include_trigger_key);
+
+    // Word boundary patterns wait for the boundary
+    let mut table = ShortcutTable::new();
+    table.add(Shortcut::pattern(r"(\d+)k", "$1.000 đ").unwrap());
+    assert!(table.try_match("5k", None, false).is_none());
+    let m = table.try_match("5k", Some(' '), true).unwrap();
+    assert_eq!(m.output, "5.000 đ ");
+}
+
+#[test]
+fn cursor_template_with_captures() {
+    let mut table = ShortcutTable::new();
+    table.add(Shortcut::pattern(r"h(\d)", "<h$1>{cursor}</h$1>").unwrap());
+    let m = table.try_match("h2", None, true).unwrap();
+    assert_eq!(m.output, "<h2></h2>");
+    assert_eq!(m.caret_left, 5);
+}
+
+#[test]
+fn table_management() {
+    let mut table = ShortcutTable::new();
+    assert!(Shortcut::pattern(r"(\d+k", "x").is_none());
+    assert!(Shortcut::pattern(r"(\d+)k", "$2").is_none());
+    table.add(Shortcut::pattern(r"(\d+)K", "$1.000 đ").unwrap());
+    table.add(Shortcut::new("an", "anh"));
+
+    // Stored lowercase, listed and looked up like any trigger
+    assert!(table.get(r"(\d+)k").is_some_and(|s| s.pattern.is_some()));
+    assert_eq!(table.len(), 2);
+    // Patterns are not analyzed as words; indexes still point into entries()
+    let warnings = table.analyze();
+    assert!(warnings.iter().all(|w| w.trigger == "an"));
+    assert_eq!(table.entries()[warnings[0].index].trigger, "an");
+
+    assert!(table.remove(r"(\d+)k").is_some());
+    assert!(table.try_match("5k", None, true).is_none());
+}
+
+#[test]
+fn json_round_trip_keeps_patterns() {
+    let mut table = ShortcutTable::new();
+    table.add(Shortcut::pattern(r"(\d+)tr", "$1 triệu").unwrap());
+    table.add(Shortcut::new("vn", "Việt Nam"));
+
+    // Macro files can't carry patterns
+    let unikey = table.export(ShortcutFormat::UniKey);
+    assert!(unikey.contains("vn:Việt Nam") && !unikey.contains("triệu"));
+
+    let json = table.export(ShortcutFormat::Json);
+    assert!(json.contains(r#""pattern": true"#));
+    let mut copy = ShortcutTable::new();
+    let import = copy.import(&json, ShortcutFormat::Json, &ImportOptions::default());
+    assert!(import.errors.is_empty() && import.warnings.is_empty());
+    assert_eq!(copy.try_match("7tr", None, true).unwrap().output, "7 triệu");
+
+    let bad = r#"[{"trigger": "(\\d+", "replacement": "x", "pattern": true}]"#;
+    let import = copy.import(bad, ShortcutFormat::Json, &ImportOptions::default());
+    assert_eq!(import.errors.len(), 1);
+}
+
+#[test]
+fn pathological_pattern_is_bounded() {
+    // Exponential backtracking; "[bc]" leaves no literal suffix to reject early
+    let mut table = ShortcutTable::new();
+    for i in 0..10 {
+        let pattern = format!("(?:a|a)*(a*)*[bc]{}", "?".repeat(i % 2));
+        table.add(Shortcut::pattern(&format!("x{}|{}", i, pattern), "y").unwrap());
+    }
+    let word = format!("{}d", "a".repeat(60));
+    // Gives up once the shared step budget runs out
+    assert!(table.try_match(&word, Some(' '), true).is_none());
+}
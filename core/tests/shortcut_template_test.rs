@@ -0,0 +1,105 @@
+//! Shortcut Template Tests - {date}, {time}, {weekday_vi}, {year} and
+//! {counter} placeholders evaluated with a fixed clock
+
+use gonhanh_core::engine::shortcut::template::{Clock, DateTime};
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Sunday 2025-03-09 14:07:05
+const NOW: DateTime = DateTime {
+    year: 2025,
+    month: 3,
+    day: 9,
+    hour: 14,
+    minute: 7,
+    second: 5,
+};
+
+/// Engine with the given shortcuts and a fixed clock
+fn engine_with(shortcuts: &[(&str, &str)]) -> Engine {
+    let mut e = Engine::new();
+    e.shortcuts_mut().set_clock(Clock::Fixed(NOW));
+    for &(trigger, replacement) in shortcuts {
+        assert!(e.shortcuts_mut().add(Shortcut::new(trigger, replacement)));
+    }
+    e
+}
+
+/// Helper to run telex tests with template shortcuts
+fn telex_template(shortcuts: &[(&str, &str)], cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = engine_with(shortcuts);
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex Template] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+#[test]
+fn date_and_time() {
+    telex_template(
+        &[
+            ("nd", "{date}"),
+            ("gio", "{time}"),
+            ("dt", "{date:yyyy-MM-dd} {time:HH:mm:ss}"),
+        ],
+        &[
+            ("nd ", "09/03/2025 "),
+            ("gio ", "14:07 "),
+            ("dt ", "2025-03-09 14:07:05 "),
+        ],
+    );
+}
+
+#[test]
+fn vietnamese_date() {
+    telex_template(
+        &[("hnay", "{weekday_vi}, {date:'ngày' d 'tháng' M 'năm' yyyy}")],
+        &[
+            ("hnay ", "Chủ Nhật, ngày 9 tháng 3 năm 2025 "),
+            ("Hnay ", "Chủ Nhật, ngày 9 tháng 3 năm 2025 "),
+        ],
+    );
+}
+
+#[test]
+fn year_and_literal_braces() {
+    telex_template(
+        &[("cr", "© {year} {{GoNhanh}}")],
+        &[("cr ", "© 2025 {GoNhanh} ")],
+    );
+}
+
+#[test]
+fn counter_increments_per_shortcut() {
+    let mut e = engine_with(&[("ve", "Vé số {counter}"), ("don", "Đơn #{counter}")]);
+    assert_eq!(
+        type_word(&mut e, "ve ve don ve "),
+        "Vé số 1 Vé số 2 Đơn #1 Vé số 3 "
+    );
+}
+
+#[test]
+fn malformed_templates_rejected() {
+    let mut e = Engine::new();
+    for replacement in ["{date:qq}", "{year:yy}", "{cursor}{cursor}"] {
+        assert!(
+            !e.shortcuts_mut().add(Shortcut::new("x", replacement)),
+            "{}",
+            replacement
+        );
+    }
+    assert!(e.shortcuts().is_empty());
+}
+
+#[test]
+fn other_braces_are_literal() {
+    telex_template(
+        &[("obj", "{}"), ("js", "{\"a\": 1}"), ("th", "{thu}")],
+        &[("obj ", "{} "), ("js ", "{\"a\": 1} "), ("th ", "{thu} ")],
+    );
+}
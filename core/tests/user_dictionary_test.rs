@@ -0,0 +1,70 @@
+//! User Dictionary Tests - runtime English words for auto-restore
+//!
+//! Words added by the user restore to English at word end even when the
+//! bundled dictionary doesn't know them; removed words keep Vietnamese.
+
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Helper to run telex tests with auto-restore and a user dictionary
+fn telex_user_dict(add: &[&str], remove: &[&str], cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        e.set_english_auto_restore(true);
+        for w in add {
+            e.user_dictionary_mut().add(w);
+        }
+        for w in remove {
+            e.user_dictionary_mut().remove(w);
+        }
+        let result = type_word(&mut e, input);
+        assert_eq!(
+            result, *expected,
+            "[Telex UserDict] '{}' → '{}'",
+            input, result
+        );
+    }
+}
+
+#[test]
+fn bundled_dictionary_behavior() {
+    telex_user_dict(
+        &[],
+        &[],
+        &[("nestjs ", "nét "), ("vuex ", "vuẽ "), ("aw ", "aw ")],
+    );
+}
+
+#[test]
+fn added_words_restore() {
+    telex_user_dict(
+        &["nestjs", "vuex", "terraform", "mix"],
+        &[],
+        &[
+            ("nestjs ", "nestjs "),
+            ("Nestjs ", "Nestjs "),
+            ("vuex ", "vuex "),
+            ("terraform ", "terraform "),
+            ("mix ", "mix "),
+        ],
+    );
+}
+
+#[test]
+fn added_words_restore_on_punctuation() {
+    telex_user_dict(&["nestjs"], &[], &[("nestjs.", "nestjs.")]);
+}
+
+#[test]
+fn removed_words_keep_vietnamese() {
+    telex_user_dict(&[], &["aw", "asus"], &[("aw ", "ă "), ("asus ", "áu ")]);
+}
+
+#[test]
+fn other_words_unaffected() {
+    telex_user_dict(
+        &["nestjs"],
+        &["aw"],
+        &[("vieetj ", "việt "), ("text ", "text "), ("nest ", "nét ")],
+    );
+}
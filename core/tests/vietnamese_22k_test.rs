@@ -432,3 +432,33 @@ fn vietnamese_22k_compound() {
         MIN_PASS_RATE
     );
 }
+
+#[test]
+fn vietnamese_22k_with_english_classifier() {
+    let content = include_str!("data/vietnamese_22k.txt");
+    let mut syllables: Vec<&str> = content.lines().flat_map(str::split_whitespace).collect();
+    syllables.sort_unstable();
+    syllables.dedup();
+
+    // Syllables the classifier changes compared to rules alone
+    let mut regressions = Vec::new();
+    for syllable in syllables {
+        let input = format!("{} ", vn_to_telex(syllable));
+        let mut outputs = [false, true].map(|classifier| {
+            let mut e = Engine::new();
+            e.set_modern_tone(false);
+            e.set_english_auto_restore(true);
+            e.set_english_classifier(classifier);
+            type_word(&mut e, &input)
+        });
+        if outputs[0] != outputs[1] {
+            regressions.push((syllable, std::mem::take(&mut outputs[1])));
+        }
+    }
+
+    assert!(
+        regressions.is_empty(),
+        "English classifier changed Vietnamese syllables: {:?}",
+        regressions
+    );
+}
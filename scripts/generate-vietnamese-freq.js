@@ -0,0 +1,37 @@
+#!/usr/bin/env node
+
+/**
+ * Generate core/src/data/vietnamese_freq.txt for word prediction
+ *
+ * Source: core/tests/data/vietnamese_22k.txt (one word/phrase per line)
+ * Output lines: "<count>\t<text>"
+ *   - "<count>\t<syllable>"       how many entries contain the syllable
+ *   - "<count>\t<word> <next>"    how many entries contain the adjacent pair
+ *
+ * Usage: node scripts/generate-vietnamese-freq.js
+ */
+
+const fs = require('fs');
+const path = require('path');
+
+const ROOT = path.join(__dirname, '..');
+const SOURCE = path.join(ROOT, 'core/tests/data/vietnamese_22k.txt');
+const OUTPUT = path.join(ROOT, 'core/src/data/vietnamese_freq.txt');
+
+const counts = new Map();
+const bump = (key) => counts.set(key, (counts.get(key) || 0) + 1);
+
+for (const line of fs.readFileSync(SOURCE, 'utf8').split('\n')) {
+  const words = line.trim().toLowerCase().split(/\s+/).filter(Boolean);
+  words.forEach((word, i) => {
+    bump(word);
+    if (i + 1 < words.length) bump(`${word} ${words[i + 1]}`);
+  });
+}
+
+const lines = [...counts.entries()]
+  .sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0))
+  .map(([text, count]) => `${count}\t${text}`);
+
+fs.writeFileSync(OUTPUT, lines.join('\n') + '\n');
+console.log(`Wrote ${lines.length} entries to ${path.relative(ROOT, OUTPUT)}`);
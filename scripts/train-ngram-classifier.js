@@ -0,0 +1,110 @@
+#!/usr/bin/env node
+
+/**
+ * Train core/src/data/ngram_en_vi.bin for English auto-restore
+ *
+ * Character trigram models over raw keystrokes:
+ *   - English: core/tests/data/english_100k.txt (words as spelled)
+ *   - Vietnamese: core/tests/data/vietnamese_22k.txt converted to Telex
+ *     keystrokes (tone key at the end of each syllable: "việt" → "vieejt"),
+ *     plus the deferred-w spelling when it differs ("thương" → "thuongw")
+ *
+ * Output: 27^3 signed bytes, one per trigram (a-z, 26 = word boundary),
+ * index = (a * 27 + b) * 27 + c. Each byte is
+ *   round(SCALE * (ln P_en(c | ab) - ln P_vi(c | ab)))
+ * clamped to [-127, 127]. SCALE must match core/src/data/ngram.rs.
+ *
+ * Usage: node scripts/train-ngram-classifier.js [--holdout]
+ *   --holdout  skip every 10th word of both corpora (for evaluation)
+ */
+
+const fs = require('fs');
+const path = require('path');
+
+const ROOT = path.join(__dirname, '..');
+const ENGLISH = path.join(ROOT, 'core/tests/data/english_100k.txt');
+const VIETNAMESE = path.join(ROOT, 'core/tests/data/vietnamese_22k.txt');
+const OUTPUT = path.join(ROOT, 'core/src/data/ngram_en_vi.bin');
+
+const SYMBOLS = 27;
+const BOUNDARY = 26;
+const SCALE = 16;
+const SMOOTHING = 0.1;
+const holdout = process.argv.includes('--holdout');
+
+const TONES = { '̀': 'f', '́': 's', '̉': 'r', '̃': 'x', '̣': 'j' };
+
+/** Vietnamese syllable → Telex keystrokes ("việt" → "vieejt") */
+function toTelex(syllable, deferW = false) {
+  let keys = '';
+  let tone = '';
+  for (const ch of syllable.normalize('NFD')) {
+    if (ch === 'đ') keys += 'dd';
+    else if (TONES[ch]) tone = TONES[ch];
+    else if (ch === '̂') keys += keys[keys.length - 1]; // circumflex: aa, ee, oo
+    else if (ch === '̆' || ch === '̛') keys += deferW ? '' : 'w'; // breve, horn
+    else keys += ch;
+  }
+  const w = deferW && /[̛̆]/.test(syllable.normalize('NFD')) ? 'w' : '';
+  return keys + w + tone;
+}
+
+function code(ch) {
+  return ch.charCodeAt(0) - 97;
+}
+
+/** Count trigrams of words made of a-z only */
+function train(words) {
+  const counts = new Float64Array(SYMBOLS ** 3);
+  words.forEach((word, i) => {
+    if (holdout && i % 10 === 0) return;
+    if (!/^[a-z]+$/.test(word)) return;
+    const seq = [BOUNDARY, BOUNDARY, ...[...word].map(code), BOUNDARY];
+    for (let j = 2; j < seq.length; j++) {
+      counts[(seq[j - 2] * SYMBOLS + seq[j - 1]) * SYMBOLS + seq[j]] += 1;
+    }
+  });
+  return counts;
+}
+
+/** ln P(c | ab) with add-k smoothing */
+function logProbs(counts) {
+  const out = new Float64Array(counts.length);
+  for (let ctx = 0; ctx < SYMBOLS * SYMBOLS; ctx++) {
+    let total = 0;
+    for (let c = 0; c < SYMBOLS; c++) total += counts[ctx * SYMBOLS + c];
+    for (let c = 0; c < SYMBOLS; c++) {
+      out[ctx * SYMBOLS + c] = Math.log(
+        (counts[ctx * SYMBOLS + c] + SMOOTHING) / (total + SMOOTHING * SYMBOLS)
+      );
+    }
+  }
+  return out;
+}
+
+const lines = (file) =>
+  fs.readFileSync(file, 'utf8').split('\n').map((l) => l.trim().toLowerCase()).filter(Boolean);
+
+const english = lines(ENGLISH);
+const vietnamese = lines(VIETNAMESE)
+  .flatMap((l) => l.split(/\s+/))
+  .flatMap((syllable) => {
+    const inline = toTelex(syllable);
+    const deferred = toTelex(syllable, true);
+    return deferred === inline ? [inline] : [inline, deferred];
+  });
+
+const en = logProbs(train(english));
+const vi = logProbs(train(vietnamese));
+
+const table = Buffer.alloc(SYMBOLS ** 3);
+for (let i = 0; i < table.length; i++) {
+  const w = Math.max(-127, Math.min(127, Math.round(SCALE * (en[i] - vi[i]))));
+  table.writeInt8(w, i);
+}
+
+fs.writeFileSync(OUTPUT, table);
+console.log(
+  `Wrote ${table.length} weights to ${path.relative(ROOT, OUTPUT)}` +
+    ` (${english.length} English words, ${vietnamese.length} Vietnamese syllables${holdout ? ', holdout' : ''})`
+);